@@ -0,0 +1,34 @@
+use bisector::{Bisector, ConvergeTo};
+
+// NB: output held by ConvergeTo does *not* need to be of the same type as
+// the value. In this example, it just happens to be the case.
+fn f(value: u32) -> ConvergeTo<u32, u32> {
+    if value >= 7 {
+        ConvergeTo::Left(value)
+    } else {
+        ConvergeTo::Right(value)
+    }
+}
+
+fn main() {
+    let values = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    let bisector = Bisector::new(&values);
+
+    // In this example, we let the iterator feed the indices of each step into the next step,
+    // instead of writing the loop ourselves (compare with the `loop` example).
+    let mut iter = bisector.iter(|&v| f(v));
+
+    let elements_seen = iter
+        .by_ref()
+        .map(|step| match step.result {
+            Some(ConvergeTo::Left(l)) => l,
+            Some(ConvergeTo::Right(r)) => r,
+            None => unreachable!("the iterator only yields steps which made progress"),
+        })
+        .collect::<Vec<_>>();
+
+    let indices = iter.indices().unwrap();
+
+    println!("{:?}", elements_seen);
+    println!("Final converged to '{}'", values[indices.left]);
+}
@@ -0,0 +1,284 @@
+use crate::{Bisector, ConvergeTo, Indices, Step};
+use futures_core::Stream;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+impl<'v, T> Bisector<'v, T> {
+    /// Asynchronous variant of [`Bisector::bisect`], for convergence functions which produce a
+    /// [`Future`].
+    ///
+    /// Since the elements of the view outlive the [`Bisector`], the future produced by the
+    /// convergence function `f` may borrow the element it was created for. For this reason, the
+    /// asynchronous methods are only available for a [`Bisector`] over a slice, and not for one
+    /// created by [`Bisector::from_source`], whose candidates may be produced on demand.
+    ///
+    /// This method does not depend on a specific async runtime.
+    ///
+    /// ```
+    /// use bisector::{Bisector, ConvergeTo, Indices};
+    ///
+    /// let values = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    /// let bisector = Bisector::new(&values);
+    ///
+    /// let step = futures::executor::block_on(bisector.bisect_async(
+    ///     |&value| async move { ConvergeTo::<u32, u32>::Left(value) },
+    ///     Indices::from_bisector(&bisector),
+    /// ));
+    ///
+    /// assert_eq!(step.indices, Indices::new(0, 4));
+    /// ```
+    ///
+    /// See also:
+    /// * [`Bisector::try_bisect_async`]: A variant of [`bisect_async`] which can be used when the
+    ///   convergence function is fallible.
+    ///
+    /// [`Bisector::bisect`]: crate::Bisector::bisect
+    /// [`Bisector`]: crate::Bisector
+    /// [`Bisector::from_source`]: crate::Bisector::from_source
+    /// [`Future`]: std::future::Future
+    /// [`Bisector::try_bisect_async`]: crate::Bisector::try_bisect_async
+    /// [`bisect_async`]: crate::Bisector::bisect_async
+    pub async fn bisect_async<F, Fut, L, R>(&self, f: F, indices: Indices) -> Step<L, R>
+    where
+        F: FnOnce(&'v T) -> Fut,
+        Fut: Future<Output = ConvergeTo<L, R>>,
+    {
+        if indices.left == indices.right {
+            return Step {
+                indices,
+                result: None,
+            };
+        }
+
+        let result = f(&self.view()[indices.middle()]).await;
+
+        self.bisect(move |_| result, indices)
+    }
+
+    /// This method can be used when the convergence function is fallible.
+    /// Otherwise exactly the same as [`Bisector::bisect_async`].
+    ///
+    /// [`Bisector::bisect_async`]: crate::Bisector::bisect_async
+    pub async fn try_bisect_async<F, Fut, E, L, R>(
+        &self,
+        f: F,
+        indices: Indices,
+    ) -> Result<Step<L, R>, E>
+    where
+        F: FnOnce(&'v T) -> Fut,
+        Fut: Future<Output = Result<ConvergeTo<L, R>, E>>,
+    {
+        if indices.left == indices.right {
+            return Ok(Step {
+                indices,
+                result: None,
+            });
+        }
+
+        let result = f(&self.view()[indices.middle()]).await?;
+
+        Ok(self.bisect(move |_| result, indices))
+    }
+
+    /// Asynchronous variant of [`Bisector::iter`], which returns a [`Stream`] over the steps of the
+    /// bisection.
+    ///
+    /// The stream does not depend on a specific async runtime. Like [`Bisector::bisect_async`], it's
+    /// only available for a [`Bisector`] over a slice.
+    ///
+    /// ```
+    /// use bisector::{Bisector, ConvergeTo};
+    /// use futures::StreamExt;
+    ///
+    /// let values = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    /// let bisector = Bisector::new(&values);
+    ///
+    /// let steps = futures::executor::block_on(
+    ///     bisector
+    ///         .stream(|&value| async move {
+    ///             if value >= 7 {
+    ///                 ConvergeTo::Left(value)
+    ///             } else {
+    ///                 ConvergeTo::<u32, u32>::Right(value)
+    ///             }
+    ///         })
+    ///         .collect::<Vec<_>>(),
+    /// );
+    ///
+    /// assert_eq!(values[steps.last().unwrap().indices.left], 7);
+    /// ```
+    ///
+    /// See also:
+    /// * [`Bisector::try_stream`]: A variant of [`stream`] which can be used when the convergence
+    ///   function is fallible.
+    ///
+    /// [`Bisector::iter`]: crate::Bisector::iter
+    /// [`Bisector::bisect_async`]: crate::Bisector::bisect_async
+    /// [`Bisector`]: crate::Bisector
+    /// [`Stream`]: futures_core::Stream
+    /// [`Bisector::try_stream`]: crate::Bisector::try_stream
+    /// [`stream`]: crate::Bisector::stream
+    pub fn stream<F, Fut, L, R>(&self, f: F) -> BisectStream<'_, 'v, T, F, Fut>
+    where
+        F: FnMut(&'v T) -> Fut,
+        Fut: Future<Output = ConvergeTo<L, R>>,
+    {
+        BisectStream {
+            bisector: self,
+            f,
+            indices: Indices::try_from_bisector(self).ok(),
+            pending: None,
+        }
+    }
+
+    /// This method can be used when the convergence function is fallible.
+    /// Otherwise exactly the same as [`Bisector::stream`].
+    ///
+    /// The stream yields `Result`s. After the convergence function produced an error, the error is
+    /// yielded, and the stream is exhausted.
+    ///
+    /// [`Bisector::stream`]: crate::Bisector::stream
+    pub fn try_stream<F, Fut, E, L, R>(&self, f: F) -> TryBisectStream<'_, 'v, T, F, Fut>
+    where
+        F: FnMut(&'v T) -> Fut,
+        Fut: Future<Output = Result<ConvergeTo<L, R>, E>>,
+    {
+        TryBisectStream {
+            inner: BisectStream {
+                bisector: self,
+                f,
+                indices: Indices::try_from_bisector(self).ok(),
+                pending: None,
+            },
+            failed: false,
+        }
+    }
+}
+
+/// A [`Stream`] over the steps of a bisection, created by [`Bisector::stream`].
+///
+/// [`Stream`]: futures_core::Stream
+/// [`Bisector::stream`]: crate::Bisector::stream
+pub struct BisectStream<'b, 'v, T, F, Fut> {
+    bisector: &'b Bisector<'v, T>,
+    f: F,
+    indices: Option<Indices>,
+    pending: Option<Pin<Box<Fut>>>,
+}
+
+impl<'b, 'v, T, F, Fut> BisectStream<'b, 'v, T, F, Fut> {
+    /// The indices from which the next step will be taken.
+    ///
+    /// Returns `None` if the view of the [`Bisector`] is empty.
+    ///
+    /// [`Bisector`]: crate::Bisector
+    pub fn indices(&self) -> Option<Indices> {
+        self.indices
+    }
+
+    // Polls the convergence function for the middle element of the current view, and returns its
+    // output together with the current indices, or None if the bisection has converged.
+    fn poll_middle<O>(&mut self, cx: &mut Context<'_>) -> Poll<Option<(Indices, O)>>
+    where
+        F: FnMut(&'v T) -> Fut,
+        Fut: Future<Output = O>,
+    {
+        let indices = match self.indices {
+            Some(indices) if indices.left != indices.right => indices,
+            _ => return Poll::Ready(None),
+        };
+
+        if self.pending.is_none() {
+            let element = &self.bisector.view()[indices.middle()];
+            self.pending = Some(Box::pin((self.f)(element)));
+        }
+
+        match self.pending.as_mut().map(|future| future.as_mut().poll(cx)) {
+            Some(Poll::Ready(output)) => {
+                self.pending = None;
+                Poll::Ready(Some((indices, output)))
+            }
+            _ => Poll::Pending,
+        }
+    }
+}
+
+// The convergence function is never pinned; the futures it produces are pinned on the heap.
+impl<'b, 'v, T, F, Fut> Unpin for BisectStream<'b, 'v, T, F, Fut> {}
+
+impl<'b, 'v, T, F, Fut, L, R> Stream for BisectStream<'b, 'v, T, F, Fut>
+where
+    F: FnMut(&'v T) -> Fut,
+    Fut: Future<Output = ConvergeTo<L, R>>,
+{
+    type Item = Step<L, R>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        match this.poll_middle(cx) {
+            Poll::Ready(Some((indices, result))) => {
+                let step = this.bisector.bisect(move |_| result, indices);
+                this.indices = Some(step.indices);
+
+                Poll::Ready(Some(step))
+            }
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// A [`Stream`] over the steps of a bisection with a fallible convergence function, created by
+/// [`Bisector::try_stream`].
+///
+/// [`Stream`]: futures_core::Stream
+/// [`Bisector::try_stream`]: crate::Bisector::try_stream
+pub struct TryBisectStream<'b, 'v, T, F, Fut> {
+    inner: BisectStream<'b, 'v, T, F, Fut>,
+    failed: bool,
+}
+
+impl<'b, 'v, T, F, Fut> TryBisectStream<'b, 'v, T, F, Fut> {
+    /// The indices from which the next step will be taken.
+    ///
+    /// Returns `None` if the view of the [`Bisector`] is empty.
+    ///
+    /// [`Bisector`]: crate::Bisector
+    pub fn indices(&self) -> Option<Indices> {
+        self.inner.indices
+    }
+}
+
+impl<'b, 'v, T, F, Fut, E, L, R> Stream for TryBisectStream<'b, 'v, T, F, Fut>
+where
+    F: FnMut(&'v T) -> Fut,
+    Fut: Future<Output = Result<ConvergeTo<L, R>, E>>,
+{
+    type Item = Result<Step<L, R>, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if this.failed {
+            return Poll::Ready(None);
+        }
+
+        match this.inner.poll_middle(cx) {
+            Poll::Ready(Some((indices, Ok(result)))) => {
+                let step = this.inner.bisector.bisect(move |_| result, indices);
+                this.inner.indices = Some(step.indices);
+
+                Poll::Ready(Some(Ok(step)))
+            }
+            Poll::Ready(Some((_, Err(err)))) => {
+                this.failed = true;
+
+                Poll::Ready(Some(Err(err)))
+            }
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use crate::{BisectLogError, Commit, ConvergeTo, GitOutcome, Indices};
+use std::fmt::{Display, Formatter};
+
+/// The mark given to a commit in a `git bisect` session.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Mark {
+    /// The commit is from before the regression; `git bisect good` (or `old`, or the term given
+    /// with `--term-old`).
+    Good,
+    /// The commit contains the regression; `git bisect bad` (or `new`, or the term given with
+    /// `--term-new`).
+    Bad,
+    /// The commit could not be tested; `git bisect skip`.
+    Skip,
+}
+
+impl Mark {
+    fn parse(term: &str) -> Option<Self> {
+        match term {
+            "good" | "old" => Some(Self::Good),
+            "bad" | "new" => Some(Self::Bad),
+            "skip" => Some(Self::Skip),
+            _ => None,
+        }
+    }
+}
+
+// The commands of `git bisect` which don't mark a commit, besides `start`.
+const IGNORED_COMMANDS: [&str; 8] = [
+    "terms",
+    "log",
+    "replay",
+    "reset",
+    "run",
+    "visualize",
+    "view",
+    "help",
+];
+
+// The custom terms of a bisection, which `git bisect start` may give for good and bad commits.
+#[derive(Default)]
+struct Terms {
+    old: Option<String>,
+    new: Option<String>,
+}
+
+impl Terms {
+    // Reads the terms from the arguments of `git bisect start`, which `git bisect log` quotes, e.g.
+    // `'--term-old=fixed'`, or `'--term-old' 'fixed'`.
+    fn from_start<'l>(args: impl Iterator<Item = &'l str>) -> Self {
+        let mut terms = Self::default();
+        let mut args = args.map(|arg| arg.trim_matches('\''));
+
+        while let Some(arg) = args.next() {
+            let (option, value) = match arg.split_once('=') {
+                Some((option, value)) => (option, Some(value)),
+                None => (arg, None),
+            };
+
+            let term = match option {
+                "--term-old" | "--term-good" => &mut terms.old,
+                "--term-new" | "--term-bad" => &mut terms.new,
+                _ => continue,
+            };
+
+            *term = value.or_else(|| args.next()).map(str::to_string);
+        }
+
+        terms
+    }
+
+    fn mark(&self, command: &str) -> Option<Mark> {
+        if self.old.as_deref() == Some(command) {
+            Some(Mark::Good)
+        } else if self.new.as_deref() == Some(command) {
+            Some(Mark::Bad)
+        } else {
+            Mark::parse(command)
+        }
+    }
+}
+
+impl Display for Mark {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Good => "good",
+            Self::Bad => "bad",
+            Self::Skip => "skip",
+        })
+    }
+}
+
+/// A single marked commit of a [`BisectLog`].
+///
+/// [`BisectLog`]: crate::BisectLog
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LogEntry {
+    /// How the commit was marked.
+    pub mark: Mark,
+    /// The marked commit.
+    pub commit: Commit,
+}
+
+/// The marks of a `git bisect` session, in the format of `git bisect log`.
+///
+/// A log can be parsed from the output of `git bisect log`, to continue a bisection which was
+/// started with plain `git bisect`, with [`BisectLog::indices`]. The other way round, a log
+/// written with its [`Display`] implementation is accepted by `git bisect replay`.
+///
+/// ```
+/// use bisector::{BisectLog, Commit, Indices};
+///
+/// let log = BisectLog::parse(
+///     "git bisect start\n\
+///      ## bad: [c4] Fix typo\n\
+///      git bisect bad c4\n\
+///      ## good: [c0] Initial commit\n\
+///      git bisect good c0\n\
+///      ## good: [c2] Add tests\n\
+///      git bisect good c2\n",
+/// )
+/// .unwrap();
+///
+/// let candidates = ["c1", "c2", "c3", "c4"].map(Commit::new);
+///
+/// // The first bad commit is c3 or c4
+/// assert_eq!(log.indices(&candidates).unwrap(), Indices::new(2, 3));
+///
+/// assert_eq!(
+///     log.to_string(),
+///     "git bisect start\n\
+///      ## bad: [c4]\n\
+///      git bisect bad c4\n\
+///      ## good: [c0]\n\
+///      git bisect good c0\n\
+///      ## good: [c2]\n\
+///      git bisect good c2\n",
+/// );
+/// ```
+///
+/// [`BisectLog::indices`]: crate::BisectLog::indices
+/// [`Display`]: std::fmt::Display
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct BisectLog {
+    entries: Vec<LogEntry>,
+}
+
+impl BisectLog {
+    /// Create an empty log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse the output of `git bisect log`.
+    ///
+    /// Comments, empty lines, and the commands of `git bisect` which don't mark a commit, such as
+    /// `git bisect start` and `git bisect terms`, are ignored. Commits are marked by `good`, `bad`
+    /// and `skip`, by `old` and `new`, and by the custom terms given to `git bisect start` with
+    /// `--term-old` (or `--term-good`) and `--term-new` (or `--term-bad`). A `skip` command may list
+    /// several commits.
+    ///
+    /// Returns a [`BisectLogError::Parse`] error for any other line, such as a command with an
+    /// unknown term, or for a mark without a commit.
+    ///
+    /// [`BisectLogError::Parse`]: crate::BisectLogError::Parse
+    pub fn parse(log: &str) -> Result<Self, BisectLogError> {
+        let mut entries = Vec::new();
+        let mut terms = Terms::default();
+
+        for (index, line) in log.lines().enumerate() {
+            let trimmed = line.trim();
+
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let error = || BisectLogError::Parse {
+                line: index + 1,
+                content: line.to_string(),
+            };
+
+            let mut words = trimmed.split_whitespace();
+
+            let command = match (words.next(), words.next()) {
+                (Some("git"), Some("bisect")) => words.next(),
+                (Some("git-bisect"), command) => command,
+                _ => return Err(error()),
+            };
+
+            let mark = match command {
+                Some("start") => {
+                    terms = Terms::from_start(words);
+                    continue;
+                }
+                Some(command) if IGNORED_COMMANDS.contains(&command) => continue,
+                Some(command) => terms.mark(command).ok_or_else(error)?,
+                None => return Err(error()),
+            };
+
+            let start = entries.len();
+            entries.extend(words.map(|commit| LogEntry {
+                mark,
+                commit: Commit::new(commit),
+            }));
+
+            if entries.len() == start {
+                return Err(error());
+            }
+        }
+
+        Ok(Self { entries })
+    }
+
+    /// Add a marked commit to the log.
+    pub fn push(&mut self, mark: Mark, commit: Commit) {
+        self.entries.push(LogEntry { mark, commit });
+    }
+
+    /// The marked commits, in the order in which they were marked.
+    pub fn entries(&self) -> &[LogEntry] {
+        &self.entries
+    }
+
+    /// The indices of the given candidates which remain to be bisected, after applying the marks
+    /// of the log.
+    ///
+    /// The candidates must form a line, ordered from oldest to newest, such as the candidates
+    /// listed by [`Repository::candidates`] for a range which only follows first parents (see
+    /// [`RevRange::with_first_parent`]). Like the [`Bisector`], the right index starts at the last
+    /// candidate, which is assumed to be bad. Each good candidate moves the left index past it,
+    /// and each bad candidate moves the right index to it. Marked commits which aren't
+    /// candidates, such as the good commit which bounds the range, or the commits of a merged
+    /// branch, are ignored, and so are skipped commits.
+    ///
+    /// When the candidates include the commits of merged branches, a good commit on a merged
+    /// branch may be listed after a bad commit on the mainline, even though the log is valid, so
+    /// such candidates are not supported.
+    ///
+    /// Returns [`BisectLogError::NoCandidates`] if there are no candidates, and
+    /// [`BisectLogError::Inconsistent`] if a good candidate comes after a bad candidate.
+    ///
+    /// [`Repository::candidates`]: crate::Repository::candidates
+    /// [`RevRange::with_first_parent`]: crate::RevRange::with_first_parent
+    /// [`Bisector`]: crate::Bisector
+    /// [`BisectLogError::NoCandidates`]: crate::BisectLogError::NoCandidates
+    /// [`BisectLogError::Inconsistent`]: crate::BisectLogError::Inconsistent
+    pub fn indices(&self, candidates: &[Commit]) -> Result<Indices, BisectLogError> {
+        let last = candidates
+            .len()
+            .checked_sub(1)
+            .ok_or(BisectLogError::NoCandidates)?;
+
+        let position =
+            |commit: &Commit| candidates.iter().position(|candidate| candidate == commit);
+
+        // The latest good candidate, and the earliest bad candidate
+        let mut good = None;
+        let mut bad = last;
+
+        for entry in &self.entries {
+            match (entry.mark, position(&entry.commit)) {
+                (Mark::Good, Some(index)) => {
+                    good = Some(good.map_or(index, |good| index.max(good)))
+                }
+                (Mark::Bad, Some(index)) => bad = bad.min(index),
+                _ => {}
+            }
+        }
+
+        match good {
+            Some(good) if good >= bad => Err(BisectLogError::Inconsistent {
+                good: candidates[good].clone(),
+                bad: candidates[bad].clone(),
+            }),
+            Some(good) => Ok(Indices::new(good + 1, bad)),
+            None => Ok(Indices::new(0, bad)),
+        }
+    }
+}
+
+impl Display for BisectLog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "git bisect start")?;
+
+        for LogEntry { mark, commit } in &self.entries {
+            writeln!(f, "# {}: [{}]", mark, commit)?;
+            writeln!(f, "git bisect {} {}", mark, commit)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<L, R> GitOutcome<L, R> {
+    /// The log of this bisection, in the format of `git bisect log`, which can be given to
+    /// `git bisect replay`.
+    ///
+    /// The log marks the last candidate as bad and the good commit of the range as good, followed
+    /// by the mark of each probed candidate, in the order in which they were probed.
+    pub fn bisect_log(&self) -> BisectLog {
+        let mut log = BisectLog::new();
+
+        if let Some(bad) = self.candidates.last() {
+            log.push(Mark::Bad, bad.clone());
+        }
+
+        log.push(Mark::Good, self.good.clone());
+
+        for probe in &self.probes {
+            let mark = match probe.result {
+                ConvergeTo::Left(_) => Mark::Bad,
+                ConvergeTo::Right(_) => Mark::Good,
+            };
+
+            log.push(mark, self.candidates[probe.index].clone());
+        }
+
+        log
+    }
+}
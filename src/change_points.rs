@@ -0,0 +1,176 @@
+use crate::{Bisectable, Bisector, EmptySliceError, Indices, TryRunError};
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+
+/// A maximal run of consecutive elements for which the function given to
+/// [`Bisector::change_points`] produced the same value.
+///
+/// [`Bisector::change_points`]: crate::Bisector::change_points
+pub struct Segment<V> {
+    /// The indices of the first and the last element of the segment; both are part of the segment.
+    pub indices: Indices,
+    /// The value which the function produced for the elements of the segment.
+    pub value: V,
+}
+
+/// The segments found by [`Bisector::change_points`] and [`Bisector::try_change_points`].
+///
+/// [`Bisector::change_points`]: crate::Bisector::change_points
+/// [`Bisector::try_change_points`]: crate::Bisector::try_change_points
+pub struct ChangePoints<V> {
+    /// The segments of the view, ordered by index. Together they cover the complete view, and the
+    /// values of adjacent segments differ.
+    pub segments: Vec<Segment<V>>,
+    /// The number of times the function was called.
+    pub probes: usize,
+}
+
+impl<V> ChangePoints<V> {
+    /// The indices at which the value changes, i.e. the index of the first element of each segment
+    /// but the first.
+    pub fn indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.segments
+            .iter()
+            .skip(1)
+            .map(|segment| segment.indices.left)
+    }
+}
+
+impl<'v, T, S> Bisector<'v, T, S>
+where
+    S: Bisectable<Item = T> + ?Sized,
+{
+    /// Find every position in the view where the value produced by `f` changes, for functions which
+    /// produce more than two outcomes, such as an exit code, a hash of the output, or a number of
+    /// warnings.
+    ///
+    /// The function `f` is assumed to be piecewise constant, where each distinct value occupies a
+    /// single contiguous [`Segment`] of the view. Under this assumption, a region of the view whose
+    /// first and last element produce the same value is constant, and is not probed any further.
+    /// Other regions are bisected until each change point is found. This takes a number of calls
+    /// to `f` logarithmic in the length of the view, per change point. If a value reappears after a
+    /// different value, the change points in between may be missed when the surrounding probes
+    /// happen to produce the same value.
+    ///
+    /// The view must not be empty. If it is, an [`EmptySliceError`] `Err` result will be returned.
+    ///
+    /// ```
+    /// use bisector::{Bisector, Indices};
+    ///
+    /// // The exit code of each version of a program
+    /// let versions = (0..100).collect::<Vec<u32>>();
+    /// let bisector = Bisector::new(&versions);
+    ///
+    /// let found = bisector
+    ///     .change_points(|&version| match version {
+    ///         0..=41 => 0,
+    ///         42..=86 => 101,
+    ///         _ => 2,
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(found.indices().collect::<Vec<_>>(), vec![42, 87]);
+    /// assert_eq!(found.segments[1].indices, Indices::new(42, 86));
+    /// assert_eq!(found.segments[1].value, 101);
+    /// ```
+    ///
+    /// See also:
+    /// * [`Bisector::try_change_points`]: A variant of [`change_points`] which can be used when the
+    ///   function is fallible.
+    ///
+    /// [`Segment`]: crate::Segment
+    /// [`EmptySliceError`]: crate::EmptySliceError
+    /// [`Bisector::try_change_points`]: crate::Bisector::try_change_points
+    /// [`change_points`]: crate::Bisector::change_points
+    pub fn change_points<F, V>(&self, mut f: F) -> Result<ChangePoints<V>, EmptySliceError>
+    where
+        F: FnMut(&T) -> V,
+        V: Eq,
+    {
+        self.find_change_points(|value| Ok::<_, Infallible>(f(value)))
+            .map_err(|err| match err {
+                TryRunError::EmptySlice(err) => err,
+                TryRunError::Convergence(infallible) => match infallible {},
+            })
+    }
+
+    /// This method can be used when the function is fallible.
+    /// Otherwise exactly the same as [`Bisector::change_points`].
+    ///
+    /// Returns a [`TryRunError::EmptySlice`] error if the view is empty, and a
+    /// [`TryRunError::Convergence`] error if the function failed.
+    ///
+    /// [`Bisector::change_points`]: crate::Bisector::change_points
+    /// [`TryRunError::EmptySlice`]: crate::TryRunError::EmptySlice
+    /// [`TryRunError::Convergence`]: crate::TryRunError::Convergence
+    pub fn try_change_points<F, E, V>(&self, f: F) -> Result<ChangePoints<V>, TryRunError<E>>
+    where
+        F: FnMut(&T) -> Result<V, E>,
+        V: Eq,
+    {
+        self.find_change_points(f)
+    }
+
+    fn find_change_points<F, E, V>(&self, mut f: F) -> Result<ChangePoints<V>, TryRunError<E>>
+    where
+        F: FnMut(&T) -> Result<V, E>,
+        V: Eq,
+    {
+        let source = self.source();
+        let last = source
+            .len()
+            .checked_sub(1)
+            .ok_or(TryRunError::EmptySlice(EmptySliceError))?;
+
+        let mut probe = |index: usize| f(&source.get(index)).map_err(TryRunError::Convergence);
+
+        let mut probed = BTreeMap::new();
+        probed.insert(0, probe(0)?);
+
+        if last > 0 {
+            probed.insert(last, probe(last)?);
+        }
+
+        // Regions are explored depth first, left before right, so the change points are found in
+        // order
+        let mut starts = vec![0];
+        let mut regions = vec![Indices::new(0, last)];
+
+        while let Some(Indices { left, right }) = regions.pop() {
+            if probed[&left] == probed[&right] {
+                continue;
+            }
+
+            if right - left == 1 {
+                starts.push(right);
+                continue;
+            }
+
+            let middle = Indices::new(left, right).middle();
+            probed.insert(middle, probe(middle)?);
+
+            regions.push(Indices::new(middle, right));
+            regions.push(Indices::new(left, middle));
+        }
+
+        let probes = probed.len();
+        let ends = starts
+            .iter()
+            .skip(1)
+            .map(|start| start - 1)
+            .chain(Some(last));
+
+        let segments = starts
+            .iter()
+            .zip(ends)
+            .map(|(&start, end)| Segment {
+                indices: Indices::new(start, end),
+                // Every segment starts at a probed element: either the first element of the view,
+                // or the right element of a region in which the value changed
+                value: probed.remove(&start).expect("start of segment was probed"),
+            })
+            .collect();
+
+        Ok(ChangePoints { segments, probes })
+    }
+}
@@ -0,0 +1,165 @@
+use crate::git::{git, widen};
+use crate::{CheckoutMode, Commit, ConvergeTo, GitError, GitOutcome, Repository};
+use std::convert::Infallible;
+use std::path::{Path, PathBuf};
+
+/// The result of confirming the first bad commit of a bisection, as produced by
+/// [`Repository::confirm`] and [`Repository::try_confirm`].
+///
+/// [`Repository::confirm`]: crate::Repository::confirm
+/// [`Repository::try_confirm`]: crate::Repository::try_confirm
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Confirmation<L, R> {
+    /// With the first bad commit reverted, the bad revision converges to the right, i.e. it's
+    /// good again. Holds the output of the convergence function.
+    Confirmed(R),
+    /// The first bad commit can't be reverted cleanly on top of the bad revision, so it couldn't
+    /// be tested.
+    RevertConflicts,
+    /// With the first bad commit reverted, the bad revision still converges to the left. Either the
+    /// bisection named the wrong commit, for example because the convergence function is flaky, or
+    /// several commits are bad. Holds the output of the convergence function.
+    StillFailing(L),
+}
+
+impl Repository {
+    /// Confirm the first bad commit of the given outcome, by reverting it on top of the bad
+    /// revision, and running the convergence function once more.
+    ///
+    /// The bad revision, which is the last candidate of the outcome, is checked out in a throwaway
+    /// `git worktree` at the given path, which must not exist yet, and is removed afterwards. The
+    /// first bad commit is reverted in its working tree, without committing; merge commits are
+    /// reverted relative to their first parent.
+    ///
+    /// The convergence function `f` is given the bad commit, and the path of the worktree, just
+    /// like while bisecting, so the function which was given to [`Repository::run`] can be used
+    /// again. The result is stored in [`GitOutcome::confirmation`], and returned.
+    ///
+    /// ```no_run
+    /// use bisector::{CheckoutMode, Confirmation, ConvergeTo, Repository, RevRange};
+    /// use std::path::Path;
+    /// use std::process::Command;
+    ///
+    /// let repository = Repository::open(".").unwrap();
+    /// let range = RevRange::new("v1.0.0", "main");
+    ///
+    /// let cargo_test = |_: &_, path: &Path| {
+    ///     let status = Command::new("cargo")
+    ///         .arg("test")
+    ///         .current_dir(path)
+    ///         .status()
+    ///         .unwrap();
+    ///
+    ///     if status.success() {
+    ///         ConvergeTo::Right(())
+    ///     } else {
+    ///         ConvergeTo::<(), ()>::Left(())
+    ///     }
+    /// };
+    ///
+    /// let mut outcome = repository
+    ///     .run(&range, CheckoutMode::InPlace, cargo_test)
+    ///     .unwrap();
+    ///
+    /// match repository.confirm(&mut outcome, "../confirm", cargo_test).unwrap() {
+    ///     Confirmation::Confirmed(()) => println!("first bad commit: {}", outcome.commit),
+    ///     Confirmation::RevertConflicts => println!("unable to revert {}", outcome.commit),
+    ///     Confirmation::StillFailing(()) => println!("{} is not the only culprit", outcome.commit),
+    /// }
+    /// ```
+    ///
+    /// See also:
+    /// * [`Repository::try_confirm`]: A variant of [`confirm`] which can be used when the
+    ///   convergence function is fallible.
+    ///
+    /// [`Repository::run`]: crate::Repository::run
+    /// [`GitOutcome::confirmation`]: crate::GitOutcome::confirmation
+    /// [`Repository::try_confirm`]: crate::Repository::try_confirm
+    /// [`confirm`]: crate::Repository::confirm
+    pub fn confirm<'o, F, L, R>(
+        &self,
+        outcome: &'o mut GitOutcome<L, R>,
+        worktree: impl Into<PathBuf>,
+        mut f: F,
+    ) -> Result<&'o Confirmation<L, R>, GitError>
+    where
+        F: FnMut(&Commit, &Path) -> ConvergeTo<L, R>,
+    {
+        self.try_confirm(outcome, worktree, |commit, path| {
+            Ok::<_, Infallible>(f(commit, path))
+        })
+    }
+
+    /// This method can be used when the convergence function is fallible.
+    /// Otherwise exactly the same as [`Repository::confirm`].
+    ///
+    /// An error of the convergence function is returned as a [`GitError::Convergence`] error, in
+    /// which case the confirmation of the outcome is left as it was.
+    ///
+    /// [`Repository::confirm`]: crate::Repository::confirm
+    /// [`GitError::Convergence`]: crate::GitError::Convergence
+    pub fn try_confirm<'o, F, E, L, R>(
+        &self,
+        outcome: &'o mut GitOutcome<L, R>,
+        worktree: impl Into<PathBuf>,
+        mut f: F,
+    ) -> Result<&'o Confirmation<L, R>, GitError<E>>
+    where
+        F: FnMut(&Commit, &Path) -> Result<ConvergeTo<L, R>, E>,
+    {
+        let bad = outcome
+            .candidates
+            .last()
+            .ok_or(GitError::NoCandidates)?
+            .clone();
+
+        let mut checkout = self
+            .checkout(CheckoutMode::Worktree(worktree.into()))
+            .map_err(widen)?;
+        let path = checkout.switch(&bad).map_err(widen)?;
+
+        let confirmation = if revert(path, &outcome.commit).map_err(widen)? {
+            match f(&bad, path).map_err(GitError::Convergence)? {
+                ConvergeTo::Left(out) => Confirmation::StillFailing(out),
+                ConvergeTo::Right(out) => Confirmation::Confirmed(out),
+            }
+        } else {
+            Confirmation::RevertConflicts
+        };
+
+        checkout.restore().map_err(widen)?;
+
+        Ok(outcome.confirmation.insert(confirmation))
+    }
+}
+
+// Reverts the given commit in the working tree at the given path, without committing. Returns
+// whether the commit was reverted cleanly, or `false` if the revert conflicts.
+fn revert(path: &Path, commit: &Commit) -> Result<bool, GitError> {
+    let parents = git(
+        path,
+        ["rev-list", "--parents", "--max-count=1", commit.id()],
+    )?;
+
+    let mut args = vec!["revert", "--no-commit"];
+
+    // The commit itself is listed first, followed by its parents
+    if parents.split_whitespace().count() > 2 {
+        args.extend(["--mainline", "1"]);
+    }
+
+    args.push(commit.id());
+
+    match git(path, args) {
+        Ok(_) => Ok(true),
+        Err(err) => {
+            let unmerged = git(path, ["diff", "--name-only", "--diff-filter=U"])?;
+
+            if unmerged.is_empty() {
+                Err(err)
+            } else {
+                Ok(false)
+            }
+        }
+    }
+}
@@ -0,0 +1,384 @@
+use crate::{ConvergeTo, InvalidDagError, Probe};
+use std::convert::Infallible;
+
+/// The outcome of a bisection over a directed acyclic graph, as produced by [`DagBisector::run`]
+/// and [`DagBisector::try_run`].
+///
+/// [`DagBisector::run`]: crate::DagBisector::run
+/// [`DagBisector::try_run`]: crate::DagBisector::try_run
+pub struct DagOutcome<'v, T, L, R> {
+    /// The index of the first bad node.
+    pub index: usize,
+    /// The first bad node.
+    pub node: &'v T,
+    /// The output of the convergence function for each probed node, in the order in which they
+    /// were probed.
+    pub probes: Vec<Probe<L, R>>,
+}
+
+/// Bisects a directed acyclic graph of nodes, such as a commit history with merge commits, to find
+/// the first bad node.
+///
+/// Nodes are identified by their index in the view, and `parents[index]` holds the indices of the
+/// parents of the node at `index`. Like `git bisect`, the bisector starts from one node which is
+/// known to be bad, and any number of nodes which are known to be good. The candidates are the
+/// ancestors of the bad node (including the bad node itself) which aren't ancestors of a good node
+/// (or a good node itself).
+///
+/// Since the candidates don't form a list, the middle of a list is not a meaningful probe. Instead,
+/// for each candidate, the number of candidates which are its ancestors (including itself) is
+/// counted, and the candidate for which this number is closest to half of the candidates is probed,
+/// just like `git bisect` does. Whatever the outcome, about half of the candidates remain.
+///
+/// Like the [`Bisector`], the convergence function converges to the left for bad nodes:
+/// * [`ConvergeTo::Left`]: the node is bad; it becomes the new bad node
+/// * [`ConvergeTo::Right`]: the node is good; it and its ancestors are no longer candidates
+///
+/// The bisector itself is stateless: [`DagBisector::with_bad`] and [`DagBisector::with_good`]
+/// return a bisector with the given mark added, and [`DagBisector::next_probe`] returns the node to
+/// probe next. To run the bisection to completion, [`DagBisector::run`] can be used instead.
+///
+/// [`Bisector`]: crate::Bisector
+/// [`ConvergeTo::Left`]: crate::ConvergeTo::Left
+/// [`ConvergeTo::Right`]: crate::ConvergeTo::Right
+/// [`DagBisector::with_bad`]: crate::DagBisector::with_bad
+/// [`DagBisector::with_good`]: crate::DagBisector::with_good
+/// [`DagBisector::next_probe`]: crate::DagBisector::next_probe
+/// [`DagBisector::run`]: crate::DagBisector::run
+#[derive(Debug)]
+pub struct DagBisector<'v, T> {
+    view: &'v [T],
+    parents: Vec<Vec<usize>>,
+    bad: usize,
+    good: Vec<usize>,
+}
+
+// Not derived, since the nodes themselves don't need to be cloned
+impl<'v, T> Clone for DagBisector<'v, T> {
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view,
+            parents: self.parents.clone(),
+            bad: self.bad,
+            good: self.good.clone(),
+        }
+    }
+}
+
+impl<'v, T> DagBisector<'v, T> {
+    /// Create a new bisector for the nodes in the given view, where `parents[index]` holds the
+    /// indices of the parents of the node at `index`, and `bad` is the index of a node which is
+    /// known to be bad, such as the tip of a branch.
+    ///
+    /// Returns an [`InvalidDagError`] if the number of parent lists doesn't match the number of
+    /// nodes, if an index is out of range, or if the parent edges contain a cycle.
+    ///
+    /// [`InvalidDagError`]: crate::InvalidDagError
+    pub fn new(
+        view: &'v [T],
+        parents: Vec<Vec<usize>>,
+        bad: usize,
+    ) -> Result<Self, InvalidDagError> {
+        if parents.len() != view.len() {
+            return Err(InvalidDagError::LengthMismatch {
+                nodes: view.len(),
+                parents: parents.len(),
+            });
+        }
+
+        if let Some(&index) = std::iter::once(&bad)
+            .chain(parents.iter().flatten())
+            .find(|&&index| index >= view.len())
+        {
+            return Err(InvalidDagError::OutOfRange { index });
+        }
+
+        if has_cycle(&parents) {
+            return Err(InvalidDagError::Cycle);
+        }
+
+        Ok(Self {
+            view,
+            parents,
+            bad,
+            good: Vec::new(),
+        })
+    }
+
+    /// Mark the node at the given index as bad. It replaces the previous bad node, and should be
+    /// one of its ancestors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of range.
+    pub fn with_bad(mut self, index: usize) -> Self {
+        assert!(index < self.view.len(), "node index {} out of range", index);
+        self.bad = index;
+        self
+    }
+
+    /// Mark the node at the given index as good. Good nodes accumulate: neither the node, nor any
+    /// of its ancestors, remains a candidate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of range.
+    pub fn with_good(mut self, index: usize) -> Self {
+        assert!(index < self.view.len(), "node index {} out of range", index);
+        self.good.push(index);
+        self
+    }
+
+    /// The nodes of the graph.
+    pub fn view(&self) -> &'v [T] {
+        self.view
+    }
+
+    /// The index of the node which is currently marked as bad.
+    pub fn bad(&self) -> usize {
+        self.bad
+    }
+
+    /// The indices of the candidates for the first bad node, in ascending order. The bad node is
+    /// always a candidate.
+    pub fn candidates(&self) -> Vec<usize> {
+        let candidates = self.candidate_set();
+
+        (0..self.view.len())
+            .filter(|&index| candidates[index])
+            .collect()
+    }
+
+    /// The index of the node which should be probed next, or `None` if the bad node is the only
+    /// candidate left, in which case it's the first bad node.
+    ///
+    /// Of all candidates, the node whose number of candidate ancestors (including itself) is
+    /// closest to half of the number of candidates is chosen. Ties are broken in favour of the
+    /// lowest index.
+    pub fn next_probe(&self) -> Option<usize> {
+        let candidates = self.candidate_set();
+        let total = candidates.iter().filter(|&&candidate| candidate).count();
+        let weights = self.ancestor_counts(&candidates);
+
+        (0..self.view.len())
+            .filter(|&index| candidates[index] && index != self.bad)
+            .map(|index| {
+                let weight = weights[index];
+                (weight.min(total - weight), index)
+            })
+            // Largest distance from either end first, then the lowest index
+            .max_by(|(left, left_index), (right, right_index)| {
+                left.cmp(right).then(right_index.cmp(left_index))
+            })
+            .map(|(_, index)| index)
+    }
+
+    /// Run the bisection to completion, and return the first bad node.
+    ///
+    /// ```
+    /// use bisector::{ConvergeTo, DagBisector};
+    ///
+    /// //   0 - 1 - 2 ----- 5 - 6
+    /// //        \         /
+    /// //         3 ----- 4
+    /// let commits = ["a", "b", "c", "d", "e", "merge", "tip"];
+    /// let parents = vec![vec![], vec![0], vec![1], vec![1], vec![3], vec![2, 4], vec![5]];
+    ///
+    /// let bisector = DagBisector::new(&commits, parents, 6).unwrap().with_good(0);
+    ///
+    /// // Commit "e" on the side branch introduced the bug
+    /// let outcome = bisector.run(|&commit| {
+    ///     if ["e", "merge", "tip"].contains(&commit) {
+    ///         ConvergeTo::Left(())
+    ///     } else {
+    ///         ConvergeTo::<(), ()>::Right(())
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(*outcome.node, "e");
+    /// ```
+    ///
+    /// See also:
+    /// * [`DagBisector::try_run`]: A variant of [`run`] which can be used when the convergence
+    ///   function is fallible.
+    ///
+    /// [`DagBisector::try_run`]: crate::DagBisector::try_run
+    /// [`run`]: crate::DagBisector::run
+    pub fn run<F, L, R>(&self, mut f: F) -> DagOutcome<'v, T, L, R>
+    where
+        F: FnMut(&'v T) -> ConvergeTo<L, R>,
+    {
+        self.try_run(|node| Ok::<_, Infallible>(f(node)))
+            .unwrap_or_else(|infallible| match infallible {})
+    }
+
+    /// This method can be used when the convergence function is fallible.
+    /// Otherwise exactly the same as [`DagBisector::run`].
+    ///
+    /// The bisection stops as soon as the convergence function returns an error.
+    ///
+    /// [`DagBisector::run`]: crate::DagBisector::run
+    pub fn try_run<F, E, L, R>(&self, mut f: F) -> Result<DagOutcome<'v, T, L, R>, E>
+    where
+        F: FnMut(&'v T) -> Result<ConvergeTo<L, R>, E>,
+    {
+        let mut bisector = self.clone();
+        let mut probes = Vec::new();
+
+        while let Some(index) = bisector.next_probe() {
+            let result = f(&self.view[index])?;
+
+            bisector = match result {
+                ConvergeTo::Left(_) => bisector.with_bad(index),
+                ConvergeTo::Right(_) => bisector.with_good(index),
+            };
+
+            probes.push(Probe { index, result });
+        }
+
+        Ok(DagOutcome {
+            index: bisector.bad,
+            node: &self.view[bisector.bad],
+            probes,
+        })
+    }
+
+    // Whether each node is a candidate: an ancestor of the bad node, but not of a good node.
+    fn candidate_set(&self) -> Vec<bool> {
+        let mut candidates = vec![false; self.view.len()];
+
+        for index in self.reachable(&[self.bad]) {
+            candidates[index] = true;
+        }
+
+        for index in self.reachable(&self.good) {
+            candidates[index] = false;
+        }
+
+        candidates[self.bad] = true;
+        candidates
+    }
+
+    // The number of candidates which are ancestors of each candidate (including itself), or zero
+    // for nodes which aren't candidates.
+    //
+    // The candidates are visited in topological order, parents first, and the ancestors of each
+    // candidate are the union of the bitsets of its parents, so all counts are computed in a single
+    // pass. The bitset of a candidate is dropped as soon as all of its children have been visited.
+    fn ancestor_counts(&self, candidates: &[bool]) -> Vec<usize> {
+        let nodes = (0..self.view.len())
+            .filter(|&index| candidates[index])
+            .collect::<Vec<_>>();
+
+        // The position of each candidate in `nodes`, which is its bit in the bitsets
+        let mut bits = vec![usize::MAX; self.view.len()];
+
+        for (bit, &index) in nodes.iter().enumerate() {
+            bits[index] = bit;
+        }
+
+        let parents_of = |bit: usize| {
+            self.parents[nodes[bit]]
+                .iter()
+                .filter(|&&parent| candidates[parent])
+                .map(|&parent| bits[parent])
+        };
+
+        let mut children = vec![Vec::new(); nodes.len()];
+        let mut unvisited_parents = vec![0usize; nodes.len()];
+
+        for (bit, unvisited) in unvisited_parents.iter_mut().enumerate() {
+            for parent in parents_of(bit) {
+                children[parent].push(bit);
+                *unvisited += 1;
+            }
+        }
+
+        let mut unvisited_children = children.iter().map(Vec::len).collect::<Vec<_>>();
+        let mut ancestors = vec![Vec::new(); nodes.len()];
+        let mut ready = (0..nodes.len())
+            .filter(|&bit| unvisited_parents[bit] == 0)
+            .collect::<Vec<_>>();
+        let mut counts = vec![0; self.view.len()];
+        let words = nodes.len() / 64 + 1;
+
+        while let Some(bit) = ready.pop() {
+            let mut set = vec![0u64; words];
+            set[bit / 64] |= 1 << (bit % 64);
+
+            for parent in parents_of(bit) {
+                for (word, parent_word) in set.iter_mut().zip(&ancestors[parent]) {
+                    *word |= parent_word;
+                }
+
+                unvisited_children[parent] -= 1;
+
+                if unvisited_children[parent] == 0 {
+                    ancestors[parent] = Vec::new();
+                }
+            }
+
+            counts[nodes[bit]] = set.iter().map(|word| word.count_ones() as usize).sum();
+            ancestors[bit] = set;
+
+            for &child in &children[bit] {
+                unvisited_parents[child] -= 1;
+
+                if unvisited_parents[child] == 0 {
+                    ready.push(child);
+                }
+            }
+        }
+
+        counts
+    }
+
+    // The nodes which are reachable from the given nodes, by following parent edges, including the
+    // given nodes themselves.
+    fn reachable(&self, from: &[usize]) -> Vec<usize> {
+        let mut visited = vec![false; self.view.len()];
+        let mut stack = from.to_vec();
+        let mut reached = Vec::new();
+
+        while let Some(index) = stack.pop() {
+            if visited[index] {
+                continue;
+            }
+
+            visited[index] = true;
+            reached.push(index);
+            stack.extend(&self.parents[index]);
+        }
+
+        reached
+    }
+}
+
+// Kahn's algorithm: the graph is acyclic if every node can be removed once all of its children
+// have been removed.
+fn has_cycle(parents: &[Vec<usize>]) -> bool {
+    let mut children = vec![0usize; parents.len()];
+
+    for &parent in parents.iter().flatten() {
+        children[parent] += 1;
+    }
+
+    let mut leaves = (0..parents.len())
+        .filter(|&index| children[index] == 0)
+        .collect::<Vec<_>>();
+    let mut removed = 0;
+
+    while let Some(index) = leaves.pop() {
+        removed += 1;
+
+        for &parent in &parents[index] {
+            children[parent] -= 1;
+
+            if children[parent] == 0 {
+                leaves.push(parent);
+            }
+        }
+    }
+
+    removed != parents.len()
+}
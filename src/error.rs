@@ -1,3 +1,4 @@
+use std::convert::Infallible;
 use std::fmt::{Debug, Display, Formatter};
 
 #[derive(Debug, Eq, PartialEq)]
@@ -12,3 +13,249 @@ impl Display for EmptySliceError {
 }
 
 impl std::error::Error for EmptySliceError {}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum TryRunError<E> {
+    EmptySlice(EmptySliceError),
+    Convergence(E),
+}
+
+impl<E: Display> Display for TryRunError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptySlice(err) => Display::fmt(err, f),
+            Self::Convergence(err) => f.write_fmt(format_args!(
+                "The convergence function failed with: {}",
+                err
+            )),
+        }
+    }
+}
+
+impl<E: Debug + Display> std::error::Error for TryRunError<E> {}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct InvalidNoiseError;
+
+impl Display for InvalidNoiseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "Expected false positive and false negative rates in the range [0, 0.5)"
+        ))
+    }
+}
+
+impl std::error::Error for InvalidNoiseError {}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum InvalidRangeError {
+    Empty,
+    TooLarge,
+}
+
+impl Display for InvalidRangeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => f.write_fmt(format_args!(
+                "Expected a non-empty range, but the given range was empty"
+            )),
+            Self::TooLarge => f.write_fmt(format_args!(
+                "Expected a range with at most usize::MAX + 1 values, but the given range was larger"
+            )),
+        }
+    }
+}
+
+impl std::error::Error for InvalidRangeError {}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum InvalidDagError {
+    LengthMismatch { nodes: usize, parents: usize },
+    OutOfRange { index: usize },
+    Cycle,
+}
+
+impl Display for InvalidDagError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LengthMismatch { nodes, parents } => f.write_fmt(format_args!(
+                "Expected the parents of each of the {} nodes, but got the parents of {} nodes",
+                nodes, parents
+            )),
+            Self::OutOfRange { index } => f.write_fmt(format_args!(
+                "Expected node indices to be in range, but got index {}",
+                index
+            )),
+            Self::Cycle => f.write_fmt(format_args!(
+                "Expected the parent edges to form a directed acyclic graph, but found a cycle"
+            )),
+        }
+    }
+}
+
+impl std::error::Error for InvalidDagError {}
+
+#[derive(Debug, PartialEq)]
+pub struct NotBracketedError {
+    pub left: f64,
+    pub right: f64,
+}
+
+impl Display for NotBracketedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "Expected the function to have a different sign at both endpoints, but got {} and {}",
+            self.left, self.right
+        ))
+    }
+}
+
+impl std::error::Error for NotBracketedError {}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum ValidationError<L, R, E = Infallible> {
+    TooShort,
+    BothLeft { first: L, last: L },
+    BothRight { first: R, last: R },
+    Inverted { first: L, last: R },
+    Convergence(E),
+}
+
+impl<L, R, E: Display> Display for ValidationError<L, R, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooShort => f.write_fmt(format_args!(
+                "Expected a view with at least two elements, to validate its endpoints"
+            )),
+            Self::BothLeft { .. } => f.write_fmt(format_args!(
+                "Expected the first element to converge to the right, but both endpoints converged to the left"
+            )),
+            Self::BothRight { .. } => f.write_fmt(format_args!(
+                "Expected the last element to converge to the left, but both endpoints converged to the right"
+            )),
+            Self::Inverted { .. } => f.write_fmt(format_args!(
+                "Expected the first element to converge to the right and the last element to the left, but they converged the other way round"
+            )),
+            Self::Convergence(err) => f.write_fmt(format_args!(
+                "The convergence function failed with: {}",
+                err
+            )),
+        }
+    }
+}
+
+impl<L: Debug, R: Debug, E: Debug + Display> std::error::Error for ValidationError<L, R, E> {}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum MinimizeError<R, S, E = Infallible> {
+    Passes(R),
+    Unresolved(S),
+    Convergence(E),
+}
+
+impl<R, S, E: Display> Display for MinimizeError<R, S, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Passes(_) => {
+                f.write_fmt(format_args!("Expected the input to fail, but it passed"))
+            }
+            Self::Unresolved(_) => f.write_fmt(format_args!(
+                "Expected the input to fail, but its outcome was unresolved"
+            )),
+            Self::Convergence(err) => {
+                f.write_fmt(format_args!("The test function failed with: {}", err))
+            }
+        }
+    }
+}
+
+impl<R: Debug, S: Debug, E: Debug + Display> std::error::Error for MinimizeError<R, S, E> {}
+
+#[cfg(feature = "git")]
+#[derive(Debug)]
+pub enum GitError<E = Infallible> {
+    Io(std::io::Error),
+    Command {
+        args: Vec<String>,
+        status: Option<i32>,
+        stderr: String,
+    },
+    NoCandidates,
+    DirtyWorkingTree,
+    Convergence(E),
+}
+
+#[cfg(feature = "git")]
+impl<E: Display> Display for GitError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => f.write_fmt(format_args!("Unable to run git: {}", err)),
+            Self::Command {
+                args,
+                status,
+                stderr,
+            } => f.write_fmt(format_args!(
+                "Expected `git {}` to succeed, but it exited with {}: {}",
+                args.join(" "),
+                status.map_or_else(|| "a signal".to_string(), |code| format!("code {}", code)),
+                stderr.trim()
+            )),
+            Self::NoCandidates => f.write_fmt(format_args!(
+                "Expected at least one candidate commit between the good and the bad revision"
+            )),
+            Self::DirtyWorkingTree => f.write_fmt(format_args!(
+                "Expected the tracked files of the working tree to have no local changes"
+            )),
+            Self::Convergence(err) => f.write_fmt(format_args!(
+                "The convergence function failed with: {}",
+                err
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "git")]
+impl<E: Debug + Display> std::error::Error for GitError<E> {}
+
+#[cfg(feature = "git")]
+#[derive(Debug, Eq, PartialEq)]
+pub enum BisectLogError {
+    /// A line of the log is neither a comment, nor a `git bisect` command which is understood.
+    Parse {
+        /// The line number, starting at 1.
+        line: usize,
+        /// The content of the line.
+        content: String,
+    },
+    /// The log was applied to an empty list of candidates.
+    NoCandidates,
+    /// A candidate marked as good comes after a candidate marked as bad.
+    Inconsistent {
+        /// The latest candidate marked as good.
+        good: crate::Commit,
+        /// The earliest candidate marked as bad.
+        bad: crate::Commit,
+    },
+}
+
+#[cfg(feature = "git")]
+impl Display for BisectLogError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse { line, content } => f.write_fmt(format_args!(
+                "Expected a `git bisect` command or a comment on line {}, but got: {}",
+                line, content
+            )),
+            Self::NoCandidates => f.write_fmt(format_args!(
+                "Expected at least one candidate commit to apply the log to"
+            )),
+            Self::Inconsistent { good, bad } => f.write_fmt(format_args!(
+                "Expected good commits to precede bad commits, but good commit {} comes after bad commit {}",
+                good, bad
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "git")]
+impl std::error::Error for BisectLogError {}
@@ -0,0 +1,213 @@
+use crate::{Bisectable, Bisector, ConvergeTo, Indices, Integer, Probe, RangeBisector};
+use std::convert::Infallible;
+
+/// The output of an exponential (galloping) search, as produced by [`Bisector::gallop`] and
+/// [`RangeBisector::gallop`].
+///
+/// The output consists of two parts:
+/// * `indices`: The indices of the bracket which contains the boundary, which can be used as the
+///   starting indices of a bisection
+/// * `probes`: The output of the convergence function for each probed element, in the order in
+///   which they were probed
+///
+/// [`Bisector::gallop`]: crate::Bisector::gallop
+/// [`RangeBisector::gallop`]: crate::RangeBisector::gallop
+pub struct Gallop<L, R> {
+    pub indices: Indices,
+    pub probes: Vec<Probe<L, R>>,
+}
+
+impl<'v, T, S> Bisector<'v, T, S>
+where
+    S: Bisectable<Item = T> + ?Sized,
+{
+    /// Exponential (galloping) search for the boundary, when it's expected to be close to the left
+    /// index.
+    ///
+    /// Starting at the left index of the given `indices`, the convergence function is called for
+    /// the elements at offsets `0, 1, 3, 7, 15, …` (i.e. the 1st, 2nd, 4th, 8th, 16th, … element),
+    /// for as long as it converges to the right. Once it converges to the left, the boundary is
+    /// bracketed by the last two probes, and the returned [`Gallop::indices`] can be handed to
+    /// [`Bisector::bisect`], to find the exact boundary.
+    ///
+    /// The right index of the given `indices` acts as the upper cap of the search. Like
+    /// [`Bisector::bisect`], the element at the right index is never probed, and is assumed to
+    /// converge to the left. If the cap is reached, the bracket extends up to the right index.
+    ///
+    /// Since the number of probes is logarithmic in the distance to the boundary, rather than in
+    /// the size of the view, galloping is cheaper than bisecting the complete view when the
+    /// boundary is close to the start.
+    ///
+    /// ```
+    /// use bisector::{Bisector, ConvergeTo, Indices};
+    ///
+    /// let values = (0..1000).collect::<Vec<u32>>();
+    /// let bisector = Bisector::new(&values);
+    ///
+    /// let f = |&value: &u32| {
+    ///     if value >= 10 {
+    ///         ConvergeTo::Left(value)
+    ///     } else {
+    ///         ConvergeTo::<u32, u32>::Right(value)
+    ///     }
+    /// };
+    ///
+    /// let gallop = bisector.gallop(f, Indices::from_bisector(&bisector));
+    ///
+    /// // Probed 0, 1, 3, 7 and 15
+    /// assert_eq!(gallop.probes.len(), 5);
+    /// assert_eq!(gallop.indices, Indices::new(8, 15));
+    ///
+    /// let mut indices = gallop.indices;
+    ///
+    /// loop {
+    ///     let step = bisector.bisect(f, indices);
+    ///
+    ///     if step.result.is_none() {
+    ///         break;
+    ///     }
+    ///
+    ///     indices = step.indices;
+    /// }
+    ///
+    /// assert_eq!(values[indices.left], 10);
+    /// ```
+    ///
+    /// See also:
+    /// * [`Bisector::try_gallop`]: A variant of [`gallop`] which can be used when the convergence
+    ///   function is fallible.
+    ///
+    /// [`Gallop::indices`]: crate::Gallop::indices
+    /// [`Bisector::bisect`]: crate::Bisector::bisect
+    /// [`Bisector::try_gallop`]: crate::Bisector::try_gallop
+    /// [`gallop`]: crate::Bisector::gallop
+    pub fn gallop<F, L, R>(&self, mut f: F, indices: Indices) -> Gallop<L, R>
+    where
+        F: FnMut(&T) -> ConvergeTo<L, R>,
+    {
+        gallop(indices, |index| {
+            Ok::<_, Infallible>(f(&self.source().get(index)))
+        })
+        .unwrap_or_else(|infallible| match infallible {})
+    }
+
+    /// This method can be used when the convergence function is fallible.
+    /// Otherwise exactly the same as [`Bisector::gallop`].
+    ///
+    /// The search stops as soon as the convergence function returns an error.
+    ///
+    /// [`Bisector::gallop`]: crate::Bisector::gallop
+    pub fn try_gallop<F, E, L, R>(&self, mut f: F, indices: Indices) -> Result<Gallop<L, R>, E>
+    where
+        F: FnMut(&T) -> Result<ConvergeTo<L, R>, E>,
+    {
+        gallop(indices, |index| f(&self.source().get(index)))
+    }
+}
+
+impl<I: Integer> RangeBisector<I> {
+    /// Exponential (galloping) search for the boundary over a range of integers.
+    ///
+    /// Exactly the same as [`Bisector::gallop`], except that the convergence function `f` is given
+    /// the value at each probed offset. Since the range may be open-ended, e.g. `1..`, this can be
+    /// used to find a boundary without knowing an upper bound up front. An upper cap can be set by
+    /// bounding the range, or by lowering the right index of the given `indices`.
+    ///
+    /// ```
+    /// use bisector::{ConvergeTo, Indices, RangeBisector};
+    ///
+    /// // The smallest input size which makes a program time out
+    /// let bisector = RangeBisector::new(1u64..).unwrap();
+    /// let times_out = |size: u64| size >= 5_000;
+    ///
+    /// let gallop = bisector.gallop(
+    ///     |size| {
+    ///         if times_out(size) {
+    ///             ConvergeTo::Left(())
+    ///         } else {
+    ///             ConvergeTo::<(), ()>::Right(())
+    ///         }
+    ///     },
+    ///     Indices::from_range_bisector(&bisector),
+    /// );
+    ///
+    /// assert_eq!(bisector.value(gallop.indices.left), 4_097);
+    /// assert_eq!(bisector.value(gallop.indices.right), 8_192);
+    /// ```
+    ///
+    /// See also:
+    /// * [`RangeBisector::try_gallop`]: A variant of [`gallop`] which can be used when the
+    ///   convergence function is fallible.
+    ///
+    /// [`Bisector::gallop`]: crate::Bisector::gallop
+    /// [`RangeBisector::try_gallop`]: crate::RangeBisector::try_gallop
+    /// [`gallop`]: crate::RangeBisector::gallop
+    pub fn gallop<F, L, R>(&self, mut f: F, indices: Indices) -> Gallop<L, R>
+    where
+        F: FnMut(I) -> ConvergeTo<L, R>,
+    {
+        self.try_gallop(|value| Ok::<_, Infallible>(f(value)), indices)
+            .unwrap_or_else(|infallible| match infallible {})
+    }
+
+    /// This method can be used when the convergence function is fallible.
+    /// Otherwise exactly the same as [`RangeBisector::gallop`].
+    ///
+    /// The search stops as soon as the convergence function returns an error.
+    ///
+    /// [`RangeBisector::gallop`]: crate::RangeBisector::gallop
+    pub fn try_gallop<F, E, L, R>(&self, mut f: F, indices: Indices) -> Result<Gallop<L, R>, E>
+    where
+        F: FnMut(I) -> Result<ConvergeTo<L, R>, E>,
+    {
+        Bisector::from_source(&self.values()).try_gallop(|&value| f(value), indices)
+    }
+}
+
+// Probes the indices at offsets 2^k - 1 from the left index, until an index converges to the left,
+// or until the right index is reached.
+fn gallop<F, E, L, R>(indices: Indices, mut probe: F) -> Result<Gallop<L, R>, E>
+where
+    F: FnMut(usize) -> Result<ConvergeTo<L, R>, E>,
+{
+    let Indices { left, right } = indices;
+
+    let mut probes = Vec::new();
+    let mut lower = left;
+    let mut distance = 1usize;
+
+    loop {
+        let index = match left.checked_add(distance - 1) {
+            Some(index) if index < right => index,
+            _ => break,
+        };
+
+        let result = probe(index)?;
+        let converged_left = match result {
+            ConvergeTo::Left(_) => true,
+            ConvergeTo::Right(_) => false,
+        };
+
+        probes.push(Probe { index, result });
+
+        if converged_left {
+            return Ok(Gallop {
+                indices: Indices::new(lower, index),
+                probes,
+            });
+        }
+
+        lower = index + 1;
+
+        // Once the distance can't be doubled, the next index would be beyond any right index
+        distance = match distance.checked_mul(2) {
+            Some(distance) => distance,
+            None => break,
+        };
+    }
+
+    Ok(Gallop {
+        indices: Indices::new(lower, right),
+        probes,
+    })
+}
@@ -0,0 +1,599 @@
+use crate::{Bisector, Confirmation, ConvergeTo, DagBisector, GitError, Indices, Probe};
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::ffi::OsStr;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// A commit in a git repository, identified by its full object id.
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct Commit {
+    id: String,
+}
+
+impl Commit {
+    /// Create a commit from its object id. The id is not verified.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into() }
+    }
+
+    /// The object id of the commit.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl Display for Commit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.id)
+    }
+}
+
+/// The revisions which bound a bisection: a good revision, from before the regression, and a bad
+/// revision, after it. Revisions can be anything `git rev-parse` understands, such as a branch,
+/// a tag or a commit id.
+#[derive(Debug, Clone)]
+pub struct RevRange {
+    good: String,
+    bad: String,
+    pathspecs: Vec<String>,
+    first_parent: bool,
+}
+
+impl RevRange {
+    /// Create a range from the given good and bad revision.
+    pub fn new(good: impl Into<String>, bad: impl Into<String>) -> Self {
+        Self {
+            good: good.into(),
+            bad: bad.into(),
+            pathspecs: Vec::new(),
+            first_parent: false,
+        }
+    }
+
+    /// Only consider the commits which touch the given pathspec. May be given more than once.
+    pub fn with_pathspec(mut self, pathspec: impl Into<String>) -> Self {
+        self.pathspecs.push(pathspec.into());
+        self
+    }
+
+    /// Only follow the first parent of merge commits, like `git bisect --first-parent`, so the
+    /// candidates are the commits of the mainline.
+    pub fn with_first_parent(mut self, first_parent: bool) -> Self {
+        self.first_parent = first_parent;
+        self
+    }
+
+    /// The good revision.
+    pub fn good(&self) -> &str {
+        &self.good
+    }
+
+    /// The bad revision.
+    pub fn bad(&self) -> &str {
+        &self.bad
+    }
+}
+
+/// Where the candidates are checked out while bisecting.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum CheckoutMode {
+    /// Check out each candidate in the working tree of the repository itself. The tracked files of
+    /// the working tree must not have local changes. Untracked files are never overwritten, so the
+    /// bisection fails if a candidate tracks a file which is untracked in the working tree. The
+    /// original `HEAD` is restored afterwards.
+    InPlace,
+    /// Check out each candidate in a dedicated `git worktree` at the given path, which must not
+    /// exist yet. The repository itself is left untouched, and the worktree is removed afterwards.
+    Worktree(PathBuf),
+}
+
+/// A local git repository, which is driven through the `git` binary.
+#[derive(Debug, Clone)]
+pub struct Repository {
+    path: PathBuf,
+}
+
+impl Repository {
+    /// Open the repository which contains the given path.
+    ///
+    /// Returns a [`GitError`] if git can't be run, or the path is not inside a git working tree.
+    ///
+    /// [`GitError`]: crate::GitError
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, GitError> {
+        let toplevel = git(path.as_ref(), ["rev-parse", "--show-toplevel"])?;
+
+        Ok(Self {
+            path: PathBuf::from(toplevel),
+        })
+    }
+
+    /// The top-level directory of the working tree of the repository.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Resolve a revision to a commit.
+    pub fn rev_parse(&self, rev: &str) -> Result<Commit, GitError> {
+        let rev = format!("{}^{{commit}}", rev);
+
+        git(
+            &self.path,
+            ["rev-parse", "--verify", "--quiet", rev.as_str()],
+        )
+        .map(Commit::new)
+    }
+
+    /// List the candidate commits of the given range, from oldest to newest, ready to be given to
+    /// [`Bisector::new`].
+    ///
+    /// The candidates are the commits reachable from the bad revision, but not from the good
+    /// revision, as listed by `git rev-list --topo-order`, so every commit comes after its parents.
+    /// The bad commit is always the last candidate, even if it doesn't touch the pathspecs of the
+    /// range, so the [`Bisector`] (which never probes the last element) converges to it if no
+    /// earlier candidate is bad.
+    ///
+    /// Unless the range only follows first parents, the candidates of a history with merge commits
+    /// don't form a line, and a [`Bisector`] over them may converge to the wrong commit. The
+    /// [`Repository::run`] method bisects such a history with a [`DagBisector`] instead.
+    ///
+    /// Returns [`GitError::NoCandidates`] if no commit is reachable from the bad revision but not
+    /// from the good revision (or none of them touch the pathspecs).
+    ///
+    /// [`Bisector::new`]: crate::Bisector::new
+    /// [`Bisector`]: crate::Bisector
+    /// [`Repository::run`]: crate::Repository::run
+    /// [`DagBisector`]: crate::DagBisector
+    /// [`GitError::NoCandidates`]: crate::GitError::NoCandidates
+    pub fn candidates(&self, range: &RevRange) -> Result<Vec<Commit>, GitError> {
+        self.history(range).map(|history| history.candidates)
+    }
+
+    // Lists the candidates of the given range like `candidates`.
+    fn history(&self, range: &RevRange) -> Result<History, GitError> {
+        let bad = self.rev_parse(&range.bad)?;
+        let good = self.rev_parse(&range.good)?;
+        let exclude = format!("^{}", good);
+
+        let mut args = vec!["rev-list", "--topo-order", "--reverse", "--parents"];
+
+        if range.first_parent {
+            args.push("--first-parent");
+        }
+
+        args.extend([bad.id(), exclude.as_str(), "--"]);
+        args.extend(range.pathspecs.iter().map(String::as_str));
+
+        let output = git(&self.path, args)?;
+        let mut candidates = Vec::new();
+        let mut parents = Vec::new();
+        let mut indices = HashMap::new();
+
+        // Each line holds a commit, followed by its parents, which were listed before it. With
+        // pathspecs, the parents are rewritten to the nearest commits which touch them.
+        for line in output.lines() {
+            let mut ids = line.split_whitespace();
+            let commit = ids.next().unwrap_or_default();
+
+            parents.push(
+                ids.filter_map(|parent| indices.get(parent).copied())
+                    .collect::<Vec<usize>>(),
+            );
+            indices.insert(commit, candidates.len());
+            candidates.push(Commit::new(commit));
+        }
+
+        match candidates.last() {
+            None => return Err(GitError::NoCandidates),
+            Some(last) if *last != bad => {
+                // The bad commit descends from every candidate, so its parents are the candidates
+                // which aren't a parent of another candidate
+                let mut heads = vec![true; candidates.len()];
+                parents
+                    .iter()
+                    .flatten()
+                    .for_each(|&parent| heads[parent] = false);
+
+                parents.push((0..candidates.len()).filter(|&i| heads[i]).collect());
+                candidates.push(bad);
+            }
+            Some(_) => {}
+        }
+
+        Ok(History {
+            good,
+            candidates,
+            parents,
+        })
+    }
+
+    /// Prepare to check out candidates in the given mode.
+    ///
+    /// The original state is restored by [`Checkout::restore`]: in place, the original `HEAD` is
+    /// checked out again, and otherwise the worktree is removed. A [`Checkout`] which is dropped
+    /// without being restored, e.g. because the convergence function panicked, is restored on drop.
+    ///
+    /// Returns [`GitError::DirtyWorkingTree`] when checking out in place, and tracked files of the
+    /// working tree have local changes.
+    ///
+    /// [`Checkout::restore`]: crate::Checkout::restore
+    /// [`Checkout`]: crate::Checkout
+    /// [`GitError::DirtyWorkingTree`]: crate::GitError::DirtyWorkingTree
+    pub fn checkout(&self, mode: CheckoutMode) -> Result<Checkout<'_>, GitError> {
+        match mode {
+            CheckoutMode::InPlace => {
+                // Checking out candidates would carry over, or be refused because of, local changes
+                if !git(
+                    &self.path,
+                    ["status", "--porcelain", "--untracked-files=no"],
+                )?
+                .is_empty()
+                {
+                    return Err(GitError::DirtyWorkingTree);
+                }
+
+                // The branch which is checked out, or the commit when `HEAD` is detached
+                let original = git(&self.path, ["symbolic-ref", "--quiet", "--short", "HEAD"])
+                    .or_else(|_| git(&self.path, ["rev-parse", "HEAD"]))?;
+
+                Ok(Checkout {
+                    repository: self,
+                    path: self.path.clone(),
+                    original: Some(original),
+                    restored: false,
+                })
+            }
+            CheckoutMode::Worktree(path) => {
+                // Git is run from different directories, so relative paths would be ambiguous
+                let path = if path.is_absolute() {
+                    path
+                } else {
+                    std::env::current_dir().map_err(GitError::Io)?.join(path)
+                };
+
+                git(
+                    &self.path,
+                    [
+                        OsStr::new("worktree"),
+                        OsStr::new("add"),
+                        OsStr::new("--quiet"),
+                        OsStr::new("--detach"),
+                        path.as_os_str(),
+                        OsStr::new("HEAD"),
+                    ],
+                )?;
+
+                Ok(Checkout {
+                    repository: self,
+                    path,
+                    original: None,
+                    restored: false,
+                })
+            }
+        }
+    }
+
+    /// Bisect the candidates of the given range, checking out each probed candidate in the given
+    /// mode, and return the first bad commit.
+    ///
+    /// The convergence function `f` is given the probed commit, and the path of the working tree in
+    /// which it's checked out. It must converge to the left for bad commits, and to the right for
+    /// good commits.
+    ///
+    /// When the range only follows first parents, the candidates form a line, which is bisected
+    /// like a [`Bisector`] does. Otherwise, the first bad commit may be on either side of a merge,
+    /// so the history is bisected as a graph, like a [`DagBisector`] does. In both cases, the
+    /// original state is restored afterwards, see [`Repository::checkout`].
+    ///
+    /// ```no_run
+    /// use bisector::{CheckoutMode, ConvergeTo, Repository, RevRange};
+    /// use std::process::Command;
+    ///
+    /// let repository = Repository::open(".").unwrap();
+    /// let range = RevRange::new("v1.0.0", "main").with_first_parent(true);
+    ///
+    /// let outcome = repository
+    ///     .run(&range, CheckoutMode::InPlace, |_commit, path| {
+    ///         let status = Command::new("cargo")
+    ///             .arg("test")
+    ///             .current_dir(path)
+    ///             .status()
+    ///             .unwrap();
+    ///
+    ///         if status.success() {
+    ///             ConvergeTo::Right(())
+    ///         } else {
+    ///             ConvergeTo::<(), ()>::Left(())
+    ///         }
+    ///     })
+    ///     .unwrap();
+    ///
+    /// println!("first bad commit: {}", outcome.commit);
+    /// ```
+    ///
+    /// See also:
+    /// * [`Repository::try_run`]: A variant of [`run`] which can be used when the convergence
+    ///   function is fallible.
+    ///
+    /// [`Bisector`]: crate::Bisector
+    /// [`DagBisector`]: crate::DagBisector
+    /// [`Repository::checkout`]: crate::Repository::checkout
+    /// [`Repository::try_run`]: crate::Repository::try_run
+    /// [`run`]: crate::Repository::run
+    pub fn run<F, L, R>(
+        &self,
+        range: &RevRange,
+        mode: CheckoutMode,
+        mut f: F,
+    ) -> Result<GitOutcome<L, R>, GitError>
+    where
+        F: FnMut(&Commit, &Path) -> ConvergeTo<L, R>,
+    {
+        self.try_run(range, mode, |commit, path| {
+            Ok::<_, Infallible>(f(commit, path))
+        })
+    }
+
+    /// This method can be used when the convergence function is fallible.
+    /// Otherwise exactly the same as [`Repository::run`].
+    ///
+    /// The bisection stops as soon as the convergence function returns an error, which is returned
+    /// as a [`GitError::Convergence`] error.
+    ///
+    /// [`Repository::run`]: crate::Repository::run
+    /// [`GitError::Convergence`]: crate::GitError::Convergence
+    pub fn try_run<F, E, L, R>(
+        &self,
+        range: &RevRange,
+        mode: CheckoutMode,
+        mut f: F,
+    ) -> Result<GitOutcome<L, R>, GitError<E>>
+    where
+        F: FnMut(&Commit, &Path) -> Result<ConvergeTo<L, R>, E>,
+    {
+        let History {
+            good,
+            candidates,
+            parents,
+        } = self.history(range).map_err(widen)?;
+        let mut checkout = self.checkout(mode).map_err(widen)?;
+
+        let mut probe = |commit: &Commit| {
+            let path = checkout.switch(commit).map_err(widen)?;
+            f(commit, path).map_err(GitError::Convergence)
+        };
+
+        let (index, probes) = if range.first_parent {
+            bisect_linear(&candidates, &mut probe)?
+        } else {
+            // Both sides of a merge may hold the first bad commit, so the candidates can't be
+            // bisected as a list
+            let bisector = DagBisector::new(&candidates, parents, candidates.len() - 1)
+                .expect("the history of a range is a valid graph");
+            let outcome = bisector.try_run(probe)?;
+
+            (outcome.index, outcome.probes)
+        };
+
+        checkout.restore().map_err(widen)?;
+
+        Ok(GitOutcome {
+            index,
+            commit: candidates[index].clone(),
+            good,
+            candidates,
+            probes,
+            confirmation: None,
+        })
+    }
+}
+
+// Bisects a line of candidates, and returns the index of the first bad candidate, together with
+// the probes.
+fn bisect_linear<F, E, L, R>(
+    candidates: &[Commit],
+    mut f: F,
+) -> Result<(usize, Vec<Probe<L, R>>), E>
+where
+    F: FnMut(&Commit) -> Result<ConvergeTo<L, R>, E>,
+{
+    let bisector = Bisector::new(candidates);
+    let mut indices = Indices::from_bisector(&bisector);
+    let mut probes = Vec::new();
+
+    loop {
+        let middle = indices.middle();
+        let step = bisector.try_bisect(&mut f, indices)?;
+
+        match step.result {
+            Some(result) => probes.push(Probe {
+                index: middle,
+                result,
+            }),
+            None => break,
+        }
+
+        indices = step.indices;
+    }
+
+    Ok((indices.left, probes))
+}
+
+// The candidates of a range, as listed by `Repository::history`.
+struct History {
+    // The resolved good revision of the range
+    good: Commit,
+    candidates: Vec<Commit>,
+    // The indices of the parents of each candidate, which are candidates themselves
+    parents: Vec<Vec<usize>>,
+}
+
+/// The outcome of a bisection over the commits of a git repository, as produced by
+/// [`Repository::run`] and [`Repository::try_run`].
+///
+/// [`Repository::run`]: crate::Repository::run
+/// [`Repository::try_run`]: crate::Repository::try_run
+pub struct GitOutcome<L, R> {
+    /// The index of the first bad commit in `candidates`.
+    pub index: usize,
+    /// The first bad commit.
+    pub commit: Commit,
+    /// The good commit of the range.
+    pub good: Commit,
+    /// The candidate commits, from oldest to newest.
+    pub candidates: Vec<Commit>,
+    /// The output of the convergence function for each probed candidate, in the order in which
+    /// they were probed.
+    pub probes: Vec<Probe<L, R>>,
+    /// Whether reverting the first bad commit on top of the bad revision fixes it, once
+    /// [`Repository::confirm`] has been called.
+    ///
+    /// [`Repository::confirm`]: crate::Repository::confirm
+    pub confirmation: Option<Confirmation<L, R>>,
+}
+
+/// Checks out candidates while bisecting, as created by [`Repository::checkout`].
+///
+/// The original state is restored by [`Checkout::restore`], which reports whether it succeeded.
+/// A checkout which is dropped without being restored, e.g. when the convergence function panics,
+/// still tries to restore the original state, but errors are ignored.
+///
+/// [`Repository::checkout`]: crate::Repository::checkout
+/// [`Checkout::restore`]: crate::Checkout::restore
+#[derive(Debug)]
+pub struct Checkout<'r> {
+    repository: &'r Repository,
+    path: PathBuf,
+    // The branch or commit to restore, when checking out in place
+    original: Option<String>,
+    restored: bool,
+}
+
+impl<'r> Checkout<'r> {
+    /// The path of the working tree in which candidates are checked out.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Check out the given commit, and return the path of the working tree.
+    ///
+    /// Changes which the convergence function made to tracked files are thrown away. In place,
+    /// untracked files are left alone: if the commit tracks a file which is untracked in the
+    /// working tree, the commit is not checked out, and a [`GitError::Command`] error is returned.
+    ///
+    /// [`GitError::Command`]: crate::GitError::Command
+    pub fn switch(&mut self, commit: &Commit) -> Result<&Path, GitError> {
+        self.check_out(&["--detach", commit.id()])?;
+
+        Ok(&self.path)
+    }
+
+    /// Restore the original state: in place, the original `HEAD` is checked out again, and
+    /// otherwise the worktree is removed.
+    ///
+    /// Changes which the convergence function made to tracked files are thrown away. Like
+    /// [`Checkout::switch`], untracked files in the working tree are never overwritten.
+    ///
+    /// [`Checkout::switch`]: crate::Checkout::switch
+    pub fn restore(mut self) -> Result<(), GitError> {
+        self.restored = true;
+        self.restore_original()
+    }
+
+    fn restore_original(&self) -> Result<(), GitError> {
+        match &self.original {
+            Some(original) => self.check_out(&[original.as_str()]),
+            None => git(
+                &self.repository.path,
+                [
+                    OsStr::new("worktree"),
+                    OsStr::new("remove"),
+                    OsStr::new("--force"),
+                    self.path.as_os_str(),
+                ],
+            )
+            .map(drop),
+        }
+    }
+
+    // Runs `git checkout` with the given arguments, after throwing away the changes to tracked
+    // files. The working tree had no such changes when the checkout was created, so they were made
+    // by the convergence function.
+    fn check_out(&self, args: &[&str]) -> Result<(), GitError> {
+        let mut checkout = vec!["checkout", "--quiet"];
+
+        if self.original.is_some() {
+            // Unlike `checkout --force`, which overwrites untracked files of the user
+            git(&self.path, ["reset", "--quiet", "--hard"])?;
+        } else {
+            // A dedicated worktree holds no files of the user
+            checkout.push("--force");
+        }
+
+        checkout.extend(args);
+        checkout.push("--");
+
+        git(&self.path, checkout).map(drop)
+    }
+}
+
+impl Drop for Checkout<'_> {
+    fn drop(&mut self) {
+        // Errors can't be reported from drop, and leave the repository as it is
+        if !self.restored {
+            let _ = self.restore_original();
+        }
+    }
+}
+
+// Runs git in the given directory, and returns its trimmed standard output.
+pub(crate) fn git<I, S>(dir: &Path, args: I) -> Result<String, GitError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+{
+    let args = args
+        .into_iter()
+        .map(|arg| arg.as_ref().to_os_string())
+        .collect::<Vec<_>>();
+
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(&args)
+        .output()
+        .map_err(GitError::Io)?;
+
+    if !output.status.success() {
+        return Err(GitError::Command {
+            args: args
+                .iter()
+                .map(|arg| arg.to_string_lossy().into_owned())
+                .collect(),
+            status: output.status.code(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        });
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+// Converts an error which can't hold a convergence error, into one which can.
+pub(crate) fn widen<E>(err: GitError) -> GitError<E> {
+    match err {
+        GitError::Io(err) => GitError::Io(err),
+        GitError::Command {
+            args,
+            status,
+            stderr,
+        } => GitError::Command {
+            args,
+            status,
+            stderr,
+        },
+        GitError::NoCandidates => GitError::NoCandidates,
+        GitError::DirtyWorkingTree => GitError::DirtyWorkingTree,
+        GitError::Convergence(infallible) => match infallible {},
+    }
+}
@@ -0,0 +1,153 @@
+use crate::ConvergeTo;
+use std::convert::Infallible;
+
+/// The bad items found by [`GroupTester::find_all`] and [`GroupTester::try_find_all`].
+///
+/// [`GroupTester::find_all`]: crate::GroupTester::find_all
+/// [`GroupTester::try_find_all`]: crate::GroupTester::try_find_all
+pub struct BadItems<'v, T> {
+    /// The indices of the bad items, in ascending order.
+    pub indices: Vec<usize>,
+    /// The bad items, in the order of the view.
+    pub items: Vec<&'v T>,
+    /// The number of times the test function was called.
+    pub tests: usize,
+    /// The number of tests which would have been needed to test each item individually, i.e. the
+    /// length of the view.
+    pub individual_tests: usize,
+}
+
+/// Finds every bad item in an unordered set of items, using adaptive group testing.
+///
+/// Where bisection assumes a single boundary in an ordered view, group testing finds any number of
+/// independently broken items, such as the crates of a workspace which each fail to build with a
+/// new toolchain. The test function is given a group of items, and must converge to the left if
+/// any item in the group is bad, and to the right if all items in the group are good:
+/// * [`ConvergeTo::Left`]: the group contains at least one bad item
+/// * [`ConvergeTo::Right`]: every item in the group is good
+///
+/// When few items are bad, far fewer tests are needed than when testing each item individually.
+///
+/// [`ConvergeTo::Left`]: crate::ConvergeTo::Left
+/// [`ConvergeTo::Right`]: crate::ConvergeTo::Right
+#[derive(Debug)]
+pub struct GroupTester<'v, T> {
+    view: &'v [T],
+}
+
+impl<'v, T> GroupTester<'v, T> {
+    /// Create a new group tester for the given view of items.
+    pub fn new(view: &'v [T]) -> Self {
+        Self { view }
+    }
+
+    /// The view of items which is tested.
+    pub fn view(&self) -> &'v [T] {
+        self.view
+    }
+
+    /// Find every bad item in the view.
+    ///
+    /// The complete view is tested first. Each group which contains a bad item is split into two
+    /// halves, and the first half is tested. If it contains no bad item, the second half must
+    /// contain one, so it isn't tested, and is split further right away. Otherwise, the second half
+    /// is tested as well. A group of a single item which contains a bad item is that bad item.
+    ///
+    /// For `d` bad items in a view of `n` items, this takes at most about `d * log2(n)` tests. The
+    /// number of tests which were used, compared with testing each item individually, is reported
+    /// in [`BadItems::tests`] and [`BadItems::individual_tests`].
+    ///
+    /// ```
+    /// use bisector::{ConvergeTo, GroupTester};
+    ///
+    /// let crates = (0..64).collect::<Vec<u32>>();
+    /// let broken = [7, 42];
+    ///
+    /// let found = GroupTester::new(&crates).find_all(|group: &[&u32]| {
+    ///     if group.iter().any(|krate| broken.contains(krate)) {
+    ///         ConvergeTo::Left(())
+    ///     } else {
+    ///         ConvergeTo::<(), ()>::Right(())
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(found.items, vec![&7, &42]);
+    /// assert!(found.tests < found.individual_tests);
+    /// ```
+    ///
+    /// See also:
+    /// * [`GroupTester::try_find_all`]: A variant of [`find_all`] which can be used when the test
+    ///   function is fallible.
+    ///
+    /// [`BadItems::tests`]: crate::BadItems::tests
+    /// [`BadItems::individual_tests`]: crate::BadItems::individual_tests
+    /// [`GroupTester::try_find_all`]: crate::GroupTester::try_find_all
+    /// [`find_all`]: crate::GroupTester::find_all
+    pub fn find_all<F, L, R>(&self, mut f: F) -> BadItems<'v, T>
+    where
+        F: FnMut(&[&'v T]) -> ConvergeTo<L, R>,
+    {
+        self.try_find_all(|group| Ok::<_, Infallible>(f(group)))
+            .unwrap_or_else(|infallible| match infallible {})
+    }
+
+    /// This method can be used when the test function is fallible.
+    /// Otherwise exactly the same as [`GroupTester::find_all`].
+    ///
+    /// Testing stops as soon as the test function returns an error.
+    ///
+    /// [`GroupTester::find_all`]: crate::GroupTester::find_all
+    pub fn try_find_all<F, E, L, R>(&self, mut f: F) -> Result<BadItems<'v, T>, E>
+    where
+        F: FnMut(&[&'v T]) -> Result<ConvergeTo<L, R>, E>,
+    {
+        let view = self.view;
+        let mut tests = 0;
+        let mut is_bad = |start: usize, end: usize| -> Result<bool, E> {
+            tests += 1;
+            let group = view[start..end].iter().collect::<Vec<_>>();
+
+            Ok(match f(&group)? {
+                ConvergeTo::Left(_) => true,
+                ConvergeTo::Right(_) => false,
+            })
+        };
+
+        let mut indices = Vec::new();
+
+        // Groups are ranges of the view which are known to contain a bad item
+        let mut groups = Vec::new();
+
+        if !view.is_empty() && is_bad(0, view.len())? {
+            groups.push((0, view.len()));
+        }
+
+        while let Some((start, end)) = groups.pop() {
+            if end - start == 1 {
+                indices.push(start);
+                continue;
+            }
+
+            let middle = start + (end - start) / 2;
+
+            if is_bad(start, middle)? {
+                groups.push((start, middle));
+
+                if is_bad(middle, end)? {
+                    groups.push((middle, end));
+                }
+            } else {
+                groups.push((middle, end));
+            }
+        }
+
+        indices.sort_unstable();
+
+        Ok(BadItems {
+            items: indices.iter().map(|&index| &view[index]).collect(),
+            indices,
+            tests,
+            individual_tests: view.len(),
+        })
+    }
+}
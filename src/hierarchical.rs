@@ -0,0 +1,301 @@
+use crate::minimize::{ddmin, failure};
+use crate::{Cache, MemoryCache, MinimizeError, Verdict};
+use std::convert::Infallible;
+
+/// A tree-shaped input which can be minimized with hierarchical delta debugging, such as a JSON
+/// document, a syntax tree, or a nested configuration.
+///
+/// Nodes are identified by a [`Tree::Node`] handle, such as the path of child positions from the
+/// root. The root itself is never removed.
+///
+/// [`Tree::Node`]: crate::Tree::Node
+pub trait Tree: Clone {
+    /// A handle to a node of the tree.
+    type Node;
+    /// The serialized form of the tree, which is given to the test function.
+    type Serialized;
+
+    /// The handle of the root node.
+    fn root(&self) -> Self::Node;
+
+    /// The handles of the children of the given node, in order.
+    fn children(&self, node: &Self::Node) -> Vec<Self::Node>;
+
+    /// Remove the given node, and all of its descendants, from the tree.
+    ///
+    /// When several nodes of one level are removed, they are removed in reverse order, i.e. the
+    /// last child of the last parent first. Handles which are positional, such as paths of child
+    /// indices, therefore stay valid while the nodes before them are being removed.
+    fn remove(&mut self, node: &Self::Node);
+
+    /// Serialize the tree, to be given to the test function.
+    fn serialize(&self) -> Self::Serialized;
+}
+
+/// The minimized tree, as produced by [`HierarchicalMinimizer::hdd`] and
+/// [`HierarchicalMinimizer::try_hdd`].
+///
+/// [`HierarchicalMinimizer::hdd`]: crate::HierarchicalMinimizer::hdd
+/// [`HierarchicalMinimizer::try_hdd`]: crate::HierarchicalMinimizer::try_hdd
+pub struct HierarchicallyMinimized<X, L> {
+    /// The minimized tree.
+    pub tree: X,
+    /// The output of the test function for the minimized tree.
+    pub output: L,
+    /// The number of levels of the tree which were minimized.
+    pub levels: usize,
+    /// The number of times the test function was called.
+    pub tests: usize,
+    /// The number of times the resolution of a configuration was taken from the cache, instead of
+    /// calling the test function.
+    pub cache_hits: usize,
+}
+
+/// Minimizes a failing tree-shaped input, using the hierarchical delta debugging algorithm (HDD)
+/// by Misherghi and Su.
+///
+/// Flat delta debugging, as implemented by the [`Minimizer`], treats the input as a list of items,
+/// and tends to produce configurations which break the structure of the input. Instead, HDD
+/// minimizes one level of the [`Tree`] at a time, starting with the children of the root, using
+/// `ddmin` over the nodes of that level. Nodes which aren't needed to reproduce the failure are
+/// removed, together with their descendants, before the next level is minimized.
+///
+/// Like the [`Minimizer`], the test function returns a [`Verdict`], where [`Verdict::Left`] means
+/// that the serialized tree reproduces the failure, and tested configurations are stored in a
+/// [`Cache`]. Within a run, each node is identified by a unique index, in the order in which the
+/// levels are visited, so a cache can be shared between runs over the same tree.
+///
+/// [`Minimizer`]: crate::Minimizer
+/// [`Tree`]: crate::Tree
+/// [`Verdict`]: crate::Verdict
+/// [`Verdict::Left`]: crate::Verdict::Left
+/// [`Cache`]: crate::Cache
+#[derive(Debug)]
+pub struct HierarchicalMinimizer<X, C = MemoryCache> {
+    tree: X,
+    cache: C,
+}
+
+impl<X: Tree> HierarchicalMinimizer<X> {
+    /// Create a new minimizer for the given tree, with an empty [`MemoryCache`].
+    ///
+    /// [`MemoryCache`]: crate::MemoryCache
+    pub fn new(tree: X) -> Self {
+        Self {
+            tree,
+            cache: MemoryCache::new(),
+        }
+    }
+}
+
+impl<X: Tree, C: Cache> HierarchicalMinimizer<X, C> {
+    /// Use the given [`Cache`] to store the resolution of tested configurations.
+    ///
+    /// [`Cache`]: crate::Cache
+    pub fn with_cache<D: Cache>(self, cache: D) -> HierarchicalMinimizer<X, D> {
+        HierarchicalMinimizer {
+            tree: self.tree,
+            cache,
+        }
+    }
+
+    /// The tree which is minimized.
+    pub fn tree(&self) -> &X {
+        &self.tree
+    }
+
+    /// The cache which stores the resolution of tested configurations.
+    pub fn cache(&self) -> &C {
+        &self.cache
+    }
+
+    /// Find a minimal tree which still reproduces the failure, one level at a time.
+    ///
+    /// The complete tree must reproduce the failure, and is always tested first, without consulting
+    /// the cache. If it doesn't, a [`MinimizeError::Passes`] or [`MinimizeError::Unresolved`] `Err`
+    /// result will be returned.
+    ///
+    /// Like `ddmin`, each level is reduced to a 1-minimal configuration, so at least one node of
+    /// every non-empty level is kept. When the failure of the minimized tree was taken from the
+    /// cache, it's tested once more to obtain its output, and a [`MinimizeError::Passes`] or
+    /// [`MinimizeError::Unresolved`] `Err` result will be returned if it no longer fails.
+    ///
+    /// ```
+    /// use bisector::{HierarchicalMinimizer, Tree, Verdict};
+    ///
+    /// #[derive(Clone)]
+    /// struct Node(&'static str, Vec<Node>);
+    ///
+    /// impl Node {
+    ///     fn at(&self, path: &[usize]) -> &Node {
+    ///         path.iter().fold(self, |node, &index| &node.1[index])
+    ///     }
+    /// }
+    ///
+    /// impl Tree for Node {
+    ///     type Node = Vec<usize>;
+    ///     type Serialized = String;
+    ///
+    ///     fn root(&self) -> Vec<usize> {
+    ///         Vec::new()
+    ///     }
+    ///
+    ///     fn children(&self, path: &Vec<usize>) -> Vec<Vec<usize>> {
+    ///         (0..self.at(path).1.len())
+    ///             .map(|index| [path.as_slice(), &[index]].concat())
+    ///             .collect()
+    ///     }
+    ///
+    ///     fn remove(&mut self, path: &Vec<usize>) {
+    ///         let (last, parent) = path.split_last().unwrap();
+    ///         let parent = parent.iter().fold(self, |node, &index| &mut node.1[index]);
+    ///         parent.1.remove(*last);
+    ///     }
+    ///
+    ///     fn serialize(&self) -> String {
+    ///         let children = self.1.iter().map(Tree::serialize).collect::<Vec<_>>();
+    ///         format!("{}[{}]", self.0, children.join(","))
+    ///     }
+    /// }
+    ///
+    /// let document = Node("fn", vec![
+    ///     Node("args", vec![Node("a", vec![]), Node("b", vec![])]),
+    ///     Node("body", vec![Node("let", vec![]), Node("panic", vec![]), Node("ret", vec![])]),
+    /// ]);
+    ///
+    /// let mut minimizer = HierarchicalMinimizer::new(document);
+    ///
+    /// let minimized = minimizer
+    ///     .hdd(|serialized: &String| {
+    ///         if serialized.contains("panic") {
+    ///             Verdict::<_, (), ()>::Left(())
+    ///         } else {
+    ///             Verdict::Right(())
+    ///         }
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(minimized.tree.serialize(), "fn[body[panic[]]]");
+    /// ```
+    ///
+    /// See also:
+    /// * [`HierarchicalMinimizer::try_hdd`]: A variant of [`hdd`] which can be used when the test
+    ///   function is fallible.
+    ///
+    /// [`MinimizeError::Passes`]: crate::MinimizeError::Passes
+    /// [`MinimizeError::Unresolved`]: crate::MinimizeError::Unresolved
+    /// [`HierarchicalMinimizer::try_hdd`]: crate::HierarchicalMinimizer::try_hdd
+    /// [`hdd`]: crate::HierarchicalMinimizer::hdd
+    pub fn hdd<F, L, R, S>(
+        &mut self,
+        mut f: F,
+    ) -> Result<HierarchicallyMinimized<X, L>, MinimizeError<R, S>>
+    where
+        F: FnMut(&X::Serialized) -> Verdict<L, R, S>,
+    {
+        self.try_hdd(|serialized| Ok::<_, Infallible>(f(serialized)))
+            .map_err(|err| match err {
+                MinimizeError::Passes(out) => MinimizeError::Passes(out),
+                MinimizeError::Unresolved(out) => MinimizeError::Unresolved(out),
+                MinimizeError::Convergence(infallible) => match infallible {},
+            })
+    }
+
+    /// This method can be used when the test function is fallible.
+    /// Otherwise exactly the same as [`HierarchicalMinimizer::hdd`].
+    ///
+    /// Minimizing stops as soon as the test function returns an error, which is returned as a
+    /// [`MinimizeError::Convergence`] error. The configurations tested up to that point remain in
+    /// the cache.
+    ///
+    /// [`HierarchicalMinimizer::hdd`]: crate::HierarchicalMinimizer::hdd
+    /// [`MinimizeError::Convergence`]: crate::MinimizeError::Convergence
+    pub fn try_hdd<F, E, L, R, S>(
+        &mut self,
+        mut f: F,
+    ) -> Result<HierarchicallyMinimized<X, L>, MinimizeError<R, S, E>>
+    where
+        F: FnMut(&X::Serialized) -> Result<Verdict<L, R, S>, E>,
+    {
+        let mut tree = self.tree.clone();
+
+        let mut output = failure(f(&tree.serialize()))?;
+        // Whether the failure of the pruned tree was taken from the cache, so `output` belongs to
+        // a larger tree
+        let mut stale = false;
+
+        let mut tests = 1;
+        let mut cache_hits = 0;
+        let mut levels = 0;
+
+        // Nodes are identified by `offset + position` within their level, which is unique within
+        // a run, so the configurations of different levels don't collide in the cache
+        let mut offset = 0;
+        let mut level = nodes_at_depth(&tree, 1);
+
+        while !level.is_empty() {
+            let all = (offset..offset + level.len()).collect::<Vec<_>>();
+
+            let reduction = ddmin(all, &mut self.cache, |configuration: &[usize]| {
+                let mut candidate = tree.clone();
+                remove_except(&mut candidate, &level, offset, configuration);
+
+                f(&candidate.serialize())
+            })
+            .map_err(MinimizeError::Convergence)?;
+
+            let pruned = reduction.configuration.len() < level.len();
+            remove_except(&mut tree, &level, offset, &reduction.configuration);
+
+            match reduction.output {
+                Some(out) => {
+                    output = out;
+                    stale = false;
+                }
+                None => stale |= pruned,
+            }
+
+            tests += reduction.tests;
+            cache_hits += reduction.cache_hits;
+            levels += 1;
+            offset += level.len();
+
+            // Positional handles may have shifted by pruning, so the next level is taken from the
+            // pruned tree
+            level = nodes_at_depth(&tree, levels + 1);
+        }
+
+        if stale {
+            output = failure(f(&tree.serialize()))?;
+            tests += 1;
+        }
+
+        Ok(HierarchicallyMinimized {
+            tree,
+            output,
+            levels,
+            tests,
+            cache_hits,
+        })
+    }
+}
+
+// Removes the nodes of the level which aren't part of the configuration, in reverse order.
+fn remove_except<X: Tree>(tree: &mut X, level: &[X::Node], offset: usize, configuration: &[usize]) {
+    for (position, node) in level.iter().enumerate().rev() {
+        if configuration.binary_search(&(offset + position)).is_err() {
+            tree.remove(node);
+        }
+    }
+}
+
+// The nodes at the given depth below the root, in order.
+fn nodes_at_depth<X: Tree>(tree: &X, depth: usize) -> Vec<X::Node> {
+    let mut level = vec![tree.root()];
+
+    for _ in 0..depth {
+        level = level.iter().flat_map(|node| tree.children(node)).collect();
+    }
+
+    level
+}
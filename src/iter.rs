@@ -0,0 +1,155 @@
+use crate::{Bisectable, Bisector, ConvergeTo, Indices, Step};
+use std::iter::FusedIterator;
+
+/// An iterator over the steps of a bisection, created by [`Bisector::iter`].
+///
+/// Each call to `next` performs a single [`Bisector::bisect`] step, starting from the complete
+/// view of the [`Bisector`], and feeds the produced indices into the next step. The iterator is
+/// exhausted once the bisection has converged (i.e. when [`Step::result`] would be `None`).
+///
+/// Since the iterator is built on top of the stateless [`Bisector::bisect`] method, the current
+/// indices can be retrieved with [`Iter::indices`] at any time, and may be used to continue the
+/// bisection manually.
+///
+/// [`Bisector`]: crate::Bisector
+/// [`Bisector::iter`]: crate::Bisector::iter
+/// [`Bisector::bisect`]: crate::Bisector::bisect
+/// [`Step::result`]: crate::Step::result
+/// [`Iter::indices`]: crate::Iter::indices
+pub struct Iter<'b, 'v, T, F, S: ?Sized = [T]> {
+    bisector: &'b Bisector<'v, T, S>,
+    f: F,
+    indices: Option<Indices>,
+}
+
+impl<'b, 'v, T, F, S> Iter<'b, 'v, T, F, S>
+where
+    S: Bisectable<Item = T> + ?Sized,
+{
+    pub(crate) fn new(bisector: &'b Bisector<'v, T, S>, f: F) -> Self {
+        Self {
+            bisector,
+            f,
+            indices: Indices::try_from_source(bisector).ok(),
+        }
+    }
+
+    /// The indices from which the next step will be taken.
+    ///
+    /// Returns `None` if the view of the [`Bisector`] is empty.
+    ///
+    /// [`Bisector`]: crate::Bisector
+    pub fn indices(&self) -> Option<Indices> {
+        self.indices
+    }
+}
+
+impl<'b, 'v, T, F, S, L, R> Iterator for Iter<'b, 'v, T, F, S>
+where
+    S: Bisectable<Item = T> + ?Sized,
+    F: FnMut(&T) -> ConvergeTo<L, R>,
+{
+    type Item = Step<L, R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let indices = self.indices?;
+        let f = &mut self.f;
+
+        let step = self.bisector.bisect(|value| f(value), indices);
+        self.indices = Some(step.indices);
+
+        if step.result.is_some() {
+            Some(step)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'b, 'v, T, F, S, L, R> FusedIterator for Iter<'b, 'v, T, F, S>
+where
+    S: Bisectable<Item = T> + ?Sized,
+    F: FnMut(&T) -> ConvergeTo<L, R>,
+{
+}
+
+/// An iterator over the steps of a bisection with a fallible convergence function, created by
+/// [`Bisector::try_iter`].
+///
+/// Behaves like [`Iter`], except that each item is a `Result`. When the convergence function
+/// returns an `Err`, the error is yielded once, after which the iterator is exhausted. The
+/// indices are not progressed by a failed step, so [`TryIter::indices`] still points to the
+/// indices for which the convergence function failed.
+///
+/// [`Bisector::try_iter`]: crate::Bisector::try_iter
+/// [`Iter`]: crate::Iter
+/// [`TryIter::indices`]: crate::TryIter::indices
+pub struct TryIter<'b, 'v, T, F, S: ?Sized = [T]> {
+    bisector: &'b Bisector<'v, T, S>,
+    f: F,
+    indices: Option<Indices>,
+    failed: bool,
+}
+
+impl<'b, 'v, T, F, S> TryIter<'b, 'v, T, F, S>
+where
+    S: Bisectable<Item = T> + ?Sized,
+{
+    pub(crate) fn new(bisector: &'b Bisector<'v, T, S>, f: F) -> Self {
+        Self {
+            bisector,
+            f,
+            indices: Indices::try_from_source(bisector).ok(),
+            failed: false,
+        }
+    }
+
+    /// The indices from which the next step will be taken.
+    ///
+    /// Returns `None` if the view of the [`Bisector`] is empty.
+    ///
+    /// [`Bisector`]: crate::Bisector
+    pub fn indices(&self) -> Option<Indices> {
+        self.indices
+    }
+}
+
+impl<'b, 'v, T, F, S, E, L, R> Iterator for TryIter<'b, 'v, T, F, S>
+where
+    S: Bisectable<Item = T> + ?Sized,
+    F: FnMut(&T) -> Result<ConvergeTo<L, R>, E>,
+{
+    type Item = Result<Step<L, R>, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let indices = self.indices?;
+        let f = &mut self.f;
+
+        match self.bisector.try_bisect(|value| f(value), indices) {
+            Ok(step) => {
+                self.indices = Some(step.indices);
+
+                if step.result.is_some() {
+                    Some(Ok(step))
+                } else {
+                    None
+                }
+            }
+            Err(err) => {
+                self.failed = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+impl<'b, 'v, T, F, S, E, L, R> FusedIterator for TryIter<'b, 'v, T, F, S>
+where
+    S: Bisectable<Item = T> + ?Sized,
+    F: FnMut(&T) -> Result<ConvergeTo<L, R>, E>,
+{
+}
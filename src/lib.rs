@@ -7,6 +7,71 @@
 //! The [`Bisector`] also provides the [`Bisector::try_bisect`] method which may be used when
 //! the convergence function is fallible.
 //!
+//! To run a bisection to completion, without writing the loop which feeds the indices of one step
+//! into the next, the [`Bisector::iter`] and [`Bisector::try_iter`] methods can be used. These return
+//! an iterator over the [`Step`]s of the bisection. If you're only interested in the result of the
+//! bisection, [`Bisector::run`] and [`Bisector::try_run`] produce an [`Outcome`], which describes
+//! the element to which the bisection converged. To guard against a convergence function which was
+//! inverted by mistake, [`Bisector::run_validated`] first checks that the endpoints of the view
+//! converge to the expected sides.
+//!
+//! When the convergence function flips more than once, e.g. when a regression comes and goes,
+//! [`Bisector::transitions`] finds every position where it flips, within a budget. When only a
+//! single stretch of the view converges to the left, [`Bisector::window`] finds where it starts and
+//! ends, given one element inside it.
+//!
+//! When the function which is bisected produces more than two outcomes, such as an exit code,
+//! [`Bisector::change_points`] finds every position where its value changes, and the [`Segment`]s
+//! in between.
+//!
+//! When a failure is caused by a combination of items, rather than a single culprit, the
+//! [`Minimizer`] finds a minimal failing subset of the items using delta debugging. For tree-shaped
+//! inputs, the [`HierarchicalMinimizer`] minimizes one level of a [`Tree`] at a time.
+//!
+//! When several items of an unordered set are independently broken, the [`GroupTester`] finds all of
+//! them with adaptive group testing, using fewer tests than testing each item individually.
+//!
+//! When some candidates can't be tested at all, the convergence function may return a [`Verdict`]
+//! instead, which can mark a candidate as skipped. See [`Bisector::iter_skipping`] and
+//! [`Bisector::run_skipping`].
+//!
+//! For flaky convergence functions, [`Bisector::bisect_voting`] and [`Bisector::try_bisect_voting`]
+//! run the convergence function several times per step, and converge to the side which wins the
+//! majority vote. Alternatively, the [`NoisyBisector`] implements a probabilistic bisection method,
+//! which can recover from a convergence function which converged to the wrong side.
+//!
+//! When the convergence function is slow, but many cores are available, [`Bisector::multisect`] and
+//! [`Bisector::try_multisect`] evaluate several split points of the view concurrently, narrowing the
+//! view by more than half per step. Alternatively, [`Bisector::run_speculative`] hides the latency of
+//! the convergence function, by already evaluating both possible middle elements of the next step.
+//!
+//! To bisect over a range of integers, such as `0..u64::MAX`, without allocating a slice which holds
+//! every value, the [`RangeBisector`] can be used. More generally, the [`Bisector`] can bisect over
+//! any random-access source of candidates which implements [`Bisectable`], such as a `VecDeque`, or
+//! an [`FnSource`] which produces candidates on demand.
+//!
+//! When the boundary is expected to be close to the start of a large (or even open-ended) view,
+//! [`Bisector::gallop`] and [`RangeBisector::gallop`] bracket the boundary with an exponential
+//! search, after which only the bracket needs to be bisected.
+//!
+//! To bisect a history with merge commits, where the candidates form a directed acyclic graph
+//! rather than a list, the [`DagBisector`] chooses probes the way `git bisect` does.
+//!
+//! The [`ContinuousBisector`] applies the bisection method to an interval of real numbers, to find
+//! a root of a function. Besides bisection, it supports the false position method, its Illinois
+//! variant, and Brent's method.
+//!
+//! When the `async` feature is enabled, `Bisector::bisect_async` and `Bisector::stream` accept
+//! convergence functions which produce a [`Future`]. These don't depend on a specific async runtime.
+//!
+//! When the `git` feature is enabled, `Repository` lists the candidate commits between a good and a
+//! bad revision with `git rev-list`, and checks out each probed commit, either in place or in a
+//! dedicated worktree. A `BisectLog` reads and writes the format of `git bisect log`, to continue a
+//! bisection which was started with plain `git bisect`, or the other way round. Once a first bad
+//! commit has been found, `Repository::confirm` reverts it on top of the bad revision in a
+//! throwaway worktree, and checks that this fixes the failure. To test several commits at once, a
+//! `WorktreePool` leases one of a fixed set of worktrees to each concurrent convergence function.
+//!
 //! # Examples
 //!
 //! Examples can be found in the examples folder of this crate ([Examples on GitHub](https://github.com/foresterre/bisector/tree/main/examples)).
@@ -22,13 +87,74 @@
 //! [`Bisector`]: crate::Bisector
 //! [`Bisector::bisect`]: crate::Bisector::bisect
 //! [`Bisector::try_bisect`]: crate::Bisector::try_bisect
+//! [`Bisector::iter`]: crate::Bisector::iter
+//! [`Bisector::try_iter`]: crate::Bisector::try_iter
+//! [`Step`]: crate::Step
+//! [`Bisector::run`]: crate::Bisector::run
+//! [`Bisector::try_run`]: crate::Bisector::try_run
+//! [`Outcome`]: crate::Outcome
+//! [`Bisector::run_validated`]: crate::Bisector::run_validated
+//! [`Bisector::transitions`]: crate::Bisector::transitions
+//! [`Bisector::window`]: crate::Bisector::window
+//! [`Bisector::change_points`]: crate::Bisector::change_points
+//! [`Segment`]: crate::Segment
+//! [`Minimizer`]: crate::Minimizer
+//! [`HierarchicalMinimizer`]: crate::HierarchicalMinimizer
+//! [`Tree`]: crate::Tree
+//! [`GroupTester`]: crate::GroupTester
+//! [`Verdict`]: crate::Verdict
+//! [`Bisector::iter_skipping`]: crate::Bisector::iter_skipping
+//! [`Bisector::run_skipping`]: crate::Bisector::run_skipping
+//! [`Bisector::bisect_voting`]: crate::Bisector::bisect_voting
+//! [`Bisector::try_bisect_voting`]: crate::Bisector::try_bisect_voting
+//! [`NoisyBisector`]: crate::NoisyBisector
+//! [`Bisector::multisect`]: crate::Bisector::multisect
+//! [`Bisector::try_multisect`]: crate::Bisector::try_multisect
+//! [`Bisector::run_speculative`]: crate::Bisector::run_speculative
+//! [`RangeBisector`]: crate::RangeBisector
+//! [`Bisectable`]: crate::Bisectable
+//! [`FnSource`]: crate::FnSource
+//! [`Bisector::gallop`]: crate::Bisector::gallop
+//! [`RangeBisector::gallop`]: crate::RangeBisector::gallop
+//! [`DagBisector`]: crate::DagBisector
+//! [`ContinuousBisector`]: crate::ContinuousBisector
+//! [`Future`]: std::future::Future
 
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "async")]
+mod asynchronous;
+#[cfg(feature = "git")]
+mod bisect_log;
+mod change_points;
+#[cfg(feature = "git")]
+mod confirm;
+mod dag;
 pub(crate) mod error;
+mod gallop;
+#[cfg(feature = "git")]
+mod git;
+mod group;
+mod hierarchical;
+mod iter;
+mod minimize;
+mod noisy;
+mod numeric;
+mod outcome;
+mod parallel;
+mod range;
+mod skip;
+mod source;
+mod speculative;
+mod transitions;
+mod vote;
+mod window;
+#[cfg(feature = "git")]
+mod worktree_pool;
 
 use std::fmt::Debug;
+use std::marker::PhantomData;
 
 /// Error returned by [`Indices::try_from_bisector`], when the slice given to [`Bisector::new`]
 /// is empty.
@@ -37,16 +163,125 @@ use std::fmt::Debug;
 /// [`Bisector::new`]: crate::Bisector::new
 pub use error::EmptySliceError;
 
+/// Error returned by [`Bisector::try_run`], when either the slice given to [`Bisector::new`] is
+/// empty, or the convergence function failed.
+///
+/// [`Bisector::try_run`]: crate::Bisector::try_run
+/// [`Bisector::new`]: crate::Bisector::new
+pub use error::TryRunError;
+
+/// Error returned by [`Noise::new`], when either error rate is not in the range `[0, 0.5)`.
+///
+/// [`Noise::new`]: crate::Noise::new
+pub use error::InvalidNoiseError;
+
+/// Error returned by [`RangeBisector::new`], when the range is empty, or holds too many values for
+/// its offsets to fit in a `usize`.
+///
+/// [`RangeBisector::new`]: crate::RangeBisector::new
+pub use error::InvalidRangeError;
+
+/// Error returned by [`DagBisector::new`], when the parent edges don't describe a directed acyclic
+/// graph over the given nodes.
+///
+/// [`DagBisector::new`]: crate::DagBisector::new
+pub use error::InvalidDagError;
+
+/// Error returned by the [`Repository`] methods, when git can't be run, a git command fails, the
+/// range has no candidates, or the working tree has local changes while checking out in place.
+/// [`Repository::try_run`] also returns it when the convergence function failed.
+///
+/// [`Repository`]: crate::Repository
+/// [`Repository::try_run`]: crate::Repository::try_run
+#[cfg(feature = "git")]
+pub use error::GitError;
+
+/// Error returned by [`BisectLog::parse`] and [`BisectLog::indices`], when a line of the log isn't
+/// understood, or the marks of the log don't fit the candidates.
+///
+/// [`BisectLog::parse`]: crate::BisectLog::parse
+/// [`BisectLog::indices`]: crate::BisectLog::indices
+#[cfg(feature = "git")]
+pub use error::BisectLogError;
+
+/// Error returned by [`ContinuousBisector::find_root`], when the function doesn't have a different
+/// sign at both endpoints of the interval. Holds the values of the function at the left and right
+/// endpoints.
+///
+/// [`ContinuousBisector::find_root`]: crate::ContinuousBisector::find_root
+pub use error::NotBracketedError;
+
+/// Error returned by [`Bisector::run_validated`] and [`Bisector::try_run_validated`], when the
+/// endpoints of the view don't converge to the expected sides, i.e. the first element to the right,
+/// and the last element to the left. Holds the outputs of the convergence function for both
+/// endpoints.
+///
+/// [`Bisector::run_validated`]: crate::Bisector::run_validated
+/// [`Bisector::try_run_validated`]: crate::Bisector::try_run_validated
+pub use error::ValidationError;
+
+/// Error returned by [`Minimizer::ddmin`], [`HierarchicalMinimizer::hdd`] and their fallible
+/// variants, when the complete input doesn't reproduce the failure, or when the test function
+/// failed. Holds the output of the test function.
+///
+/// Also returned when the minimal input, whose failure was taken from the cache, no longer
+/// reproduces the failure when it's tested again to obtain its output.
+///
+/// [`Minimizer::ddmin`]: crate::Minimizer::ddmin
+/// [`HierarchicalMinimizer::hdd`]: crate::HierarchicalMinimizer::hdd
+pub use error::MinimizeError;
+
+#[cfg(feature = "async")]
+pub use asynchronous::{BisectStream, TryBisectStream};
+#[cfg(feature = "git")]
+pub use bisect_log::{BisectLog, LogEntry, Mark};
+pub use change_points::{ChangePoints, Segment};
+#[cfg(feature = "git")]
+pub use confirm::Confirmation;
+pub use dag::{DagBisector, DagOutcome};
+pub use gallop::Gallop;
+#[cfg(feature = "git")]
+pub use git::{Checkout, CheckoutMode, Commit, GitOutcome, Repository, RevRange};
+pub use group::{BadItems, GroupTester};
+pub use hierarchical::{HierarchicalMinimizer, HierarchicallyMinimized, Tree};
+pub use iter::{Iter, TryIter};
+pub use minimize::{Cache, MemoryCache, Minimized, Minimizer, Resolution};
+pub use noisy::{Noise, NoisyBisector, NoisyOutcome, NoisyStep, Posterior};
+pub use numeric::{
+    Bracket, ContinuousBisector, ContinuousStep, Method, Root, StopReason, Tolerance,
+};
+pub use outcome::Outcome;
+pub use parallel::MultiStep;
+pub use range::{Integer, RangeBisector};
+pub use skip::{SkipIter, SkipOutcome, SkipStep, SkipStrategy, Verdict};
+pub use source::{Bisectable, Candidate, FnSource};
+pub use speculative::Speculation;
+pub use transitions::{Transition, Transitions, Unexplored};
+pub use vote::{VoteStep, Votes, Voting};
+pub use window::Window;
+#[cfg(feature = "git")]
+pub use worktree_pool::{Lease, WorktreePool};
+
 /// Stateless implementation of the bisection method.
+///
+/// By default, the bisector bisects over a slice. Other random-access sources of candidates, which
+/// implement [`Bisectable`], can be used with [`Bisector::from_source`].
+///
+/// [`Bisectable`]: crate::Bisectable
+/// [`Bisector::from_source`]: crate::Bisector::from_source
 #[derive(Debug)]
-pub struct Bisector<'v, T> {
-    view: &'v [T],
+pub struct Bisector<'v, T, S: ?Sized = [T]> {
+    view: &'v S,
+    candidate: PhantomData<fn() -> T>,
 }
 
 impl<'v, T> Bisector<'v, T> {
     /// Create a new [`Bisector`] for a given view.
     pub fn new(view: &'v [T]) -> Self {
-        Self { view }
+        Self {
+            view,
+            candidate: PhantomData,
+        }
     }
 
     /// A view of the slice as known to the bisector.
@@ -56,6 +291,47 @@ impl<'v, T> Bisector<'v, T> {
     pub fn view(&self) -> &'v [T] {
         self.view
     }
+}
+
+impl<'v, T, S> Bisector<'v, T, S>
+where
+    S: Bisectable<Item = T> + ?Sized,
+{
+    /// Create a new [`Bisector`] for a given [`Bisectable`] source of candidates.
+    ///
+    /// ```
+    /// use bisector::{Bisector, ConvergeTo, Indices};
+    /// use std::collections::VecDeque;
+    ///
+    /// let values = (1..=10).collect::<VecDeque<u32>>();
+    /// let bisector = Bisector::from_source(&values);
+    ///
+    /// let step = bisector.bisect(
+    ///     |&value| ConvergeTo::<u32, u32>::Left(value),
+    ///     Indices::from_source(&bisector),
+    /// );
+    ///
+    /// assert_eq!(step.indices, Indices::new(0, 4));
+    /// ```
+    ///
+    /// [`Bisector`]: crate::Bisector
+    /// [`Bisectable`]: crate::Bisectable
+    pub fn from_source(source: &'v S) -> Self {
+        Self {
+            view: source,
+            candidate: PhantomData,
+        }
+    }
+
+    /// The source of candidates as known to the bisector.
+    ///
+    /// NB: Like [`Bisector::view`], this is always the complete source, regardless of any bisection
+    /// steps which might have taken place.
+    ///
+    /// [`Bisector::view`]: crate::Bisector::view
+    pub fn source(&self) -> &'v S {
+        self.view
+    }
 
     /// Stateless implementation of the bisection method.
     ///
@@ -102,7 +378,7 @@ impl<'v, T> Bisector<'v, T> {
 
         let middle = indices.middle();
 
-        match f(&self.view[middle]) {
+        match f(&self.view.get(middle)) {
             ConvergeTo::Left(out) => Step {
                 indices: Indices {
                     left,
@@ -142,7 +418,7 @@ impl<'v, T> Bisector<'v, T> {
 
         let middle = indices.middle();
 
-        match f(&self.view[middle])? {
+        match f(&self.view.get(middle))? {
             ConvergeTo::Left(out) => Ok(Step {
                 indices: Indices {
                     left,
@@ -159,6 +435,65 @@ impl<'v, T> Bisector<'v, T> {
             }),
         }
     }
+
+    /// Run the bisection method by repeatedly calling [`Bisector::bisect`], starting from the complete
+    /// view.
+    ///
+    /// Returns an iterator which yields the [`Step`] of each bisection iteration, until the bisection
+    /// has converged. Since the iterator is lazy, the convergence function `f` is only called when
+    /// the next step is requested, so regular iterator adapters such as `take`, `inspect` or `last`
+    /// can be used to observe or stop the bisection early.
+    ///
+    /// If the view is empty, the iterator yields no steps.
+    ///
+    /// ```
+    /// use bisector::{Bisector, ConvergeTo};
+    ///
+    /// let values = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    /// let bisector = Bisector::new(&values);
+    ///
+    /// let mut iter = bisector.iter(|&value| {
+    ///     if value >= 7 {
+    ///         ConvergeTo::Left(value)
+    ///     } else {
+    ///         ConvergeTo::Right(value)
+    ///     }
+    /// });
+    ///
+    /// let seen = iter.by_ref().map(|step| step.indices).count();
+    ///
+    /// assert_eq!(seen, 4);
+    /// assert_eq!(values[iter.indices().unwrap().left], 7);
+    /// ```
+    ///
+    /// See also:
+    /// * [`Bisector::try_iter`]: A variant of [`iter`] which can be used when the convergence function
+    ///   is fallible.
+    ///
+    /// [`Step`]: crate::Step
+    /// [`Bisector::bisect`]: crate::Bisector::bisect
+    /// [`Bisector::try_iter`]: crate::Bisector::try_iter
+    /// [`iter`]: crate::Bisector::iter
+    pub fn iter<F, L, R>(&self, f: F) -> Iter<'_, 'v, T, F, S>
+    where
+        F: FnMut(&T) -> ConvergeTo<L, R>,
+    {
+        Iter::new(self, f)
+    }
+
+    /// This method can be used when the convergence function is fallible.
+    /// Otherwise exactly the same as [`Bisector::iter`].
+    ///
+    /// The iterator yields `Result`s. After the convergence function produced an error, the error is
+    /// yielded, and the iterator is exhausted.
+    ///
+    /// [`Bisector::iter`]: crate::Bisector::iter
+    pub fn try_iter<F, E, L, R>(&self, f: F) -> TryIter<'_, 'v, T, F, S>
+    where
+        F: FnMut(&T) -> Result<ConvergeTo<L, R>, E>,
+    {
+        TryIter::new(self, f)
+    }
 }
 
 /// The left and right indices, which in combination with the slice held by the [`Bisector`], provide
@@ -220,6 +555,23 @@ impl Indices {
     /// [`Bisector`]: crate::Bisector
     /// [`Indices::try_from_bisector`]: crate::Indices::try_from_bisector
     pub fn from_bisector<T>(bisector: &Bisector<T>) -> Self {
+        Self::from_source(bisector)
+    }
+
+    /// Like [`Indices::from_bisector`], but for a [`Bisector`] over any [`Bisectable`] source, as
+    /// created by [`Bisector::from_source`]. The returned indices will be the complete range of the
+    /// source, i.e. from index `0` to index `len - 1`.
+    ///
+    /// NB: The source given to [`Bisector`] **must not be empty**, see [`Indices::from_bisector`].
+    ///
+    /// [`Indices::from_bisector`]: crate::Indices::from_bisector
+    /// [`Bisector`]: crate::Bisector
+    /// [`Bisectable`]: crate::Bisectable
+    /// [`Bisector::from_source`]: crate::Bisector::from_source
+    pub fn from_source<T, S>(bisector: &Bisector<T, S>) -> Self
+    where
+        S: Bisectable<Item = T> + ?Sized,
+    {
         Self {
             left: 0,
             right: bisector.view.len() - 1,
@@ -237,6 +589,24 @@ impl Indices {
     /// [`Bisector`]: crate::Bisector
     /// [`EmptySliceError`]: crate::EmptySliceError
     pub fn try_from_bisector<T>(bisector: &Bisector<T>) -> Result<Self, EmptySliceError> {
+        Self::try_from_source(bisector)
+    }
+
+    /// Like [`Indices::try_from_bisector`], but for a [`Bisector`] over any [`Bisectable`] source,
+    /// as created by [`Bisector::from_source`].
+    ///
+    /// The source given to [`Bisector`] must not be empty. If it is, an [`EmptySliceError`]
+    /// `Err` result will be returned.
+    ///
+    /// [`Indices::try_from_bisector`]: crate::Indices::try_from_bisector
+    /// [`Bisector`]: crate::Bisector
+    /// [`Bisectable`]: crate::Bisectable
+    /// [`Bisector::from_source`]: crate::Bisector::from_source
+    /// [`EmptySliceError`]: crate::EmptySliceError
+    pub fn try_from_source<T, S>(bisector: &Bisector<T, S>) -> Result<Self, EmptySliceError>
+    where
+        S: Bisectable<Item = T> + ?Sized,
+    {
         if !bisector.view.is_empty() {
             Ok(Self {
                 left: 0,
@@ -284,6 +654,15 @@ pub struct Step<L, R> {
     pub result: Option<ConvergeTo<L, R>>,
 }
 
+/// The output of the convergence function for a single element of the view.
+///
+/// Used by methods which call the convergence function for more than one element per step, where
+/// the output needs to be paired with the index of the element it was produced for.
+pub struct Probe<L, R> {
+    pub index: usize,
+    pub result: ConvergeTo<L, R>,
+}
+
 /// The direction towards which a [`step`] of the [`Bisector`] should converge.
 ///
 /// [`Bisector`]: crate::Bisector
@@ -0,0 +1,434 @@
+use crate::{MinimizeError, Verdict};
+use std::collections::HashMap;
+use std::convert::Infallible;
+
+/// The resolution of a configuration which was tested while minimizing, as stored in a [`Cache`].
+///
+/// [`Cache`]: crate::Cache
+#[derive(Debug, Copy, Clone, Eq, Hash, PartialEq)]
+pub enum Resolution {
+    /// The test function returned [`Verdict::Left`]: the configuration still reproduces the failure.
+    ///
+    /// [`Verdict::Left`]: crate::Verdict::Left
+    Fails,
+    /// The test function returned [`Verdict::Right`]: the configuration doesn't reproduce the
+    /// failure.
+    ///
+    /// [`Verdict::Right`]: crate::Verdict::Right
+    Passes,
+    /// The test function returned [`Verdict::Skip`]: the configuration could not be tested.
+    ///
+    /// [`Verdict::Skip`]: crate::Verdict::Skip
+    Unresolved,
+}
+
+impl Resolution {
+    fn of<L, R, S>(verdict: &Verdict<L, R, S>) -> Self {
+        match verdict {
+            Verdict::Left(_) => Self::Fails,
+            Verdict::Right(_) => Self::Passes,
+            Verdict::Skip(_) => Self::Unresolved,
+        }
+    }
+}
+
+/// A cache of the configurations which were already tested while minimizing, so the test function
+/// is called at most once per configuration.
+///
+/// A configuration is identified by the indices of the items it consists of, in ascending order.
+///
+/// The cache is implemented for `&mut C`, so a cache can be lent to a [`Minimizer`], and inspected
+/// or reused once minimizing has finished.
+///
+/// [`Minimizer`]: crate::Minimizer
+pub trait Cache {
+    /// The resolution of the given configuration, if it was tested before.
+    fn get(&self, configuration: &[usize]) -> Option<Resolution>;
+
+    /// Store the resolution of the given configuration.
+    fn insert(&mut self, configuration: &[usize], resolution: Resolution);
+}
+
+impl<C: Cache + ?Sized> Cache for &mut C {
+    fn get(&self, configuration: &[usize]) -> Option<Resolution> {
+        (**self).get(configuration)
+    }
+
+    fn insert(&mut self, configuration: &[usize], resolution: Resolution) {
+        (**self).insert(configuration, resolution)
+    }
+}
+
+/// An in-memory [`Cache`], which stores the resolution of every tested configuration.
+///
+/// [`Cache`]: crate::Cache
+#[derive(Debug, Default)]
+pub struct MemoryCache {
+    resolutions: HashMap<Vec<usize>, Resolution>,
+}
+
+impl MemoryCache {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of configurations in the cache.
+    pub fn len(&self) -> usize {
+        self.resolutions.len()
+    }
+
+    /// Whether no configuration has been stored in the cache yet.
+    pub fn is_empty(&self) -> bool {
+        self.resolutions.is_empty()
+    }
+}
+
+impl Cache for MemoryCache {
+    fn get(&self, configuration: &[usize]) -> Option<Resolution> {
+        self.resolutions.get(configuration).copied()
+    }
+
+    fn insert(&mut self, configuration: &[usize], resolution: Resolution) {
+        self.resolutions.insert(configuration.to_vec(), resolution);
+    }
+}
+
+/// The minimal failing configuration, as produced by [`Minimizer::ddmin`] and
+/// [`Minimizer::try_ddmin`].
+///
+/// [`Minimizer::ddmin`]: crate::Minimizer::ddmin
+/// [`Minimizer::try_ddmin`]: crate::Minimizer::try_ddmin
+pub struct Minimized<'v, T, L> {
+    /// The indices of the items of the minimal failing configuration, in ascending order.
+    pub indices: Vec<usize>,
+    /// The items of the minimal failing configuration, in the order of the view.
+    pub items: Vec<&'v T>,
+    /// The output of the test function for the minimal failing configuration.
+    pub output: L,
+    /// The number of times the test function was called.
+    pub tests: usize,
+    /// The number of times the resolution of a configuration was taken from the cache, instead of
+    /// calling the test function.
+    pub cache_hits: usize,
+}
+
+/// Minimizes a failing set of items, such as changes, flags or lines of an input, using the delta
+/// debugging algorithm (`ddmin`) by Zeller and Hildebrandt.
+///
+/// Where bisection finds a single culprit in an ordered view, delta debugging finds a minimal
+/// subset of the view which still reproduces a failure, when the failure is caused by a
+/// combination of items.
+///
+/// The test function is given a configuration, i.e. a subset of the items in the view, in the
+/// order of the view, and returns a [`Verdict`]:
+/// * [`Verdict::Left`]: the configuration reproduces the failure
+/// * [`Verdict::Right`]: the configuration doesn't reproduce the failure
+/// * [`Verdict::Skip`]: the outcome is unresolved, e.g. because the configuration is inconsistent
+///
+/// Like a bisection, which converges to the left for the elements at or after the culprit,
+/// [`Verdict::Left`] stands for the failing side.
+///
+/// [`Verdict`]: crate::Verdict
+/// [`Verdict::Left`]: crate::Verdict::Left
+/// [`Verdict::Right`]: crate::Verdict::Right
+/// [`Verdict::Skip`]: crate::Verdict::Skip
+#[derive(Debug)]
+pub struct Minimizer<'v, T, C = MemoryCache> {
+    view: &'v [T],
+    cache: C,
+}
+
+impl<'v, T> Minimizer<'v, T> {
+    /// Create a new minimizer for the given view, with an empty [`MemoryCache`].
+    ///
+    /// [`MemoryCache`]: crate::MemoryCache
+    pub fn new(view: &'v [T]) -> Self {
+        Self {
+            view,
+            cache: MemoryCache::new(),
+        }
+    }
+}
+
+impl<'v, T, C: Cache> Minimizer<'v, T, C> {
+    /// Use the given [`Cache`] to store the resolution of tested configurations, for example to
+    /// share tested configurations between runs.
+    ///
+    /// [`Cache`]: crate::Cache
+    pub fn with_cache<D: Cache>(self, cache: D) -> Minimizer<'v, T, D> {
+        Minimizer {
+            view: self.view,
+            cache,
+        }
+    }
+
+    /// The view of items which is minimized.
+    pub fn view(&self) -> &'v [T] {
+        self.view
+    }
+
+    /// The cache which stores the resolution of tested configurations.
+    pub fn cache(&self) -> &C {
+        &self.cache
+    }
+
+    /// Find a minimal subset of the view which still reproduces the failure.
+    ///
+    /// The complete view must reproduce the failure, and is always tested first, without
+    /// consulting the cache. If it doesn't, a [`MinimizeError::Passes`] or
+    /// [`MinimizeError::Unresolved`] `Err` result will be returned.
+    ///
+    /// The view is split into `n = 2` parts. If one of the parts, or its complement, reproduces the
+    /// failure, the search continues with that configuration. Otherwise, the granularity `n` is
+    /// doubled, until each part consists of a single item. The resulting configuration is
+    /// 1-minimal: removing any single item makes the failure disappear (or unresolved). Unresolved
+    /// configurations are treated like configurations which don't reproduce the failure.
+    ///
+    /// When the failure of the minimal configuration was taken from the cache, it's tested once
+    /// more to obtain its output. If it no longer reproduces the failure, e.g. because the cache was
+    /// filled by a different test function, a [`MinimizeError::Passes`] or
+    /// [`MinimizeError::Unresolved`] `Err` result will be returned as well.
+    ///
+    /// ```
+    /// use bisector::{Minimizer, Verdict};
+    ///
+    /// // The failure only occurs when both flag 3 and flag 7 are set
+    /// let flags = (0..10).collect::<Vec<u32>>();
+    /// let mut minimizer = Minimizer::new(&flags);
+    ///
+    /// let minimized = minimizer
+    ///     .ddmin(|configuration: &[&u32]| {
+    ///         if configuration.contains(&&3) && configuration.contains(&&7) {
+    ///             Verdict::<_, (), ()>::Left("crashed")
+    ///         } else {
+    ///             Verdict::Right(())
+    ///         }
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(minimized.items, vec![&3, &7]);
+    /// assert_eq!(minimized.output, "crashed");
+    /// ```
+    ///
+    /// See also:
+    /// * [`Minimizer::try_ddmin`]: A variant of [`ddmin`] which can be used when the test
+    ///   function is fallible.
+    ///
+    /// [`MinimizeError::Passes`]: crate::MinimizeError::Passes
+    /// [`MinimizeError::Unresolved`]: crate::MinimizeError::Unresolved
+    /// [`Minimizer::try_ddmin`]: crate::Minimizer::try_ddmin
+    /// [`ddmin`]: crate::Minimizer::ddmin
+    pub fn ddmin<F, L, R, S>(
+        &mut self,
+        mut f: F,
+    ) -> Result<Minimized<'v, T, L>, MinimizeError<R, S>>
+    where
+        F: FnMut(&[&'v T]) -> Verdict<L, R, S>,
+    {
+        self.try_ddmin(|configuration| Ok::<_, Infallible>(f(configuration)))
+            .map_err(|err| match err {
+                MinimizeError::Passes(out) => MinimizeError::Passes(out),
+                MinimizeError::Unresolved(out) => MinimizeError::Unresolved(out),
+                MinimizeError::Convergence(infallible) => match infallible {},
+            })
+    }
+
+    /// This method can be used when the test function is fallible.
+    /// Otherwise exactly the same as [`Minimizer::ddmin`].
+    ///
+    /// Minimizing stops as soon as the test function returns an error, which is returned as a
+    /// [`MinimizeError::Convergence`] error. The configurations tested up to that point remain in
+    /// the cache.
+    ///
+    /// [`Minimizer::ddmin`]: crate::Minimizer::ddmin
+    /// [`MinimizeError::Convergence`]: crate::MinimizeError::Convergence
+    pub fn try_ddmin<F, E, L, R, S>(
+        &mut self,
+        mut f: F,
+    ) -> Result<Minimized<'v, T, L>, MinimizeError<R, S, E>>
+    where
+        F: FnMut(&[&'v T]) -> Result<Verdict<L, R, S>, E>,
+    {
+        let view = self.view;
+        let mut test = |configuration: &[usize]| {
+            let items = configuration
+                .iter()
+                .map(|&index| &view[index])
+                .collect::<Vec<_>>();
+
+            f(&items)
+        };
+
+        let all = (0..view.len()).collect::<Vec<_>>();
+
+        let output = failure(test(&all))?;
+
+        self.cache.insert(&all, Resolution::Fails);
+
+        let len = all.len();
+        let mut reduction =
+            ddmin(all, &mut self.cache, &mut test).map_err(MinimizeError::Convergence)?;
+
+        let output = match reduction.output {
+            Some(output) => output,
+            // The failure of the minimal configuration was taken from the cache
+            None if reduction.configuration.len() < len => {
+                reduction.tests += 1;
+                failure(test(&reduction.configuration))?
+            }
+            None => output,
+        };
+
+        Ok(Minimized {
+            items: reduction
+                .configuration
+                .iter()
+                .map(|&index| &view[index])
+                .collect(),
+            indices: reduction.configuration,
+            output,
+            tests: reduction.tests + 1,
+            cache_hits: reduction.cache_hits,
+        })
+    }
+}
+
+// Takes the output of a verdict which must reproduce the failure.
+pub(crate) fn failure<L, R, S, E>(
+    verdict: Result<Verdict<L, R, S>, E>,
+) -> Result<L, MinimizeError<R, S, E>> {
+    match verdict.map_err(MinimizeError::Convergence)? {
+        Verdict::Left(out) => Ok(out),
+        Verdict::Right(out) => Err(MinimizeError::Passes(out)),
+        Verdict::Skip(out) => Err(MinimizeError::Unresolved(out)),
+    }
+}
+
+// The result of reducing a failing configuration with `ddmin`.
+pub(crate) struct Reduction<L> {
+    pub(crate) configuration: Vec<usize>,
+    // The output of the test function for the reduced configuration. None if the configuration
+    // wasn't reduced, or if the failure of the reduced configuration was taken from the cache
+    pub(crate) output: Option<L>,
+    pub(crate) tests: usize,
+    pub(crate) cache_hits: usize,
+}
+
+// Tests configurations, consulting and updating the cache.
+struct Tester<'c, F, C: ?Sized, L> {
+    test: F,
+    cache: &'c mut C,
+    output: Option<L>,
+    tests: usize,
+    cache_hits: usize,
+}
+
+impl<'c, F, C, L> Tester<'c, F, C, L>
+where
+    C: Cache + ?Sized,
+{
+    fn fails<E, R, S>(&mut self, configuration: &[usize]) -> Result<bool, E>
+    where
+        F: FnMut(&[usize]) -> Result<Verdict<L, R, S>, E>,
+    {
+        if let Some(resolution) = self.cache.get(configuration) {
+            self.cache_hits += 1;
+
+            // A failing configuration is always adopted, so the output of an earlier one is stale
+            if resolution == Resolution::Fails {
+                self.output = None;
+            }
+
+            return Ok(resolution == Resolution::Fails);
+        }
+
+        let verdict = (self.test)(configuration)?;
+        let resolution = Resolution::of(&verdict);
+
+        self.tests += 1;
+        self.cache.insert(configuration, resolution);
+
+        if let Verdict::Left(out) = verdict {
+            self.output = Some(out);
+        }
+
+        Ok(resolution == Resolution::Fails)
+    }
+}
+
+// Zeller and Hildebrandt's `ddmin` over a failing configuration of indices, which must be in
+// ascending order. The given configuration itself is not tested.
+pub(crate) fn ddmin<F, E, L, R, S, C>(
+    mut configuration: Vec<usize>,
+    cache: &mut C,
+    test: F,
+) -> Result<Reduction<L>, E>
+where
+    F: FnMut(&[usize]) -> Result<Verdict<L, R, S>, E>,
+    C: Cache + ?Sized,
+{
+    let mut tester = Tester {
+        test,
+        cache,
+        output: None,
+        tests: 0,
+        cache_hits: 0,
+    };
+
+    let mut granularity = 2;
+
+    while configuration.len() >= 2 {
+        let len = configuration.len();
+        let parts = (0..granularity)
+            .map(|part| (part * len / granularity, (part + 1) * len / granularity))
+            .collect::<Vec<_>>();
+
+        let mut reduced = false;
+
+        // Reduce to a part
+        for &(start, end) in &parts {
+            let subset = &configuration[start..end];
+
+            if tester.fails(subset)? {
+                configuration = subset.to_vec();
+                granularity = 2;
+                reduced = true;
+                break;
+            }
+        }
+
+        // Reduce to a complement; with two parts, the complements are the parts themselves
+        if !reduced && granularity > 2 {
+            for &(start, end) in &parts {
+                let complement = configuration[..start]
+                    .iter()
+                    .chain(&configuration[end..])
+                    .copied()
+                    .collect::<Vec<_>>();
+
+                if tester.fails(&complement)? {
+                    configuration = complement;
+                    granularity = (granularity - 1).max(2);
+                    reduced = true;
+                    break;
+                }
+            }
+        }
+
+        if !reduced {
+            if granularity >= len {
+                break;
+            }
+
+            granularity = (granularity * 2).min(len);
+        }
+    }
+
+    Ok(Reduction {
+        configuration,
+        output: tester.output,
+        tests: tester.tests,
+        cache_hits: tester.cache_hits,
+    })
+}
@@ -0,0 +1,417 @@
+use crate::{ConvergeTo, EmptySliceError, InvalidNoiseError};
+
+/// The rates at which a noisy convergence function converges to the wrong side.
+///
+/// The [`Bisector`] converges to the first element for which the convergence function converges to
+/// the left, i.e. elements before this boundary should converge to the right, and elements at or
+/// after the boundary should converge to the left. A convergence function which is noisy may not
+/// always do so:
+///
+/// * The false positive rate is the probability that an element before the boundary converges to
+///   the left.
+/// * The false negative rate is the probability that an element at or after the boundary converges
+///   to the right.
+///
+/// [`Bisector`]: crate::Bisector
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Noise {
+    false_positive: f64,
+    false_negative: f64,
+}
+
+impl Noise {
+    /// Create a new noise model from a false positive and false negative rate.
+    ///
+    /// Both rates must be in the range `[0, 0.5)`, as otherwise the convergence function is not
+    /// more likely to be right than wrong. If either rate is out of range, an [`InvalidNoiseError`]
+    /// `Err` result will be returned.
+    ///
+    /// [`InvalidNoiseError`]: crate::InvalidNoiseError
+    pub fn new(false_positive: f64, false_negative: f64) -> Result<Self, InvalidNoiseError> {
+        let is_valid = |rate: f64| (0.0..0.5).contains(&rate);
+
+        if is_valid(false_positive) && is_valid(false_negative) {
+            Ok(Self {
+                false_positive,
+                false_negative,
+            })
+        } else {
+            Err(InvalidNoiseError)
+        }
+    }
+
+    /// The probability that an element before the boundary converges to the left.
+    pub fn false_positive(&self) -> f64 {
+        self.false_positive
+    }
+
+    /// The probability that an element at or after the boundary converges to the right.
+    pub fn false_negative(&self) -> f64 {
+        self.false_negative
+    }
+}
+
+/// A probability distribution over the elements of the view of a [`NoisyBisector`], which describes
+/// for each index how likely it is that the element at that index is the boundary.
+///
+/// [`NoisyBisector`]: crate::NoisyBisector
+#[derive(Debug, Clone, PartialEq)]
+pub struct Posterior {
+    probabilities: Vec<f64>,
+}
+
+impl Posterior {
+    /// Create a uniform distribution over the complete view of the [`NoisyBisector`], i.e. before
+    /// any element has been probed, each element is equally likely to be the boundary.
+    ///
+    /// The view must not be empty. If it is, an [`EmptySliceError`] `Err` result will be returned.
+    ///
+    /// [`NoisyBisector`]: crate::NoisyBisector
+    /// [`EmptySliceError`]: crate::EmptySliceError
+    pub fn try_from_bisector<T>(bisector: &NoisyBisector<T>) -> Result<Self, EmptySliceError> {
+        let len = bisector.view.len();
+
+        if len > 0 {
+            Ok(Self {
+                probabilities: vec![1.0 / len as f64; len],
+            })
+        } else {
+            Err(EmptySliceError)
+        }
+    }
+
+    /// The probability of each index being the boundary. The probabilities sum up to `1`.
+    pub fn probabilities(&self) -> &[f64] {
+        &self.probabilities
+    }
+
+    /// The index which is most likely to be the boundary, and its probability.
+    ///
+    /// When several indices are equally likely, the lowest index is returned.
+    pub fn most_likely(&self) -> (usize, f64) {
+        self.probabilities
+            .iter()
+            .copied()
+            .enumerate()
+            .fold(
+                (0, 0.0),
+                |best, (index, p)| {
+                    if p > best.1 {
+                        (index, p)
+                    } else {
+                        best
+                    }
+                },
+            )
+    }
+
+    // The index to probe next: the median of the distribution, i.e. the index which splits the
+    // probability mass into two halves which are as close to even as possible, so each observation
+    // is as informative as possible.
+    //
+    // The last index is never probed, since we already know that the boundary is at or before it,
+    // so probing it would not provide information.
+    fn median(&self) -> usize {
+        let last = self.probabilities.len() - 1;
+        let mut cumulative = 0.0;
+        let mut median = (0, f64::INFINITY);
+
+        for (index, p) in self.probabilities[..last].iter().enumerate() {
+            cumulative += p;
+
+            let distance = (cumulative - 0.5f64).abs();
+
+            if distance < median.1 {
+                median = (index, distance);
+            }
+        }
+
+        median.0
+    }
+
+    // Updates the distribution with the observation that the element at `probe` converged to the
+    // given side.
+    fn update(&mut self, probe: usize, converged_left: bool, noise: &Noise) {
+        // The probability of the observation, if the boundary is at or before the probe (then the
+        // probe is truly left), or after the probe (then the probe is truly right)
+        let (at_or_before, after) = if converged_left {
+            (1.0 - noise.false_negative, noise.false_positive)
+        } else {
+            (noise.false_negative, 1.0 - noise.false_positive)
+        };
+
+        for (index, p) in self.probabilities.iter_mut().enumerate() {
+            *p *= if index <= probe { at_or_before } else { after };
+        }
+
+        let total = self.probabilities.iter().sum::<f64>();
+
+        // Only possible for a noiseless model when the observations contradict each other; we keep
+        // the distribution as it was before the update, as there is no way to weigh the evidence.
+        // The same index is then probed again, until the probe limit of `run` is reached.
+        if total > 0.0 {
+            for p in self.probabilities.iter_mut() {
+                *p /= total;
+            }
+        }
+    }
+}
+
+/// Probabilistic (Bayesian) implementation of the bisection method, for noisy convergence functions.
+///
+/// Where the [`Bisector`] halves the view at each step, and can never recover from a convergence
+/// function which converged to the wrong side, the `NoisyBisector` keeps a [`Posterior`]
+/// distribution over the elements of the view, which describes how likely each element is to be the
+/// boundary. Each step probes the median of this distribution, and updates the distribution with
+/// the outcome, taking into account the configured [`Noise`] of the convergence function.
+///
+/// The bisection has converged once the probability of the most likely element exceeds the
+/// confidence threshold.
+///
+/// [`Bisector`]: crate::Bisector
+/// [`Posterior`]: crate::Posterior
+/// [`Noise`]: crate::Noise
+#[derive(Debug)]
+pub struct NoisyBisector<'v, T> {
+    view: &'v [T],
+    noise: Noise,
+    confidence: f64,
+    max_probes: Option<usize>,
+}
+
+impl<'v, T> NoisyBisector<'v, T> {
+    /// Create a new [`NoisyBisector`] for a given view and noise model.
+    ///
+    /// By default, the bisection has converged once an element is the boundary with a probability
+    /// of at least `0.95`, and the number of probes is limited as described by
+    /// [`NoisyBisector::max_probes`].
+    ///
+    /// [`NoisyBisector::max_probes`]: crate::NoisyBisector::max_probes
+    /// [`NoisyBisector`]: crate::NoisyBisector
+    pub fn new(view: &'v [T], noise: Noise) -> Self {
+        Self {
+            view,
+            noise,
+            confidence: 0.95,
+            max_probes: None,
+        }
+    }
+
+    /// Set the probability which the most likely element must reach, for the bisection to be
+    /// considered converged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the confidence is not in the range `(0, 1)`, since a noisy bisection can't become
+    /// certain of the boundary.
+    pub fn with_confidence(self, confidence: f64) -> Self {
+        assert!(
+            confidence > 0.0 && confidence < 1.0,
+            "the confidence must be in the range (0, 1), but was {}",
+            confidence
+        );
+
+        Self { confidence, ..self }
+    }
+
+    /// Limit the number of times [`NoisyBisector::run`] may call the convergence function,
+    /// replacing the default limit.
+    ///
+    /// [`NoisyBisector::run`]: crate::NoisyBisector::run
+    pub fn with_max_probes(self, max_probes: usize) -> Self {
+        Self {
+            max_probes: Some(max_probes),
+            ..self
+        }
+    }
+
+    /// A view of the slice as known to the bisector.
+    pub fn view(&self) -> &'v [T] {
+        self.view
+    }
+
+    /// The noise model of the convergence function.
+    pub fn noise(&self) -> Noise {
+        self.noise
+    }
+
+    /// The maximum number of times [`NoisyBisector::run`] calls the convergence function.
+    ///
+    /// Unless set with [`NoisyBisector::with_max_probes`], the limit is derived from the length of
+    /// the view, the confidence and the noise model: each probe provides at most `1 - H(p)` bits of
+    /// information about the boundary, where `H` is the binary entropy function and `p` the highest
+    /// of both noise rates, and locating the boundary with the given confidence takes about
+    /// `log2(len) + log2(1 / (1 - confidence))` bits. The default limit allows four times as many
+    /// probes as this takes, so the bisection stops even when the convergence function is noisier
+    /// than the model, or contradicts itself.
+    ///
+    /// [`NoisyBisector::run`]: crate::NoisyBisector::run
+    /// [`NoisyBisector::with_max_probes`]: crate::NoisyBisector::with_max_probes
+    pub fn max_probes(&self) -> usize {
+        self.max_probes.unwrap_or_else(|| {
+            let p = self.noise.false_positive.max(self.noise.false_negative);
+            let entropy = if p > 0.0 {
+                -p * p.log2() - (1.0 - p) * (1.0 - p).log2()
+            } else {
+                0.0
+            };
+
+            let bits = (self.view.len().max(1) as f64).log2() - (1.0 - self.confidence).log2();
+
+            // Saturates at usize::MAX when the noise rate approaches one half
+            (4.0 * bits / (1.0 - entropy)).ceil() as usize
+        })
+    }
+
+    /// Stateless implementation of a step of the probabilistic bisection method.
+    ///
+    /// Like [`Bisector::bisect`], but instead of indices, the distribution produced by the previous
+    /// step (or [`Posterior::try_from_bisector`] for the first step) must be provided. The
+    /// convergence function `f` is called for the median of the distribution, after which the
+    /// updated distribution is returned as part of the [`NoisyStep`].
+    ///
+    /// The `result` of the step is `None` if the given distribution had already converged, in which
+    /// case `f` is not called.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the distribution doesn't hold a probability for each element of the view, e.g.
+    /// because it was created for another view.
+    ///
+    /// [`Bisector::bisect`]: crate::Bisector::bisect
+    /// [`Posterior::try_from_bisector`]: crate::Posterior::try_from_bisector
+    /// [`NoisyStep`]: crate::NoisyStep
+    pub fn bisect<F, L, R>(&self, f: F, posterior: Posterior) -> NoisyStep<L, R>
+    where
+        F: FnOnce(&T) -> ConvergeTo<L, R>,
+    {
+        assert_eq!(
+            posterior.probabilities().len(),
+            self.view.len(),
+            "the posterior must hold a probability for each element of the view"
+        );
+
+        let mut posterior = posterior;
+
+        if self.has_converged(&posterior) {
+            return NoisyStep {
+                posterior,
+                result: None,
+            };
+        }
+
+        let probe = posterior.median();
+        let result = f(&self.view[probe]);
+
+        let converged_left = match result {
+            ConvergeTo::Left(_) => true,
+            ConvergeTo::Right(_) => false,
+        };
+
+        posterior.update(probe, converged_left, &self.noise);
+
+        NoisyStep {
+            posterior,
+            result: Some((probe, result)),
+        }
+    }
+
+    /// Run the probabilistic bisection method to completion, starting from a uniform distribution.
+    ///
+    /// The bisection stops once it has converged, or once the maximum number of probes, as given
+    /// by [`NoisyBisector::max_probes`], has been reached.
+    ///
+    /// The view must not be empty. If it is, an [`EmptySliceError`] `Err` result will be returned.
+    ///
+    /// ```
+    /// use bisector::{ConvergeTo, Noise, NoisyBisector};
+    ///
+    /// let values = (0..100).collect::<Vec<u32>>();
+    /// let bisector = NoisyBisector::new(&values, Noise::new(0.1, 0.1).unwrap());
+    ///
+    /// // Every seventh probe reports the wrong side
+    /// let mut probes = 0;
+    /// let outcome = bisector
+    ///     .run(|&value| {
+    ///         probes += 1;
+    ///         let is_left = value >= 42;
+    ///
+    ///         if is_left != (probes % 7 == 0) {
+    ///             ConvergeTo::Left(())
+    ///         } else {
+    ///             ConvergeTo::Right(())
+    ///         }
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert!(outcome.converged);
+    /// assert_eq!(outcome.index, 42);
+    /// ```
+    ///
+    /// [`NoisyBisector::max_probes`]: crate::NoisyBisector::max_probes
+    /// [`EmptySliceError`]: crate::EmptySliceError
+    pub fn run<F, L, R>(&self, mut f: F) -> Result<NoisyOutcome, EmptySliceError>
+    where
+        F: FnMut(&T) -> ConvergeTo<L, R>,
+    {
+        let mut posterior = Posterior::try_from_bisector(self)?;
+        let max_probes = self.max_probes();
+        let mut probes = 0;
+
+        while probes < max_probes {
+            let step = self.bisect(|value| f(value), posterior);
+            posterior = step.posterior;
+
+            if step.result.is_none() {
+                break;
+            }
+
+            probes += 1;
+        }
+
+        let (index, confidence) = posterior.most_likely();
+
+        Ok(NoisyOutcome {
+            index,
+            confidence,
+            converged: self.has_converged(&posterior),
+            posterior,
+            probes,
+        })
+    }
+
+    fn has_converged(&self, posterior: &Posterior) -> bool {
+        posterior.most_likely().1 >= self.confidence
+    }
+}
+
+/// The output of one probabilistic bisection step.
+///
+/// The output consists of two parts:
+/// * `posterior`: The distribution updated with the outcome of this step
+/// * `result`: The index of the probed element, and the output of the convergence function
+///
+/// The `posterior` can be used to progress the bisection, like the `indices` of a [`Step`].
+///
+/// [`Step`]: crate::Step
+pub struct NoisyStep<L, R> {
+    pub posterior: Posterior,
+    pub result: Option<(usize, ConvergeTo<L, R>)>,
+}
+
+/// The outcome of a probabilistic bisection, as produced by [`NoisyBisector::run`].
+///
+/// [`NoisyBisector::run`]: crate::NoisyBisector::run
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoisyOutcome {
+    /// The index of the element which is most likely to be the boundary.
+    pub index: usize,
+    /// The probability that the element at `index` is the boundary.
+    pub confidence: f64,
+    /// Whether the confidence threshold was reached, before the maximum number of probes.
+    pub converged: bool,
+    /// The probability of each element being the boundary.
+    pub posterior: Posterior,
+    /// The number of times the convergence function was called.
+    pub probes: usize,
+}
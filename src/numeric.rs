@@ -0,0 +1,676 @@
+use crate::{ConvergeTo, NotBracketedError};
+
+/// The left and right endpoints of an interval of real numbers, which brackets a root.
+///
+/// The continuous counterpart of [`Indices`]: the [`ContinuousBisector::bisect`] method takes a
+/// `Bracket` as input, and produces the narrowed `Bracket` as output.
+///
+/// [`Indices`]: crate::Indices
+/// [`ContinuousBisector::bisect`]: crate::ContinuousBisector::bisect
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Bracket {
+    pub left: f64,
+    pub right: f64,
+}
+
+impl Bracket {
+    /// Create a new bracket. The endpoints are ordered, such that `left <= right`.
+    pub fn new(left: f64, right: f64) -> Self {
+        Self {
+            left: left.min(right),
+            right: left.max(right),
+        }
+    }
+
+    /// The point halfway between the left and right endpoints.
+    pub fn midpoint(&self) -> f64 {
+        self.left + (self.right - self.left) / 2.0
+    }
+
+    /// The distance between the left and right endpoints.
+    pub fn width(&self) -> f64 {
+        self.right - self.left
+    }
+
+    // Whether there is no f64 strictly between both endpoints, in which case the bracket can't be
+    // narrowed any further.
+    pub(crate) fn is_exhausted(&self) -> bool {
+        let midpoint = self.midpoint();
+
+        midpoint <= self.left || midpoint >= self.right
+    }
+}
+
+/// The tolerance at which a root finder considers its bracket narrow enough.
+///
+/// A bracket is narrow enough when its width is at most the absolute tolerance, or at most the
+/// relative tolerance times the magnitude of its midpoint.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Tolerance {
+    absolute: f64,
+    relative: f64,
+}
+
+impl Tolerance {
+    /// Create a tolerance from an absolute and a relative tolerance.
+    pub fn new(absolute: f64, relative: f64) -> Self {
+        Self { absolute, relative }
+    }
+
+    /// Create a tolerance which only considers the absolute width of the bracket.
+    pub fn absolute(absolute: f64) -> Self {
+        Self::new(absolute, 0.0)
+    }
+
+    /// Create a tolerance which only considers the width of the bracket relative to its midpoint.
+    pub fn relative(relative: f64) -> Self {
+        Self::new(0.0, relative)
+    }
+
+    /// Whether the given bracket is narrow enough.
+    pub fn is_satisfied(&self, bracket: &Bracket) -> bool {
+        let width = bracket.width();
+
+        width <= self.absolute || width <= self.relative * bracket.midpoint().abs()
+    }
+}
+
+impl Default for Tolerance {
+    /// An absolute tolerance of `1e-12`, and a relative tolerance of four times the machine
+    /// epsilon.
+    fn default() -> Self {
+        Self::new(1e-12, 4.0 * f64::EPSILON)
+    }
+}
+
+/// The reason a root finder stopped.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum StopReason {
+    /// The function evaluated to exactly zero.
+    Exact,
+    /// The bracket is narrower than the [`Tolerance`].
+    ///
+    /// [`Tolerance`]: crate::Tolerance
+    Tolerance,
+    /// Two consecutive estimates are within the [`Tolerance`], while the bracket itself may still
+    /// be wider. Only reported by [`Method::FalsePosition`] and [`Method::Illinois`].
+    ///
+    /// [`Tolerance`]: crate::Tolerance
+    /// [`Method::FalsePosition`]: crate::Method::FalsePosition
+    /// [`Method::Illinois`]: crate::Method::Illinois
+    EstimatesConverged,
+    /// The bracket can't be narrowed any further, since there is no `f64` between its endpoints.
+    Precision,
+    /// The maximum number of iterations was reached, before the bracket was narrow enough.
+    MaxIterations,
+}
+
+/// The method used by [`ContinuousBisector::find_root`] to find a root within the bracket.
+///
+/// All methods keep a bracket around the root, and produce the same [`Root`] result, so a root
+/// finder can switch methods without changing the code which uses the result.
+///
+/// [`ContinuousBisector::find_root`]: crate::ContinuousBisector::find_root
+/// [`Root`]: crate::Root
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Method {
+    /// The bisection method, which halves the bracket at each iteration. Slow, but its convergence
+    /// is guaranteed, and doesn't depend on the shape of the function.
+    Bisection,
+    /// The false position method (regula falsi), which probes where the secant through both
+    /// endpoints of the bracket crosses zero.
+    ///
+    /// Usually converges faster than bisection, but since one endpoint may never move, the bracket
+    /// itself may not shrink. The method therefore also stops when two consecutive estimates are
+    /// within the tolerance, with [`StopReason::EstimatesConverged`].
+    ///
+    /// [`StopReason::EstimatesConverged`]: crate::StopReason::EstimatesConverged
+    FalsePosition,
+    /// The Illinois variant of the false position method, which halves the function value of an
+    /// endpoint which was retained twice in a row, so the bracket shrinks from both sides.
+    ///
+    /// Like [`Method::FalsePosition`], the method also stops when two consecutive estimates are
+    /// within the tolerance, with [`StopReason::EstimatesConverged`].
+    ///
+    /// [`Method::FalsePosition`]: crate::Method::FalsePosition
+    /// [`StopReason::EstimatesConverged`]: crate::StopReason::EstimatesConverged
+    Illinois,
+    /// Brent's method, which combines inverse quadratic interpolation and the secant method with
+    /// bisection. Converges superlinearly for well-behaved functions, while never converging slower
+    /// than bisection.
+    Brent,
+}
+
+/// The result of a root finder.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Root {
+    /// The best estimate of the root.
+    pub estimate: f64,
+    /// The final bracket, which contains the root.
+    pub bracket: Bracket,
+    /// The number of iterations, i.e. the number of times the function was evaluated, not counting
+    /// the evaluations of the endpoints of the initial bracket.
+    pub iterations: usize,
+    /// Why the root finder stopped.
+    pub reason: StopReason,
+}
+
+/// The output of one continuous bisection step.
+///
+/// The output consists of two parts:
+/// * `bracket`: The bracket of the converged interval
+/// * `result`: The output of the convergence function
+///
+/// Like a [`Step`], the `result` is `None` when the given bracket was already narrow enough, in
+/// which case the convergence function was not called.
+///
+/// [`Step`]: crate::Step
+pub struct ContinuousStep<L, R> {
+    pub bracket: Bracket,
+    pub result: Option<ConvergeTo<L, R>>,
+}
+
+/// Stateless implementation of the continuous bisection method, which finds a root of a function
+/// over an interval of real numbers.
+///
+/// Where the [`Bisector`] halves a view of discrete elements, the `ContinuousBisector` halves an
+/// interval `[a, b]`, until the interval is narrower than the configured [`Tolerance`], or until
+/// the maximum number of iterations has been reached.
+///
+/// Besides bisection, [`ContinuousBisector::find_root`] supports faster converging bracketing
+/// methods, which can be selected with [`ContinuousBisector::with_method`].
+///
+/// [`Bisector`]: crate::Bisector
+/// [`Tolerance`]: crate::Tolerance
+/// [`ContinuousBisector::find_root`]: crate::ContinuousBisector::find_root
+/// [`ContinuousBisector::with_method`]: crate::ContinuousBisector::with_method
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ContinuousBisector {
+    bracket: Bracket,
+    tolerance: Tolerance,
+    max_iterations: usize,
+    method: Method,
+}
+
+impl ContinuousBisector {
+    /// Create a new [`ContinuousBisector`] for the interval `[a, b]`.
+    ///
+    /// By default, the [`Tolerance::default`] is used, the number of iterations is limited to `100`,
+    /// and [`ContinuousBisector::find_root`] uses [`Method::Bisection`].
+    ///
+    /// [`ContinuousBisector`]: crate::ContinuousBisector
+    /// [`Tolerance::default`]: crate::Tolerance::default
+    /// [`ContinuousBisector::find_root`]: crate::ContinuousBisector::find_root
+    /// [`Method::Bisection`]: crate::Method::Bisection
+    pub fn new(a: f64, b: f64) -> Self {
+        Self {
+            bracket: Bracket::new(a, b),
+            tolerance: Tolerance::default(),
+            max_iterations: 100,
+            method: Method::Bisection,
+        }
+    }
+
+    /// Set the tolerance at which the bracket is considered narrow enough.
+    pub fn with_tolerance(self, tolerance: Tolerance) -> Self {
+        Self { tolerance, ..self }
+    }
+
+    /// Limit the number of iterations.
+    pub fn with_max_iterations(self, max_iterations: usize) -> Self {
+        Self {
+            max_iterations,
+            ..self
+        }
+    }
+
+    /// Set the method used by [`ContinuousBisector::find_root`].
+    ///
+    /// [`ContinuousBisector::find_root`]: crate::ContinuousBisector::find_root
+    pub fn with_method(self, method: Method) -> Self {
+        Self { method, ..self }
+    }
+
+    /// The initial bracket.
+    pub fn bracket(&self) -> Bracket {
+        self.bracket
+    }
+
+    /// The tolerance at which the bracket is considered narrow enough.
+    pub fn tolerance(&self) -> Tolerance {
+        self.tolerance
+    }
+
+    /// The method used by [`ContinuousBisector::find_root`].
+    ///
+    /// [`ContinuousBisector::find_root`]: crate::ContinuousBisector::find_root
+    pub fn method(&self) -> Method {
+        self.method
+    }
+
+    /// Stateless implementation of a continuous bisection step.
+    ///
+    /// Like [`Bisector::bisect`], the convergence function `f` is called for the midpoint of the
+    /// given bracket, and determines the half of the bracket in which the bisection continues:
+    /// [`ConvergeTo::Left`] continues with `[left, midpoint]`, and [`ConvergeTo::Right`] continues
+    /// with `[midpoint, right]`.
+    ///
+    /// When the bracket is already narrow enough, `f` is not called, and the `result` of the step
+    /// is `None`.
+    ///
+    /// [`Bisector::bisect`]: crate::Bisector::bisect
+    /// [`ConvergeTo::Left`]: crate::ConvergeTo::Left
+    /// [`ConvergeTo::Right`]: crate::ConvergeTo::Right
+    pub fn bisect<F, L, R>(&self, f: F, bracket: Bracket) -> ContinuousStep<L, R>
+    where
+        F: FnOnce(f64) -> ConvergeTo<L, R>,
+    {
+        if self.tolerance.is_satisfied(&bracket) || bracket.is_exhausted() {
+            return ContinuousStep {
+                bracket,
+                result: None,
+            };
+        }
+
+        let midpoint = bracket.midpoint();
+
+        match f(midpoint) {
+            ConvergeTo::Left(out) => ContinuousStep {
+                bracket: Bracket {
+                    left: bracket.left,
+                    right: midpoint,
+                },
+                result: Some(ConvergeTo::Left(out)),
+            },
+            ConvergeTo::Right(out) => ContinuousStep {
+                bracket: Bracket {
+                    left: midpoint,
+                    right: bracket.right,
+                },
+                result: Some(ConvergeTo::Right(out)),
+            },
+        }
+    }
+
+    /// Run the continuous bisection method to completion, with a convergence function which
+    /// decides at which side of each midpoint the root lies.
+    ///
+    /// The returned [`Root`] is never [`StopReason::Exact`], as the convergence function can't
+    /// express that a midpoint is the root itself.
+    ///
+    /// [`Root`]: crate::Root
+    /// [`StopReason::Exact`]: crate::StopReason::Exact
+    pub fn run<F, L, R>(&self, mut f: F) -> Root
+    where
+        F: FnMut(f64) -> ConvergeTo<L, R>,
+    {
+        let mut bracket = self.bracket;
+        let mut iterations = 0;
+
+        let reason = loop {
+            if let Some(reason) = self.converged(&bracket) {
+                break reason;
+            }
+
+            if iterations >= self.max_iterations {
+                break StopReason::MaxIterations;
+            }
+
+            bracket = self.bisect(&mut f, bracket).bracket;
+            iterations += 1;
+        };
+
+        Root {
+            estimate: bracket.midpoint(),
+            bracket,
+            iterations,
+            reason,
+        }
+    }
+
+    /// Find a root of the function `f`, i.e. a value `x` in the interval for which `f(x) = 0`, using
+    /// the configured [`Method`].
+    ///
+    /// The function must have a different sign at both endpoints of the interval (or be zero at
+    /// one of them). Otherwise, the interval doesn't necessarily bracket a root, and a
+    /// [`NotBracketedError`] `Err` result will be returned.
+    ///
+    /// ```
+    /// use bisector::{ContinuousBisector, Method, StopReason, Tolerance};
+    ///
+    /// let bisector = ContinuousBisector::new(1.0, 2.0).with_tolerance(Tolerance::absolute(1e-9));
+    ///
+    /// let bisection = bisector.find_root(|x| x * x - 2.0).unwrap();
+    /// let brent = bisector
+    ///     .with_method(Method::Brent)
+    ///     .find_root(|x| x * x - 2.0)
+    ///     .unwrap();
+    ///
+    /// assert!((bisection.estimate - 2f64.sqrt()).abs() < 1e-9);
+    /// assert!((brent.estimate - 2f64.sqrt()).abs() < 1e-9);
+    /// assert!(brent.iterations < bisection.iterations);
+    /// ```
+    ///
+    /// [`Method`]: crate::Method
+    /// [`NotBracketedError`]: crate::NotBracketedError
+    pub fn find_root<F>(&self, mut f: F) -> Result<Root, NotBracketedError>
+    where
+        F: FnMut(f64) -> f64,
+    {
+        let bracket = self.bracket;
+        let (f_left, f_right) = (f(bracket.left), f(bracket.right));
+
+        if let Some(root) = exact_endpoint(bracket, f_left, f_right)? {
+            return Ok(root);
+        }
+
+        let root = match self.method {
+            Method::Bisection => self.find_root_bisection(f, f_left),
+            Method::FalsePosition => self.find_root_false_position(f, f_left, f_right, false),
+            Method::Illinois => self.find_root_false_position(f, f_left, f_right, true),
+            Method::Brent => self.find_root_brent(f, f_left, f_right),
+        };
+
+        Ok(root)
+    }
+
+    fn find_root_bisection<F>(&self, mut f: F, f_left: f64) -> Root
+    where
+        F: FnMut(f64) -> f64,
+    {
+        let mut bracket = self.bracket;
+        let mut iterations = 0;
+
+        let reason = loop {
+            if let Some(reason) = self.converged(&bracket) {
+                break reason;
+            }
+
+            if iterations >= self.max_iterations {
+                break StopReason::MaxIterations;
+            }
+
+            let mut exact = None;
+
+            let step = self.bisect(
+                |x| {
+                    let y = f(x);
+
+                    if y == 0.0 {
+                        exact = Some(x);
+                    }
+
+                    if (y < 0.0) == (f_left < 0.0) {
+                        ConvergeTo::Right(())
+                    } else {
+                        ConvergeTo::<(), ()>::Left(())
+                    }
+                },
+                bracket,
+            );
+
+            iterations += 1;
+
+            if let Some(x) = exact {
+                bracket = Bracket::new(x, x);
+                break StopReason::Exact;
+            }
+
+            bracket = step.bracket;
+        };
+
+        Root {
+            estimate: bracket.midpoint(),
+            bracket,
+            iterations,
+            reason,
+        }
+    }
+
+    // The false position method, which probes where the secant through both endpoints crosses
+    // zero. The Illinois variant halves the function value of an endpoint which was retained twice
+    // in a row, so a bracket which converges from one side only still shrinks from both sides.
+    fn find_root_false_position<F>(
+        &self,
+        mut f: F,
+        f_left: f64,
+        f_right: f64,
+        illinois: bool,
+    ) -> Root
+    where
+        F: FnMut(f64) -> f64,
+    {
+        let (mut a, mut fa) = (self.bracket.left, f_left);
+        let (mut b, mut fb) = (self.bracket.right, f_right);
+
+        let mut estimate = self.bracket.midpoint();
+        let mut previous: Option<f64> = None;
+        let mut moved_left = None;
+        let mut iterations = 0;
+
+        let reason = loop {
+            let bracket = Bracket { left: a, right: b };
+
+            if let Some(reason) = self.converged(&bracket) {
+                estimate = bracket.midpoint();
+                break reason;
+            }
+
+            if iterations >= self.max_iterations {
+                break StopReason::MaxIterations;
+            }
+
+            let mut x = b - fb * (b - a) / (fb - fa);
+
+            // Rounding may put the secant root outside of the bracket
+            if !(x > a && x < b) {
+                x = bracket.midpoint();
+            }
+
+            let fx = f(x);
+            iterations += 1;
+            estimate = x;
+
+            if fx == 0.0 {
+                a = x;
+                b = x;
+                break StopReason::Exact;
+            }
+
+            if (fx < 0.0) == (fa < 0.0) {
+                a = x;
+                fa = fx;
+
+                if illinois && moved_left == Some(true) {
+                    fb /= 2.0;
+                }
+
+                moved_left = Some(true);
+            } else {
+                b = x;
+                fb = fx;
+
+                if illinois && moved_left == Some(false) {
+                    fa /= 2.0;
+                }
+
+                moved_left = Some(false);
+            }
+
+            if let Some(previous) = previous {
+                if self.tolerance.is_satisfied(&Bracket::new(previous, x)) {
+                    break StopReason::EstimatesConverged;
+                }
+            }
+
+            previous = Some(x);
+        };
+
+        Root {
+            estimate,
+            bracket: Bracket { left: a, right: b },
+            iterations,
+            reason,
+        }
+    }
+
+    // Brent's method, which combines inverse quadratic interpolation and the secant method with
+    // bisection, falling back to bisection whenever the interpolation doesn't shrink the bracket
+    // fast enough. Follows the formulation of Brent's zero algorithm in "Numerical Recipes".
+    fn find_root_brent<F>(&self, mut f: F, f_left: f64, f_right: f64) -> Root
+    where
+        F: FnMut(f64) -> f64,
+    {
+        // b is the best estimate, a is the previous estimate, and b and c bracket the root
+        let (mut a, mut fa) = (self.bracket.left, f_left);
+        let (mut b, mut fb) = (self.bracket.right, f_right);
+        let (mut c, mut fc) = (b, fb);
+        let (mut d, mut e) = (b - a, b - a);
+        let mut iterations = 0;
+
+        let reason = loop {
+            if (fb < 0.0) == (fc < 0.0) {
+                c = a;
+                fc = fa;
+                d = b - a;
+                e = d;
+            }
+
+            if fc.abs() < fb.abs() {
+                a = b;
+                b = c;
+                c = a;
+                fa = fb;
+                fb = fc;
+                fc = fa;
+            }
+
+            let bracket = Bracket::new(b, c);
+
+            if fb == 0.0 {
+                break StopReason::Exact;
+            }
+
+            let tolerance = 2.0 * f64::EPSILON * b.abs()
+                + 0.5
+                    * self
+                        .tolerance
+                        .absolute
+                        .max(self.tolerance.relative * b.abs());
+            let half_width = 0.5 * (c - b);
+
+            // Brent's own tolerance, which also accounts for the rounding of b, rather than the
+            // width of the bracket as checked by the other methods
+            if half_width.abs() <= tolerance {
+                break StopReason::Tolerance;
+            }
+
+            if bracket.is_exhausted() {
+                break StopReason::Precision;
+            }
+
+            if iterations >= self.max_iterations {
+                break StopReason::MaxIterations;
+            }
+
+            if e.abs() >= tolerance && fa.abs() > fb.abs() {
+                let s = fb / fa;
+
+                let (mut p, mut q) = if a == c {
+                    // Secant method
+                    (2.0 * half_width * s, 1.0 - s)
+                } else {
+                    // Inverse quadratic interpolation
+                    let q = fa / fc;
+                    let r = fb / fc;
+
+                    (
+                        s * (2.0 * half_width * q * (q - r) - (b - a) * (r - 1.0)),
+                        (q - 1.0) * (r - 1.0) * (s - 1.0),
+                    )
+                };
+
+                if p > 0.0 {
+                    q = -q;
+                }
+
+                p = p.abs();
+
+                let min = (3.0 * half_width * q - (tolerance * q).abs()).min((e * q).abs());
+
+                if 2.0 * p < min {
+                    e = d;
+                    d = p / q;
+                } else {
+                    d = half_width;
+                    e = d;
+                }
+            } else {
+                d = half_width;
+                e = d;
+            }
+
+            a = b;
+            fa = fb;
+
+            b += if d.abs() > tolerance {
+                d
+            } else {
+                tolerance.copysign(half_width)
+            };
+
+            fb = f(b);
+            iterations += 1;
+        };
+
+        Root {
+            estimate: b,
+            bracket: if reason == StopReason::Exact {
+                Bracket::new(b, b)
+            } else {
+                Bracket::new(b, c)
+            },
+            iterations,
+            reason,
+        }
+    }
+
+    // The reason to stop when the bracket is narrow enough, or None if it can still be narrowed.
+    fn converged(&self, bracket: &Bracket) -> Option<StopReason> {
+        if self.tolerance.is_satisfied(bracket) {
+            Some(StopReason::Tolerance)
+        } else if bracket.is_exhausted() {
+            Some(StopReason::Precision)
+        } else {
+            None
+        }
+    }
+}
+
+// Checks that the endpoints of the bracket have a different sign, and returns the root if either
+// endpoint is a root itself.
+pub(crate) fn exact_endpoint(
+    bracket: Bracket,
+    f_left: f64,
+    f_right: f64,
+) -> Result<Option<Root>, NotBracketedError> {
+    let exact = |x: f64| Root {
+        estimate: x,
+        bracket: Bracket::new(x, x),
+        iterations: 0,
+        reason: StopReason::Exact,
+    };
+
+    if f_left == 0.0 {
+        Ok(Some(exact(bracket.left)))
+    } else if f_right == 0.0 {
+        Ok(Some(exact(bracket.right)))
+    } else if (f_left < 0.0) != (f_right < 0.0) && !f_left.is_nan() && !f_right.is_nan() {
+        Ok(None)
+    } else {
+        Err(NotBracketedError {
+            left: f_left,
+            right: f_right,
+        })
+    }
+}
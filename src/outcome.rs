@@ -0,0 +1,299 @@
+use crate::{
+    Bisectable, Bisector, Candidate, ConvergeTo, EmptySliceError, Indices, Step, TryRunError,
+    ValidationError,
+};
+use std::convert::Infallible;
+
+/// The outcome of a bisection which was run to completion, as produced by [`Bisector::run`] and
+/// [`Bisector::try_run`].
+///
+/// The [`Bisector`] converges to the index of the first element for which the convergence function
+/// produces a [`ConvergeTo::Left`], assuming that all elements before it produce a
+/// [`ConvergeTo::Right`]. This index is called the boundary.
+///
+/// Besides the boundary, the outcome holds the outputs of the most recent convergence function
+/// calls which converged to the left and to the right. Since every step narrows the view, these
+/// are the outputs of the probed elements closest to the boundary: `left` is the output of the
+/// nearest element at or after the boundary, and `right` is the output of the nearest element
+/// before it. They're `None` if no probed element converged to the respective side.
+///
+/// [`Bisector`]: crate::Bisector
+/// [`Bisector::run`]: crate::Bisector::run
+/// [`Bisector::try_run`]: crate::Bisector::try_run
+/// [`ConvergeTo::Left`]: crate::ConvergeTo::Left
+/// [`ConvergeTo::Right`]: crate::ConvergeTo::Right
+pub struct Outcome<'v, T, L, R> {
+    /// The index of the boundary element in the view.
+    pub index: usize,
+    /// The boundary element, as produced by the source of the [`Bisector`].
+    ///
+    /// [`Bisector`]: crate::Bisector
+    pub element: Candidate<'v, T>,
+    /// The output of the most recent convergence function call which converged to the left.
+    pub left: Option<L>,
+    /// The output of the most recent convergence function call which converged to the right.
+    pub right: Option<R>,
+    /// The number of times the convergence function was called.
+    pub probes: usize,
+}
+
+impl<'v, T, L, R> Outcome<'v, T, L, R> {
+    fn new<S>(bisector: &Bisector<'v, T, S>, indices: Indices) -> Self
+    where
+        S: Bisectable<Item = T> + ?Sized,
+    {
+        Self {
+            index: indices.left,
+            element: bisector.source().get(indices.left),
+            left: None,
+            right: None,
+            probes: 0,
+        }
+    }
+
+    // Updates the outcome with the given step, which must be a step following the steps previously
+    // recorded by this outcome.
+    fn record<S>(&mut self, bisector: &Bisector<'v, T, S>, step: Step<L, R>)
+    where
+        S: Bisectable<Item = T> + ?Sized,
+    {
+        // Only the final boundary element is needed, so on-demand candidates aren't produced for
+        // every step
+        if self.index != step.indices.left {
+            self.index = step.indices.left;
+            self.element = bisector.source().get(step.indices.left);
+        }
+
+        if let Some(result) = step.result {
+            self.probes += 1;
+
+            match result {
+                ConvergeTo::Left(out) => self.left = Some(out),
+                ConvergeTo::Right(out) => self.right = Some(out),
+            }
+        }
+    }
+}
+
+impl<'v, T, S> Bisector<'v, T, S>
+where
+    S: Bisectable<Item = T> + ?Sized,
+{
+    /// Run the bisection method to completion, starting from the complete view.
+    ///
+    /// Returns the [`Outcome`] of the bisection, i.e. the boundary index and element, the most recent
+    /// outputs of the convergence function `f` for each side, and the number of times `f` was called.
+    ///
+    /// The view must not be empty. If it is, an [`EmptySliceError`] `Err` result will be returned.
+    ///
+    /// ```
+    /// use bisector::{Bisector, ConvergeTo};
+    ///
+    /// let values = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    /// let bisector = Bisector::new(&values);
+    ///
+    /// let outcome = bisector
+    ///     .run(|&value| {
+    ///         if value >= 7 {
+    ///             ConvergeTo::Left(value)
+    ///         } else {
+    ///             ConvergeTo::Right(value)
+    ///         }
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(outcome.index, 6);
+    /// assert_eq!(*outcome.element, 7);
+    /// assert_eq!(outcome.left, Some(7));
+    /// assert_eq!(outcome.right, Some(6));
+    /// assert_eq!(outcome.probes, 4);
+    /// ```
+    ///
+    /// See also:
+    /// * [`Bisector::try_run`]: A variant of [`run`] which can be used when the convergence function
+    ///   is fallible.
+    ///
+    /// [`Outcome`]: crate::Outcome
+    /// [`EmptySliceError`]: crate::EmptySliceError
+    /// [`Bisector::try_run`]: crate::Bisector::try_run
+    /// [`run`]: crate::Bisector::run
+    pub fn run<F, L, R>(&self, f: F) -> Result<Outcome<'v, T, L, R>, EmptySliceError>
+    where
+        F: FnMut(&T) -> ConvergeTo<L, R>,
+    {
+        let iter = self.iter(f);
+        let mut outcome = Outcome::new(self, iter.indices().ok_or(EmptySliceError)?);
+
+        for step in iter {
+            outcome.record(self, step);
+        }
+
+        Ok(outcome)
+    }
+
+    /// This method can be used when the convergence function is fallible.
+    /// Otherwise exactly the same as [`Bisector::run`].
+    ///
+    /// Returns a [`TryRunError::EmptySlice`] error if the view is empty, and a
+    /// [`TryRunError::Convergence`] error if the convergence function failed.
+    ///
+    /// [`Bisector::run`]: crate::Bisector::run
+    /// [`TryRunError::EmptySlice`]: crate::TryRunError::EmptySlice
+    /// [`TryRunError::Convergence`]: crate::TryRunError::Convergence
+    pub fn try_run<F, E, L, R>(&self, f: F) -> Result<Outcome<'v, T, L, R>, TryRunError<E>>
+    where
+        F: FnMut(&T) -> Result<ConvergeTo<L, R>, E>,
+    {
+        let iter = self.try_iter(f);
+        let indices = iter
+            .indices()
+            .ok_or(TryRunError::EmptySlice(EmptySliceError))?;
+        let mut outcome = Outcome::new(self, indices);
+
+        for step in iter {
+            outcome.record(self, step.map_err(TryRunError::Convergence)?);
+        }
+
+        Ok(outcome)
+    }
+
+    /// Like [`Bisector::run`], but validates the endpoints of the view before bisecting.
+    ///
+    /// The [`Bisector`] assumes that the first element of the view converges to the right, and the
+    /// last element converges to the left, but never checks this. When the convergence function is
+    /// inverted by mistake (e.g. "good" and "bad" are swapped), the bisection still converges, but
+    /// to a meaningless boundary.
+    ///
+    /// This method first calls the convergence function `f` for the first and the last element,
+    /// and returns a [`ValidationError`] if they converge to the same side, or the wrong way round.
+    /// Otherwise, the elements in between are bisected. The two endpoint evaluations count toward
+    /// the `probes` of the [`Outcome`], and are its `left` and `right` outputs, until an element
+    /// closer to the boundary has been probed.
+    ///
+    /// The view must contain at least two elements, so it has two distinct endpoints.
+    ///
+    /// ```
+    /// use bisector::{Bisector, ConvergeTo, ValidationError};
+    ///
+    /// let values = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    /// let bisector = Bisector::new(&values);
+    ///
+    /// let outcome = bisector
+    ///     .run_validated(|&value| {
+    ///         if value >= 7 {
+    ///             ConvergeTo::Left(value)
+    ///         } else {
+    ///             ConvergeTo::Right(value)
+    ///         }
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(*outcome.element, 7);
+    /// assert_eq!(outcome.probes, 5);
+    ///
+    /// // Swapped "good" and "bad"
+    /// let inverted = bisector.run_validated(|&value| {
+    ///     if value < 7 {
+    ///         ConvergeTo::Left(value)
+    ///     } else {
+    ///         ConvergeTo::Right(value)
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(
+    ///     inverted.err(),
+    ///     Some(ValidationError::Inverted { first: 1, last: 10 })
+    /// );
+    /// ```
+    ///
+    /// See also:
+    /// * [`Bisector::try_run_validated`]: A variant of [`run_validated`] which can be used when the
+    ///   convergence function is fallible.
+    ///
+    /// [`Bisector::run`]: crate::Bisector::run
+    /// [`Bisector`]: crate::Bisector
+    /// [`ValidationError`]: crate::ValidationError
+    /// [`Outcome`]: crate::Outcome
+    /// [`Bisector::try_run_validated`]: crate::Bisector::try_run_validated
+    /// [`run_validated`]: crate::Bisector::run_validated
+    pub fn run_validated<F, L, R>(
+        &self,
+        mut f: F,
+    ) -> Result<Outcome<'v, T, L, R>, ValidationError<L, R>>
+    where
+        F: FnMut(&T) -> ConvergeTo<L, R>,
+    {
+        self.validate_and_run(|value| Ok::<_, Infallible>(f(value)))
+    }
+
+    /// This method can be used when the convergence function is fallible.
+    /// Otherwise exactly the same as [`Bisector::run_validated`].
+    ///
+    /// Returns a [`ValidationError::Convergence`] error if the convergence function failed.
+    ///
+    /// [`Bisector::run_validated`]: crate::Bisector::run_validated
+    /// [`ValidationError::Convergence`]: crate::ValidationError::Convergence
+    pub fn try_run_validated<F, E, L, R>(
+        &self,
+        f: F,
+    ) -> Result<Outcome<'v, T, L, R>, ValidationError<L, R, E>>
+    where
+        F: FnMut(&T) -> Result<ConvergeTo<L, R>, E>,
+    {
+        self.validate_and_run(f)
+    }
+
+    fn validate_and_run<F, E, L, R>(
+        &self,
+        mut f: F,
+    ) -> Result<Outcome<'v, T, L, R>, ValidationError<L, R, E>>
+    where
+        F: FnMut(&T) -> Result<ConvergeTo<L, R>, E>,
+    {
+        let source = self.source();
+
+        if source.len() < 2 {
+            return Err(ValidationError::TooShort);
+        }
+
+        let last = source.len() - 1;
+        let first_result = f(&source.get(0)).map_err(ValidationError::Convergence)?;
+        let last_result = f(&source.get(last)).map_err(ValidationError::Convergence)?;
+
+        // The first element is known to converge to the right, so it's not the boundary
+        let mut indices = Indices::new(1, last);
+        let mut outcome = Outcome::new(self, indices);
+
+        match (first_result, last_result) {
+            (ConvergeTo::Right(first), ConvergeTo::Left(last)) => {
+                outcome.right = Some(first);
+                outcome.left = Some(last);
+                outcome.probes = 2;
+            }
+            (ConvergeTo::Left(first), ConvergeTo::Left(last)) => {
+                return Err(ValidationError::BothLeft { first, last })
+            }
+            (ConvergeTo::Right(first), ConvergeTo::Right(last)) => {
+                return Err(ValidationError::BothRight { first, last })
+            }
+            (ConvergeTo::Left(first), ConvergeTo::Right(last)) => {
+                return Err(ValidationError::Inverted { first, last })
+            }
+        }
+
+        loop {
+            let step = self
+                .try_bisect(|value| f(value), indices)
+                .map_err(ValidationError::Convergence)?;
+
+            if step.result.is_none() {
+                break;
+            }
+
+            indices = step.indices;
+            outcome.record(self, step);
+        }
+
+        Ok(outcome)
+    }
+}
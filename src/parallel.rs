@@ -0,0 +1,234 @@
+use crate::{Bisectable, Bisector, ConvergeTo, Indices, Probe};
+
+/// The output of one multisection step, as produced by [`Bisector::multisect`] and
+/// [`Bisector::try_multisect`].
+///
+/// The output consists of two parts:
+/// * `indices`: The indices of the converged view
+/// * `probes`: The output of the convergence function for each split point, ordered by index
+///
+/// Like the `result` of a [`Step`], the `probes` are empty when, for the given input indices, the
+/// bisector was already in a converged state.
+///
+/// [`Bisector::multisect`]: crate::Bisector::multisect
+/// [`Bisector::try_multisect`]: crate::Bisector::try_multisect
+/// [`Step`]: crate::Step
+pub struct MultiStep<L, R> {
+    pub indices: Indices,
+    pub probes: Vec<Probe<L, R>>,
+}
+
+impl<'v, T, S> Bisector<'v, T, S>
+where
+    S: Bisectable<Item = T> + Sync + ?Sized,
+{
+    /// Parallel variant of [`Bisector::bisect`], which splits the view into `k + 1` parts, instead
+    /// of two halves.
+    ///
+    /// The convergence function `f` is evaluated concurrently for each of the `k` split points,
+    /// after which the view is narrowed to the single part in which the convergence function
+    /// switches from converging to the right, to converging to the left. Each step thus reduces the
+    /// view by a factor of `k + 1`, instead of a factor of two, so the bisection requires roughly
+    /// `log2(k + 1)` times fewer steps. When `k` is `1`, the step is equivalent to
+    /// [`Bisector::bisect`].
+    ///
+    /// If the current view contains fewer than `k + 1` elements, fewer split points are evaluated,
+    /// so `k` is effectively clamped to the size of the view.
+    ///
+    /// By default, the split points are evaluated on scoped std threads, of which at most
+    /// [`std::thread::available_parallelism`] are spawned per step; when there are more split
+    /// points than threads, each thread evaluates several split points in turn. When the `rayon`
+    /// feature is enabled, the split points are evaluated on the rayon thread pool instead.
+    ///
+    /// ```
+    /// use bisector::{Bisector, ConvergeTo, Indices};
+    ///
+    /// let values = (0..100).collect::<Vec<u32>>();
+    /// let bisector = Bisector::new(&values);
+    ///
+    /// let f = |&value: &u32| {
+    ///     if value >= 42 {
+    ///         ConvergeTo::Left(value)
+    ///     } else {
+    ///         ConvergeTo::<u32, u32>::Right(value)
+    ///     }
+    /// };
+    ///
+    /// let mut indices = Indices::from_bisector(&bisector);
+    /// let mut steps = 0;
+    ///
+    /// loop {
+    ///     let step = bisector.multisect(3, f, indices);
+    ///
+    ///     if step.probes.is_empty() {
+    ///         break;
+    ///     }
+    ///
+    ///     indices = step.indices;
+    ///     steps += 1;
+    /// }
+    ///
+    /// assert_eq!(indices, Indices::new(42, 42));
+    /// assert_eq!(steps, 4);
+    /// ```
+    ///
+    /// See also:
+    /// * [`Bisector::try_multisect`]: A variant of [`multisect`] which can be used when the
+    ///   convergence function is fallible.
+    ///
+    /// [`Bisector::bisect`]: crate::Bisector::bisect
+    /// [`Bisector::try_multisect`]: crate::Bisector::try_multisect
+    /// [`multisect`]: crate::Bisector::multisect
+    pub fn multisect<F, L, R>(&self, k: usize, f: F, indices: Indices) -> MultiStep<L, R>
+    where
+        F: Fn(&T) -> ConvergeTo<L, R> + Sync,
+        L: Send,
+        R: Send,
+    {
+        let results = self.evaluate_split_points(k, |value| f(value), indices);
+        let probes = results
+            .into_iter()
+            .map(|(index, result)| Probe { index, result });
+
+        narrow(indices, probes.collect())
+    }
+
+    /// This method can be used when the convergence function is fallible.
+    /// Otherwise exactly the same as [`Bisector::multisect`].
+    ///
+    /// All split points are evaluated, even when the convergence function fails for one of them. If
+    /// the convergence function failed for more than one split point, the error of the split point
+    /// with the lowest index is returned.
+    ///
+    /// [`Bisector::multisect`]: crate::Bisector::multisect
+    pub fn try_multisect<F, E, L, R>(
+        &self,
+        k: usize,
+        f: F,
+        indices: Indices,
+    ) -> Result<MultiStep<L, R>, E>
+    where
+        F: Fn(&T) -> Result<ConvergeTo<L, R>, E> + Sync,
+        E: Send,
+        L: Send,
+        R: Send,
+    {
+        let results = self.evaluate_split_points(k, |value| f(value), indices);
+        let probes = results
+            .into_iter()
+            .map(|(index, result)| result.map(|result| Probe { index, result }))
+            .collect::<Result<Vec<_>, E>>()?;
+
+        Ok(narrow(indices, probes))
+    }
+
+    // Evaluates the given function concurrently for each split point, and returns the outputs
+    // ordered by index.
+    fn evaluate_split_points<F, O>(&self, k: usize, f: F, indices: Indices) -> Vec<(usize, O)>
+    where
+        F: Fn(&T) -> O + Sync,
+        O: Send,
+    {
+        let points = split_points(k, indices);
+        let source = self.source();
+
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+
+            points
+                .into_par_iter()
+                .map(|index| (index, f(&source.get(index))))
+                .collect()
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        {
+            use std::sync::atomic::{AtomicUsize, Ordering};
+
+            let f = &f;
+            let points = points.as_slice();
+
+            // Each thread takes the next split point which hasn't been taken yet, so no more
+            // threads than the available parallelism are spawned, however large `k` is
+            let next = &AtomicUsize::new(0);
+            let threads = std::thread::available_parallelism()
+                .map_or(1, std::num::NonZeroUsize::get)
+                .min(points.len());
+
+            std::thread::scope(|scope| {
+                let handles = (0..threads)
+                    .map(|_| {
+                        scope.spawn(move || {
+                            let mut outputs = Vec::new();
+
+                            while let Some(&index) =
+                                points.get(next.fetch_add(1, Ordering::Relaxed))
+                            {
+                                outputs.push((index, f(&source.get(index))));
+                            }
+
+                            outputs
+                        })
+                    })
+                    .collect::<Vec<_>>();
+
+                let mut outputs = handles
+                    .into_iter()
+                    .flat_map(|handle| match handle.join() {
+                        Ok(outputs) => outputs,
+                        Err(panic) => std::panic::resume_unwind(panic),
+                    })
+                    .collect::<Vec<_>>();
+
+                outputs.sort_unstable_by_key(|(index, _)| *index);
+                outputs
+            })
+        }
+    }
+}
+
+// Computes the split points which divide the view into k + 1 parts of (roughly) equal size.
+//
+// Like Bisector::bisect, the right index itself is never a split point, as it can't narrow the view
+// when it converges to the right. When k is 1, the only split point is Indices::middle.
+fn split_points(k: usize, indices: Indices) -> Vec<usize> {
+    let Indices { left, right } = indices;
+
+    if left == right {
+        return Vec::new();
+    }
+
+    // With more parts than elements, some split points would coincide, so k is clamped to the
+    // number of elements which can be split points
+    let width = (right - left) as u128;
+    let parts = (k.max(1) as u128).min(width) + 1;
+
+    let mut points = (1..parts)
+        .map(|j| left + (width * j / parts) as usize)
+        .collect::<Vec<_>>();
+    points.dedup();
+
+    points
+}
+
+// Narrows the view to the part between the last split point which converged to the right, and the
+// first split point which converged to the left. The probes must be ordered by index.
+fn narrow<L, R>(indices: Indices, probes: Vec<Probe<L, R>>) -> MultiStep<L, R> {
+    let mut narrowed = indices;
+
+    for probe in probes.iter() {
+        match probe.result {
+            ConvergeTo::Left(_) => {
+                narrowed.right = probe.index;
+                break;
+            }
+            ConvergeTo::Right(_) => narrowed.left = probe.index + 1,
+        }
+    }
+
+    MultiStep {
+        indices: narrowed,
+        probes,
+    }
+}
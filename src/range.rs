@@ -0,0 +1,285 @@
+use crate::{Bisectable, Bisector, Candidate, ConvergeTo, Indices, InvalidRangeError, Step};
+use std::convert::{Infallible, TryFrom};
+use std::ops::{Bound, Range, RangeBounds, RangeInclusive};
+
+mod private {
+    pub trait Sealed: Copy + Ord {
+        const MIN: Self;
+        const MAX: Self;
+
+        fn checked_next(self) -> Option<Self>;
+
+        fn checked_prev(self) -> Option<Self>;
+
+        // The distance from `start` to `self`, if it fits a usize. Requires `start <= self`.
+        fn offset_from(self, start: Self) -> Option<usize>;
+
+        // The value `offset` steps after `self`. Requires the value to be in range of the type.
+        fn add_offset(self, offset: usize) -> Self;
+    }
+}
+
+/// The integer types over which a [`RangeBisector`] can bisect.
+///
+/// This trait is sealed, and implemented for all primitive integer types.
+///
+/// [`RangeBisector`]: crate::RangeBisector
+pub trait Integer: private::Sealed {}
+
+macro_rules! impl_integer {
+    ($($int:ty => $unsigned:ty),* $(,)?) => {
+        $(
+            impl private::Sealed for $int {
+                const MIN: Self = <$int>::MIN;
+                const MAX: Self = <$int>::MAX;
+
+                fn checked_next(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn checked_prev(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn offset_from(self, start: Self) -> Option<usize> {
+                    usize::try_from((self as $unsigned).wrapping_sub(start as $unsigned)).ok()
+                }
+
+                fn add_offset(self, offset: usize) -> Self {
+                    (self as $unsigned).wrapping_add(offset as $unsigned) as $int
+                }
+            }
+
+            impl Integer for $int {}
+        )*
+    };
+}
+
+impl_integer!(
+    u8 => u8,
+    u16 => u16,
+    u32 => u32,
+    u64 => u64,
+    u128 => u128,
+    usize => usize,
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128,
+    isize => usize,
+);
+
+/// Stateless implementation of the bisection method over a range of integers.
+///
+/// Like the [`Bisector`], but instead of a slice, the view is a range of integers, such as
+/// `0..u64::MAX`. The values in the range are computed on demand, so bisecting over a large range
+/// doesn't require allocating a slice which holds every value.
+///
+/// The [`Indices`] used by the `RangeBisector` are offsets from the start of the range, and the
+/// convergence function is given the value at the middle offset.
+///
+/// Each step is taken by a [`Bisector`] over the range, as created by [`Bisector::from_source`].
+/// Unlike such a [`Bisector`], the `RangeBisector` accepts any range bounds, such as `1..` or
+/// `..=u64::MAX`, as long as the offset of the last value from the first value fits in a `usize`,
+/// even if the number of values doesn't.
+///
+/// [`Bisector`]: crate::Bisector
+/// [`Bisector::from_source`]: crate::Bisector::from_source
+/// [`Indices`]: crate::Indices
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct RangeBisector<I> {
+    start: I,
+    last: usize,
+}
+
+impl<I: Integer> RangeBisector<I> {
+    /// Create a new [`RangeBisector`] for a given range.
+    ///
+    /// The range must not be empty, and the offset of its last value from its start must fit in a
+    /// `usize`. Otherwise, an [`InvalidRangeError`] `Err` result will be returned.
+    ///
+    /// [`RangeBisector`]: crate::RangeBisector
+    /// [`InvalidRangeError`]: crate::InvalidRangeError
+    pub fn new<B: RangeBounds<I>>(range: B) -> Result<Self, InvalidRangeError> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => Some(start),
+            Bound::Excluded(&start) => start.checked_next(),
+            Bound::Unbounded => Some(I::MIN),
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(&end) => Some(end),
+            Bound::Excluded(&end) => end.checked_prev(),
+            Bound::Unbounded => Some(I::MAX),
+        };
+
+        match (start, end) {
+            (Some(start), Some(end)) if start <= end => end
+                .offset_from(start)
+                .map(|last| Self { start, last })
+                .ok_or(InvalidRangeError::TooLarge),
+            _ => Err(InvalidRangeError::Empty),
+        }
+    }
+
+    /// The first value of the range.
+    pub fn first(&self) -> I {
+        self.start
+    }
+
+    /// The last value of the range.
+    pub fn last(&self) -> I {
+        self.start.add_offset(self.last)
+    }
+
+    /// The value at the given offset from the start of the range.
+    ///
+    /// The offset must not be greater than the offset of the last value, i.e. it must be a valid
+    /// index for the view of this bisector.
+    pub fn value(&self, offset: usize) -> I {
+        debug_assert!(
+            offset <= self.last,
+            "offset ({}) > last offset ({}), but expected offset <= last offset",
+            offset,
+            self.last,
+        );
+
+        self.start.add_offset(offset)
+    }
+
+    /// Stateless implementation of the bisection method over a range of integers.
+    ///
+    /// Exactly the same as [`Bisector::bisect`], except that the convergence function `f` is given
+    /// the value at the middle offset, and the `indices` are offsets from the start of the range.
+    ///
+    /// ```
+    /// use bisector::{ConvergeTo, Indices, RangeBisector};
+    ///
+    /// let bisector = RangeBisector::new(0..u64::MAX).unwrap();
+    /// let mut indices = Indices::from_range_bisector(&bisector);
+    ///
+    /// loop {
+    ///     let step = bisector.bisect(
+    ///         |fuel| {
+    ///             if fuel >= 1_234_567_890 {
+    ///                 ConvergeTo::Left(())
+    ///             } else {
+    ///                 ConvergeTo::Right(())
+    ///             }
+    ///         },
+    ///         indices,
+    ///     );
+    ///
+    ///     if step.result.is_none() {
+    ///         break;
+    ///     }
+    ///
+    ///     indices = step.indices;
+    /// }
+    ///
+    /// assert_eq!(bisector.value(indices.left), 1_234_567_890);
+    /// ```
+    ///
+    /// See also:
+    /// * [`RangeBisector::try_bisect`]: A variant of [`bisect`] which can be used when the
+    ///   convergence function is fallible.
+    ///
+    /// [`Bisector::bisect`]: crate::Bisector::bisect
+    /// [`RangeBisector::try_bisect`]: crate::RangeBisector::try_bisect
+    /// [`bisect`]: crate::RangeBisector::bisect
+    pub fn bisect<F, L, R>(&self, f: F, indices: Indices) -> Step<L, R>
+    where
+        F: FnOnce(I) -> ConvergeTo<L, R>,
+    {
+        self.try_bisect(|value| Ok::<_, Infallible>(f(value)), indices)
+            .unwrap_or_else(|infallible| match infallible {})
+    }
+
+    /// This method can be used when the convergence function is fallible.
+    /// Otherwise exactly the same as [`RangeBisector::bisect`].
+    ///
+    /// [`RangeBisector::bisect`]: crate::RangeBisector::bisect
+    pub fn try_bisect<F, E, L, R>(&self, f: F, indices: Indices) -> Result<Step<L, R>, E>
+    where
+        F: FnOnce(I) -> Result<ConvergeTo<L, R>, E>,
+    {
+        Bisector::from_source(&self.values()).try_bisect(|&value| f(value), indices)
+    }
+
+    // The values of the range, as a source for a Bisector. Its number of values may not fit in a
+    // usize, but bisecting only requires the offset of its last value to fit.
+    pub(crate) fn values(&self) -> RangeInclusive<I> {
+        self.first()..=self.last()
+    }
+}
+
+impl Indices {
+    /// Re-use the range of the [`RangeBisector`] to determine the starting indices.
+    ///
+    /// The returned indices will be the complete range, i.e. from offset `0` to the offset of the
+    /// last value of the range. Since a [`RangeBisector`] can't be empty, this can't fail.
+    ///
+    /// [`RangeBisector`]: crate::RangeBisector
+    pub fn from_range_bisector<I>(bisector: &RangeBisector<I>) -> Self {
+        Self {
+            left: 0,
+            right: bisector.last,
+        }
+    }
+}
+
+// The number of values in the range from `start` to `end` (inclusive), or `0` if `start > end`.
+fn len_inclusive<I: Integer>(start: I, end: I) -> usize {
+    if start > end {
+        return 0;
+    }
+
+    end.offset_from(start)
+        .and_then(|last| last.checked_add(1))
+        .expect("the number of values in the range must fit in a usize")
+}
+
+/// The number of values in the range must fit in a `usize`, otherwise [`Bisectable::len`] panics.
+///
+/// [`Bisectable::len`]: crate::Bisectable::len
+impl<I: Integer> Bisectable for Range<I> {
+    type Item = I;
+
+    fn len(&self) -> usize {
+        match self.end.checked_prev() {
+            Some(end) => len_inclusive(self.start, end),
+            None => 0,
+        }
+    }
+
+    fn get(&self, index: usize) -> Candidate<'_, I> {
+        assert!(index < self.len(), "index out of bounds of the range");
+
+        Candidate::Owned(self.start.add_offset(index))
+    }
+}
+
+/// The number of values in the range must fit in a `usize`, otherwise [`Bisectable::len`] panics.
+///
+/// [`Bisectable::len`]: crate::Bisectable::len
+impl<I: Integer> Bisectable for RangeInclusive<I> {
+    type Item = I;
+
+    fn len(&self) -> usize {
+        len_inclusive(*self.start(), *self.end())
+    }
+
+    fn get(&self, index: usize) -> Candidate<'_, I> {
+        // Unlike the length, the offset of the last value may fit in a usize, e.g. for `0..=u64::MAX`
+        let in_bounds = self.start() <= self.end()
+            && match self.end().offset_from(*self.start()) {
+                Some(last) => index <= last,
+                None => true,
+            };
+
+        assert!(in_bounds, "index out of bounds of the range");
+
+        Candidate::Owned(self.start().add_offset(index))
+    }
+}
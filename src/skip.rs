@@ -0,0 +1,402 @@
+use crate::{Bisectable, Bisector, Candidate, ConvergeTo, EmptySliceError, Indices};
+use std::collections::BTreeSet;
+use std::iter::FusedIterator;
+
+/// The verdict of a convergence function which may not be able to test every candidate.
+///
+/// Like [`ConvergeTo`], but with an additional [`Verdict::Skip`] variant, for candidates which can't
+/// be tested at all, for example because the candidate fails to build. This is the equivalent of
+/// `git bisect skip`, or exit code 125 for `git bisect run`.
+///
+/// [`ConvergeTo`]: crate::ConvergeTo
+/// [`Verdict::Skip`]: crate::Verdict::Skip
+pub enum Verdict<Left, Right, Skip> {
+    Left(Left),
+    Right(Right),
+    Skip(Skip),
+}
+
+impl<Left, Right, Skip> Verdict<Left, Right, Skip> {
+    pub fn try_into_left(self) -> Option<Left> {
+        if let Self::Left(left) = self {
+            Some(left)
+        } else {
+            None
+        }
+    }
+
+    pub fn try_into_right(self) -> Option<Right> {
+        if let Self::Right(right) = self {
+            Some(right)
+        } else {
+            None
+        }
+    }
+
+    pub fn try_into_skip(self) -> Option<Skip> {
+        if let Self::Skip(skip) = self {
+            Some(skip)
+        } else {
+            None
+        }
+    }
+}
+
+impl<Left, Right, Skip> From<ConvergeTo<Left, Right>> for Verdict<Left, Right, Skip> {
+    fn from(converge_to: ConvergeTo<Left, Right>) -> Self {
+        match converge_to {
+            ConvergeTo::Left(left) => Self::Left(left),
+            ConvergeTo::Right(right) => Self::Right(right),
+        }
+    }
+}
+
+/// The strategy used to pick a different candidate to probe, when the middle of the current view
+/// has been skipped.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SkipStrategy {
+    /// Probe the candidate closest to the middle which hasn't been skipped. When two candidates are
+    /// equally close, the one with the lower index is probed.
+    NearestNeighbour,
+    /// Like [`SkipStrategy::NearestNeighbour`], but the preferred direction alternates each time
+    /// the middle has been skipped, so skipped regions are approached from both sides.
+    ///
+    /// [`SkipStrategy::NearestNeighbour`]: crate::SkipStrategy::NearestNeighbour
+    Alternating,
+    /// Probe a pseudo-random candidate within the current view. If the randomly picked candidate
+    /// has been skipped, its nearest neighbour which hasn't been skipped is probed instead.
+    ///
+    /// The value is the seed of the pseudo-random number generator, so the order of probes is
+    /// reproducible.
+    Random(u64),
+}
+
+/// The output of one bisection step which may skip candidates.
+///
+/// The output consists of three parts:
+/// * `indices`: The indices of the converged view
+/// * `probe`: The index of the candidate which was given to the convergence function
+/// * `result`: The output of the convergence function
+///
+/// When the `result` is a [`Verdict::Skip`], the `indices` are the same as the indices before the
+/// step, as nothing was learned about the probed candidate.
+///
+/// [`Verdict::Skip`]: crate::Verdict::Skip
+pub struct SkipStep<L, R, S> {
+    pub indices: Indices,
+    pub probe: usize,
+    pub result: Verdict<L, R, S>,
+}
+
+/// An iterator over the steps of a bisection which may skip candidates, created by
+/// [`Bisector::iter_skipping`].
+///
+/// The iterator is exhausted when either the bisection has converged, or every candidate which
+/// could still make progress has been skipped. In the latter case, the boundary is known to lie
+/// within [`SkipIter::indices`], but it can't be determined which candidate it is.
+///
+/// [`Bisector::iter_skipping`]: crate::Bisector::iter_skipping
+/// [`SkipIter::indices`]: crate::SkipIter::indices
+pub struct SkipIter<'b, 'v, T, F, Src: ?Sized = [T]> {
+    bisector: &'b Bisector<'v, T, Src>,
+    f: F,
+    indices: Option<Indices>,
+    skipped: BTreeSet<usize>,
+    strategy: SkipStrategy,
+    prefer_right: bool,
+    rng: u64,
+}
+
+impl<'b, 'v, T, F, Src> SkipIter<'b, 'v, T, F, Src>
+where
+    Src: Bisectable<Item = T> + ?Sized,
+{
+    fn new(bisector: &'b Bisector<'v, T, Src>, strategy: SkipStrategy, f: F) -> Self {
+        let rng = match strategy {
+            SkipStrategy::Random(seed) => seed,
+            _ => 0,
+        };
+
+        Self {
+            bisector,
+            f,
+            indices: Indices::try_from_source(bisector).ok(),
+            skipped: BTreeSet::new(),
+            strategy,
+            prefer_right: false,
+            rng,
+        }
+    }
+
+    /// The indices from which the next step will be taken.
+    ///
+    /// Returns `None` if the view of the [`Bisector`] is empty.
+    ///
+    /// [`Bisector`]: crate::Bisector
+    pub fn indices(&self) -> Option<Indices> {
+        self.indices
+    }
+
+    /// The indices of the candidates which have been skipped so far, in ascending order.
+    pub fn skipped(&self) -> impl Iterator<Item = usize> + '_ {
+        self.skipped.iter().copied()
+    }
+
+    // Picks the next candidate to probe, or None if every candidate which could make progress has
+    // been skipped.
+    //
+    // Like Bisector::bisect, the right index itself is never probed: a candidate in [left, right)
+    // always narrows the view, regardless of the side it converges to.
+    fn next_probe(&mut self, indices: Indices) -> Option<usize> {
+        if indices.left == indices.right {
+            return None;
+        }
+
+        let middle = indices.middle();
+
+        if !self.skipped.contains(&middle) {
+            return Some(middle);
+        }
+
+        let (origin, prefer_right) = match self.strategy {
+            SkipStrategy::NearestNeighbour => (middle, false),
+            SkipStrategy::Alternating => {
+                self.prefer_right = !self.prefer_right;
+                (middle, self.prefer_right)
+            }
+            SkipStrategy::Random(_) => {
+                let width = (indices.right - indices.left) as u64;
+                let offset = (splitmix64(&mut self.rng) % width) as usize;
+                (indices.left + offset, false)
+            }
+        };
+
+        self.nearest_unskipped(origin, indices, prefer_right)
+    }
+
+    // Finds the candidate closest to the origin, within [left, right), which has not been skipped.
+    fn nearest_unskipped(
+        &self,
+        origin: usize,
+        indices: Indices,
+        prefer_right: bool,
+    ) -> Option<usize> {
+        let lower = indices.left;
+        let upper = indices.right - 1;
+
+        for distance in 0..=(upper - lower) {
+            let below = origin.checked_sub(distance).filter(|&i| i >= lower);
+            let above = origin.checked_add(distance).filter(|&i| i <= upper);
+
+            if below.is_none() && above.is_none() {
+                break;
+            }
+
+            let (first, second) = if prefer_right {
+                (above, below)
+            } else {
+                (below, above)
+            };
+
+            for candidate in first.into_iter().chain(second) {
+                if !self.skipped.contains(&candidate) {
+                    return Some(candidate);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl<'b, 'v, T, F, L, R, S, Src> Iterator for SkipIter<'b, 'v, T, F, Src>
+where
+    F: FnMut(&T) -> Verdict<L, R, S>,
+    Src: Bisectable<Item = T> + ?Sized,
+{
+    type Item = SkipStep<L, R, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let indices = self.indices?;
+        let probe = self.next_probe(indices)?;
+
+        let next = match (self.f)(&self.bisector.source().get(probe)) {
+            Verdict::Left(out) => SkipStep {
+                indices: Indices::new(indices.left, probe),
+                probe,
+                result: Verdict::Left(out),
+            },
+            Verdict::Right(out) => SkipStep {
+                indices: Indices::new(probe + 1, indices.right),
+                probe,
+                result: Verdict::Right(out),
+            },
+            Verdict::Skip(out) => {
+                self.skipped.insert(probe);
+
+                SkipStep {
+                    indices,
+                    probe,
+                    result: Verdict::Skip(out),
+                }
+            }
+        };
+
+        self.indices = Some(next.indices);
+
+        Some(next)
+    }
+}
+
+impl<'b, 'v, T, F, L, R, S, Src> FusedIterator for SkipIter<'b, 'v, T, F, Src>
+where
+    F: FnMut(&T) -> Verdict<L, R, S>,
+    Src: Bisectable<Item = T> + ?Sized,
+{
+}
+
+/// The outcome of a bisection which may skip candidates, as produced by [`Bisector::run_skipping`].
+///
+/// When the bisection converged, `candidates` holds a single index (i.e. `left == right`).
+/// Otherwise, skipped candidates made it impossible to pinpoint the boundary, and `candidates`
+/// holds the range of indices in which the boundary lies.
+///
+/// Like [`Outcome`], `left` and `right` hold the outputs of the most recent convergence function
+/// calls which converged to the left and the right respectively.
+///
+/// [`Bisector::run_skipping`]: crate::Bisector::run_skipping
+/// [`Outcome`]: crate::Outcome
+pub struct SkipOutcome<'v, T, L, R> {
+    /// The indices of the range of candidates in which the boundary lies.
+    pub candidates: Indices,
+    /// The elements of the range of candidates in which the boundary lies, as produced by the
+    /// source of the [`Bisector`].
+    ///
+    /// [`Bisector`]: crate::Bisector
+    pub elements: Vec<Candidate<'v, T>>,
+    /// The output of the most recent convergence function call which converged to the left.
+    pub left: Option<L>,
+    /// The output of the most recent convergence function call which converged to the right.
+    pub right: Option<R>,
+    /// The indices of the skipped candidates, in ascending order.
+    pub skipped: Vec<usize>,
+    /// The number of times the convergence function was called, including calls which resulted in
+    /// a skip.
+    pub probes: usize,
+}
+
+impl<'v, T, L, R> SkipOutcome<'v, T, L, R> {
+    /// Whether skipped candidates left the boundary ambiguous, i.e. whether there is more than one
+    /// candidate in which the boundary may lie.
+    pub fn is_ambiguous(&self) -> bool {
+        self.candidates.left != self.candidates.right
+    }
+}
+
+impl<'v, T, Src> Bisector<'v, T, Src>
+where
+    Src: Bisectable<Item = T> + ?Sized,
+{
+    /// Run the bisection method, for a convergence function which may skip candidates.
+    ///
+    /// Unlike [`Bisector::iter`], where the convergence function always receives the middle
+    /// element of the current view, the convergence function `f` may return [`Verdict::Skip`] for
+    /// candidates which can't be tested. The indices are then kept as they are, and a different
+    /// candidate within the current view is probed instead, as picked by the given `strategy`.
+    /// Skipped candidates are never probed again.
+    ///
+    /// Returns an iterator which yields a [`SkipStep`] for each call to the convergence function.
+    ///
+    /// [`Bisector::iter`]: crate::Bisector::iter
+    /// [`Verdict::Skip`]: crate::Verdict::Skip
+    /// [`SkipStep`]: crate::SkipStep
+    pub fn iter_skipping<F, L, R, S>(
+        &self,
+        strategy: SkipStrategy,
+        f: F,
+    ) -> SkipIter<'_, 'v, T, F, Src>
+    where
+        F: FnMut(&T) -> Verdict<L, R, S>,
+    {
+        SkipIter::new(self, strategy, f)
+    }
+
+    /// Run the bisection method to completion, for a convergence function which may skip
+    /// candidates.
+    ///
+    /// See [`Bisector::iter_skipping`] for how skipped candidates are handled. Returns a
+    /// [`SkipOutcome`], which holds the range of candidates in which the boundary lies.
+    ///
+    /// The view must not be empty. If it is, an [`EmptySliceError`] `Err` result will be returned.
+    ///
+    /// ```
+    /// use bisector::{Bisector, SkipStrategy, Verdict};
+    ///
+    /// let values = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    /// let bisector = Bisector::new(&values);
+    ///
+    /// let outcome = bisector
+    ///     .run_skipping(SkipStrategy::NearestNeighbour, |&value| match value {
+    ///         5 | 6 => Verdict::Skip(()),
+    ///         v if v >= 6 => Verdict::Left(v),
+    ///         v => Verdict::Right(v),
+    ///     })
+    ///     .unwrap();
+    ///
+    /// // Since 5 and 6 can't be tested, the first element converging to the left may be 5, 6 or 7
+    /// assert!(outcome.is_ambiguous());
+    /// assert_eq!(outcome.elements, &[5, 6, 7]);
+    /// ```
+    ///
+    /// [`Bisector::iter_skipping`]: crate::Bisector::iter_skipping
+    /// [`SkipOutcome`]: crate::SkipOutcome
+    /// [`EmptySliceError`]: crate::EmptySliceError
+    pub fn run_skipping<F, L, R, S>(
+        &self,
+        strategy: SkipStrategy,
+        f: F,
+    ) -> Result<SkipOutcome<'v, T, L, R>, EmptySliceError>
+    where
+        F: FnMut(&T) -> Verdict<L, R, S>,
+    {
+        let mut iter = self.iter_skipping(strategy, f);
+
+        let mut left = None;
+        let mut right = None;
+        let mut probes = 0;
+
+        for step in iter.by_ref() {
+            probes += 1;
+
+            match step.result {
+                Verdict::Left(out) => left = Some(out),
+                Verdict::Right(out) => right = Some(out),
+                Verdict::Skip(_) => {}
+            }
+        }
+
+        let candidates = iter.indices().ok_or(EmptySliceError)?;
+
+        Ok(SkipOutcome {
+            candidates,
+            elements: (candidates.left..=candidates.right)
+                .map(|index| self.source().get(index))
+                .collect(),
+            left,
+            right,
+            skipped: iter.skipped().collect(),
+            probes,
+        })
+    }
+}
+
+// A small, fast and seedable pseudo-random number generator, which is good enough to spread probes
+// over the current view. See https://prng.di.unimi.it/splitmix64.c
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
@@ -0,0 +1,183 @@
+use std::collections::VecDeque;
+use std::fmt::{Debug, Formatter};
+use std::ops::Deref;
+
+/// A random-access source of candidates, over which a [`Bisector`] can bisect.
+///
+/// Where a slice requires every candidate to exist up front, a `Bisectable` source may also
+/// produce its candidates on demand, e.g. a range of integers, or a closure which computes the
+/// candidate for an index (see [`FnSource`]).
+///
+/// Implementations are provided for slices, `Vec`, `VecDeque`, integer ranges and [`FnSource`].
+///
+/// [`Bisector`]: crate::Bisector
+/// [`FnSource`]: crate::FnSource
+pub trait Bisectable {
+    /// The type of the candidates.
+    type Item;
+
+    /// The number of candidates.
+    fn len(&self) -> usize;
+
+    /// Whether the source holds no candidates.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The candidate at the given index.
+    ///
+    /// The index must be smaller than [`Bisectable::len`]. Implementations may panic otherwise.
+    ///
+    /// [`Bisectable::len`]: crate::Bisectable::len
+    fn get(&self, index: usize) -> Candidate<'_, Self::Item>;
+}
+
+/// A candidate produced by a [`Bisectable`] source. Either borrowed from the source, or produced
+/// on demand.
+///
+/// Dereferences to the candidate itself.
+///
+/// [`Bisectable`]: crate::Bisectable
+pub enum Candidate<'s, T> {
+    Borrowed(&'s T),
+    Owned(T),
+}
+
+impl<'s, T> Deref for Candidate<'s, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match self {
+            Self::Borrowed(candidate) => candidate,
+            Self::Owned(candidate) => candidate,
+        }
+    }
+}
+
+impl<'s, T: PartialEq> PartialEq<T> for Candidate<'s, T> {
+    fn eq(&self, other: &T) -> bool {
+        **self == *other
+    }
+}
+
+impl<'s, T: Debug> Debug for Candidate<'s, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl<T> Bisectable for [T] {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn get(&self, index: usize) -> Candidate<'_, T> {
+        Candidate::Borrowed(&self[index])
+    }
+}
+
+impl<T> Bisectable for Vec<T> {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn get(&self, index: usize) -> Candidate<'_, T> {
+        Candidate::Borrowed(&self[index])
+    }
+}
+
+impl<T> Bisectable for VecDeque<T> {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+
+    fn get(&self, index: usize) -> Candidate<'_, T> {
+        Candidate::Borrowed(&self[index])
+    }
+}
+
+/// A [`Bisectable`] source which produces its candidates on demand, by calling a closure with the
+/// index of the candidate.
+///
+/// ```
+/// use bisector::{Bisector, ConvergeTo, FnSource};
+///
+/// // The nightly toolchains of 2022, by day of the year
+/// let source = FnSource::new(365, |day| format!("nightly-2022-day-{}", day + 1));
+/// let bisector = Bisector::from_source(&source);
+///
+/// let last = bisector
+///     .iter(|toolchain| {
+///         if toolchain.as_str() >= "nightly-2022-day-200" {
+///             ConvergeTo::Left(())
+///         } else {
+///             ConvergeTo::Right(())
+///         }
+///     })
+///     .last()
+///     .unwrap();
+///
+/// assert_eq!(*source.get(last.indices.left), "nightly-2022-day-200");
+/// ```
+///
+/// [`Bisectable`]: crate::Bisectable
+pub struct FnSource<F> {
+    len: usize,
+    f: F,
+}
+
+impl<F> FnSource<F> {
+    /// Create a new source of `len` candidates, where the candidate at index `i` is produced by
+    /// calling `f(i)`.
+    pub fn new(len: usize, f: F) -> Self {
+        Self { len, f }
+    }
+}
+
+impl<F, T> FnSource<F>
+where
+    F: Fn(usize) -> T,
+{
+    /// The candidate at the given index. Shorthand for [`Bisectable::get`].
+    ///
+    /// [`Bisectable::get`]: crate::Bisectable::get
+    pub fn get(&self, index: usize) -> Candidate<'_, T> {
+        Bisectable::get(self, index)
+    }
+}
+
+impl<F> Debug for FnSource<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FnSource")
+            .field("len", &self.len)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F, T> Bisectable for FnSource<F>
+where
+    F: Fn(usize) -> T,
+{
+    type Item = T;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn get(&self, index: usize) -> Candidate<'_, T> {
+        assert!(
+            index < self.len,
+            "index ({}) >= len ({}), but expected index < len",
+            index,
+            self.len,
+        );
+
+        Candidate::Owned((self.f)(index))
+    }
+}
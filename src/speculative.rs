@@ -0,0 +1,278 @@
+use crate::{Bisectable, Bisector, ConvergeTo, EmptySliceError, Indices, Probe, Step, TryRunError};
+use std::convert::Infallible;
+use std::thread::ScopedJoinHandle;
+
+/// The outcome of a speculative bisection, as produced by [`Bisector::run_speculative`] and
+/// [`Bisector::try_run_speculative`].
+///
+/// [`Bisector::run_speculative`]: crate::Bisector::run_speculative
+/// [`Bisector::try_run_speculative`]: crate::Bisector::try_run_speculative
+pub struct Speculation<L, R> {
+    /// The indices to which the bisection converged.
+    pub indices: Indices,
+    /// The steps taken by the bisection, in order. These are exactly the steps which
+    /// [`Bisector::iter`] would have produced.
+    ///
+    /// [`Bisector::iter`]: crate::Bisector::iter
+    pub steps: Vec<Step<L, R>>,
+    /// The speculative evaluations of the convergence function which turned out not to be needed,
+    /// ordered by index. Evaluations which weren't needed, and failed or panicked, are left out.
+    pub discarded: Vec<Probe<L, R>>,
+}
+
+impl<'v, T, S> Bisector<'v, T, S>
+where
+    S: Bisectable<Item = T> + Sync + ?Sized,
+{
+    /// Run the bisection method to completion, while speculatively evaluating the convergence
+    /// function ahead of time.
+    ///
+    /// While the middle element of the current view is being evaluated, the two possible middle
+    /// elements of the next step are already known: the middle of the left half, and the middle of
+    /// the right half. Both are evaluated concurrently with the current middle element, each on
+    /// their own thread. Once the current step is known, the evaluation of the next middle element
+    /// is already underway (or even finished), which hides the latency of slow convergence
+    /// functions.
+    ///
+    /// The evaluation for the half which wasn't needed is discarded. Since std threads can't be
+    /// cancelled, discarded evaluations still run to completion; their outputs are reported as part
+    /// of the [`Speculation`], in [`Speculation::discarded`]. A panic of a discarded evaluation is
+    /// ignored, while a panic of an evaluation which is needed by the bisection is propagated.
+    ///
+    /// At most four speculative evaluations run at the same time, including discarded evaluations
+    /// which haven't finished yet, besides the evaluation of the current middle element. While
+    /// that many are running, for example because discarded evaluations are slow, no new
+    /// speculative evaluations are started, and the next middle element is evaluated once it's
+    /// needed instead.
+    ///
+    /// The view must not be empty. If it is, an [`EmptySliceError`] `Err` result will be returned.
+    ///
+    /// ```
+    /// use bisector::{Bisector, ConvergeTo};
+    ///
+    /// let values = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    /// let bisector = Bisector::new(&values);
+    ///
+    /// let speculation = bisector
+    ///     .run_speculative(|&value| {
+    ///         if value >= 7 {
+    ///             ConvergeTo::Left(value)
+    ///         } else {
+    ///             ConvergeTo::<u32, u32>::Right(value)
+    ///         }
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(values[speculation.indices.left], 7);
+    /// assert_eq!(speculation.steps.len(), 4);
+    /// ```
+    ///
+    /// See also:
+    /// * [`Bisector::try_run_speculative`]: A variant of [`run_speculative`] which can be used when
+    ///   the convergence function is fallible.
+    ///
+    /// [`Speculation`]: crate::Speculation
+    /// [`Speculation::discarded`]: crate::Speculation::discarded
+    /// [`EmptySliceError`]: crate::EmptySliceError
+    /// [`Bisector::try_run_speculative`]: crate::Bisector::try_run_speculative
+    /// [`run_speculative`]: crate::Bisector::run_speculative
+    pub fn run_speculative<F, L, R>(&self, f: F) -> Result<Speculation<L, R>, EmptySliceError>
+    where
+        F: Fn(&T) -> ConvergeTo<L, R> + Sync,
+        L: Send,
+        R: Send,
+    {
+        let indices = Indices::try_from_source(self)?;
+
+        let speculation = self
+            .speculate(|value| Ok::<_, Infallible>(f(value)), indices)
+            .unwrap_or_else(|infallible| match infallible {});
+
+        Ok(speculation)
+    }
+
+    /// This method can be used when the convergence function is fallible.
+    /// Otherwise exactly the same as [`Bisector::run_speculative`].
+    ///
+    /// When the convergence function fails for an element which is needed by the bisection, the
+    /// bisection stops, and the error is returned, once the speculative evaluations which are still
+    /// running have finished. Errors of discarded evaluations are ignored, and such evaluations are
+    /// left out of [`Speculation::discarded`].
+    ///
+    /// [`Bisector::run_speculative`]: crate::Bisector::run_speculative
+    /// [`Speculation::discarded`]: crate::Speculation::discarded
+    pub fn try_run_speculative<F, E, L, R>(&self, f: F) -> Result<Speculation<L, R>, TryRunError<E>>
+    where
+        F: Fn(&T) -> Result<ConvergeTo<L, R>, E> + Sync,
+        E: Send,
+        L: Send,
+        R: Send,
+    {
+        let indices = Indices::try_from_source(self).map_err(TryRunError::EmptySlice)?;
+
+        self.speculate(f, indices).map_err(TryRunError::Convergence)
+    }
+
+    fn speculate<F, E, L, R>(&self, f: F, indices: Indices) -> Result<Speculation<L, R>, E>
+    where
+        F: Fn(&T) -> Result<ConvergeTo<L, R>, E> + Sync,
+        E: Send,
+        L: Send,
+        R: Send,
+    {
+        let source = self.source();
+        let f = &f;
+
+        std::thread::scope(|scope| {
+            let mut pending = Pending::default();
+            let mut steps = Vec::new();
+            let mut indices = indices;
+
+            let evaluate = |index: usize| scope.spawn(move || f(&source.get(index)));
+
+            let result = loop {
+                if indices.left == indices.right {
+                    break Ok(());
+                }
+
+                let middle = indices.middle();
+                let current = pending.take(middle).unwrap_or_else(|| evaluate(middle));
+
+                let halves = [
+                    Indices::new(indices.left, middle),
+                    Indices::new(middle + 1, indices.right),
+                ];
+
+                for half in halves.iter().filter(|half| half.left != half.right) {
+                    pending.start(half.middle(), evaluate);
+                }
+
+                let result = match join(current) {
+                    Ok(result) => result,
+                    Err(err) => break Err(err),
+                };
+
+                let step = self.bisect(move |_| result, indices);
+
+                indices = step.indices;
+                steps.push(step);
+
+                pending.abandon_outside(indices);
+            };
+
+            // Also on error, every speculative evaluation is joined, so a panic of an abandoned
+            // evaluation doesn't propagate when the scope ends
+            let discarded = pending.finish();
+
+            result.map(|()| Speculation {
+                indices,
+                steps,
+                discarded,
+            })
+        })
+    }
+}
+
+// The maximum number of speculative evaluations which may be running at the same time, including
+// abandoned evaluations which haven't finished yet.
+const MAX_SPECULATIONS: usize = 4;
+
+type Handle<'scope, L, R, E> = ScopedJoinHandle<'scope, Result<ConvergeTo<L, R>, E>>;
+
+// The speculative evaluations which have been started, but not yet consumed, ordered by index, and
+// the evaluations which turned out not to be needed.
+struct Pending<'scope, L, R, E> {
+    handles: Vec<(usize, Handle<'scope, L, R, E>)>,
+    // Evaluations of elements outside of the view, which are still running
+    abandoned: Vec<(usize, Handle<'scope, L, R, E>)>,
+    // Outputs of abandoned evaluations which completed successfully
+    discarded: Vec<Probe<L, R>>,
+}
+
+impl<'scope, L, R, E> Default for Pending<'scope, L, R, E> {
+    fn default() -> Self {
+        Self {
+            handles: Vec::new(),
+            abandoned: Vec::new(),
+            discarded: Vec::new(),
+        }
+    }
+}
+
+impl<'scope, L, R, E> Pending<'scope, L, R, E> {
+    fn take(&mut self, index: usize) -> Option<Handle<'scope, L, R, E>> {
+        let position = self.handles.iter().position(|(i, _)| *i == index)?;
+
+        Some(self.handles.remove(position).1)
+    }
+
+    // Starts a speculative evaluation for the given index, unless it has already been started, or
+    // too many speculative evaluations are running.
+    fn start<S>(&mut self, index: usize, evaluate: S)
+    where
+        S: FnOnce(usize) -> Handle<'scope, L, R, E>,
+    {
+        self.reap();
+
+        let running = self.handles.len() + self.abandoned.len();
+
+        if running < MAX_SPECULATIONS && self.handles.iter().all(|(i, _)| *i != index) {
+            let position = self.handles.partition_point(|(i, _)| *i < index);
+            self.handles.insert(position, (index, evaluate(index)));
+        }
+    }
+
+    // Abandons the evaluations of elements outside of the given view, which will never be needed.
+    // The right index itself is never probed, so it's not needed either.
+    fn abandon_outside(&mut self, indices: Indices) {
+        let (needed, abandoned) = std::mem::take(&mut self.handles)
+            .into_iter()
+            .partition(|(index, _)| indices.left <= *index && *index < indices.right);
+
+        self.handles = needed;
+        self.abandoned.extend::<Vec<_>>(abandoned);
+    }
+
+    // Joins the abandoned evaluations which have finished.
+    fn reap(&mut self) {
+        let (finished, running) = std::mem::take(&mut self.abandoned)
+            .into_iter()
+            .partition::<Vec<_>, _>(|(_, handle)| handle.is_finished());
+
+        self.abandoned = running;
+
+        for (index, handle) in finished {
+            self.discard(index, handle);
+        }
+    }
+
+    // Joins every remaining evaluation, and returns the outputs of the evaluations which weren't
+    // needed, ordered by index.
+    fn finish(mut self) -> Vec<Probe<L, R>> {
+        let remaining = std::mem::take(&mut self.handles)
+            .into_iter()
+            .chain(std::mem::take(&mut self.abandoned));
+
+        for (index, handle) in remaining.collect::<Vec<_>>() {
+            self.discard(index, handle);
+        }
+
+        self.discarded.sort_by_key(|probe| probe.index);
+        self.discarded
+    }
+
+    // The output of an evaluation which isn't needed is only kept if it completed successfully;
+    // errors and panics are ignored, since they don't affect the bisection.
+    fn discard(&mut self, index: usize, handle: Handle<'scope, L, R, E>) {
+        if let Ok(Ok(result)) = handle.join() {
+            self.discarded.push(Probe { index, result });
+        }
+    }
+}
+
+fn join<T>(handle: ScopedJoinHandle<'_, T>) -> T {
+    match handle.join() {
+        Ok(output) => output,
+        Err(panic) => std::panic::resume_unwind(panic),
+    }
+}
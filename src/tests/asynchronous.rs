@@ -0,0 +1,230 @@
+use super::{super::*, *};
+use futures::executor::block_on;
+use futures::StreamExt;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+fn converge_at_async(
+    boundary: u32,
+) -> impl FnMut(&u32) -> std::future::Ready<ConvergeTo<u32, u32>> {
+    let f = converge_at(boundary);
+    move |value| std::future::ready(f(value))
+}
+
+// A future which returns Pending once before it's ready, to verify that the stream correctly
+// resumes a pending evaluation, instead of calling the convergence function again.
+struct YieldOnce<T> {
+    output: Option<T>,
+    yielded: bool,
+}
+
+impl<T: Unpin> Future for YieldOnce<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        if self.yielded {
+            Poll::Ready(self.output.take().unwrap())
+        } else {
+            self.yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+#[yare::parameterized(
+    first = { 1 },
+    middle = { 5 },
+    seventh = { 7 },
+    last = { 10 },
+    none = { 11 },
+)]
+fn bisect_async_equals_bisect(boundary: u32) {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+    let indices = Indices::from_bisector(&bisector);
+
+    let expected = bisector.bisect(
+        |value| block_on(converge_at_async(boundary)(value)),
+        indices,
+    );
+    let actual = block_on(bisector.bisect_async(converge_at_async(boundary), indices));
+
+    assert_eq!(actual.indices, expected.indices);
+    assert_eq!(actual.result.is_some(), expected.result.is_some());
+}
+
+#[test]
+fn bisect_async_converged() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+    let indices = Indices::new(3, 3);
+
+    let step = block_on(bisector.bisect_async(
+        |_| async { panic!("should not be called when converged") as ConvergeTo<(), ()> },
+        indices,
+    ));
+
+    assert_eq!(step.indices, indices);
+    assert!(step.result.is_none());
+}
+
+#[test]
+fn future_may_borrow_element() {
+    let values = vec![String::from("a"), String::from("bb"), String::from("ccc")];
+    let bisector = Bisector::new(&values);
+
+    let step = block_on(bisector.bisect_async(
+        |value: &String| async move { ConvergeTo::<&str, &str>::Left(value.as_str()) },
+        Indices::from_bisector(&bisector),
+    ));
+
+    assert_eq!(step.result.unwrap().unwrap_converge_left(), "bb");
+}
+
+#[test]
+fn try_bisect_async_err() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let result = block_on(bisector.try_bisect_async(
+        |&value| async move { Err::<ConvergeTo<(), ()>, u32>(value) },
+        Indices::from_bisector(&bisector),
+    ));
+
+    assert_eq!(result.err(), Some(5));
+}
+
+#[test]
+fn try_bisect_async_ok() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let step = block_on(bisector.try_bisect_async(
+        |&value| async move { Ok::<_, ()>(ConvergeTo::<u32, u32>::Right(value)) },
+        Indices::from_bisector(&bisector),
+    ))
+    .unwrap();
+
+    assert_eq!(step.indices, Indices::new(5, 9));
+    assert_eq!(step.result.unwrap().unwrap_converge_right(), 5);
+}
+
+#[yare::parameterized(
+    first = { 1 },
+    third = { 3 },
+    middle = { 5 },
+    seventh = { 7 },
+    last = { 10 },
+    none = { 11 },
+)]
+fn stream_takes_same_steps_as_iter(boundary: u32) {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let expected = bisector
+        .iter(|value| block_on(converge_at_async(boundary)(value)))
+        .map(|step| step.indices)
+        .collect::<Vec<_>>();
+
+    let actual = block_on(
+        bisector
+            .stream(converge_at_async(boundary))
+            .map(|step| step.indices)
+            .collect::<Vec<_>>(),
+    );
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn stream_resumes_pending_evaluation() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let mut calls = 0;
+    let steps = block_on(
+        bisector
+            .stream(|&value| {
+                calls += 1;
+
+                YieldOnce {
+                    output: Some(if value >= 7 {
+                        ConvergeTo::Left(value)
+                    } else {
+                        ConvergeTo::<u32, u32>::Right(value)
+                    }),
+                    yielded: false,
+                }
+            })
+            .collect::<Vec<_>>(),
+    );
+
+    assert_eq!(steps.len(), calls);
+    assert_eq!(values[steps.last().unwrap().indices.left], 7);
+}
+
+#[test]
+fn stream_empty() {
+    let values = input_empty();
+    let bisector = Bisector::new(&values);
+
+    let mut stream = bisector.stream(converge_at_async(1));
+
+    assert!(stream.indices().is_none());
+    assert!(block_on(stream.next()).is_none());
+}
+
+#[test]
+fn stream_single() {
+    let values = input_1();
+    let bisector = Bisector::new(&values);
+
+    let mut stream = bisector.stream(converge_at_async(1));
+
+    assert_eq!(stream.indices(), Some(Indices::new(0, 0)));
+    assert!(block_on(stream.next()).is_none());
+}
+
+#[test]
+fn try_stream_stops_after_err() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let mut stream = bisector.try_stream(|&value| async move {
+        if value == 5 {
+            Err(value)
+        } else {
+            Ok(ConvergeTo::<u32, u32>::Left(value))
+        }
+    });
+
+    let first = block_on(stream.next()).unwrap();
+
+    assert_eq!(first.err(), Some(5));
+    assert_eq!(stream.indices(), Some(Indices::new(0, 9)));
+    assert!(block_on(stream.next()).is_none());
+}
+
+#[test]
+fn try_stream_ok() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let steps = block_on(
+        bisector
+            .try_stream(|&value| async move {
+                if value >= 3 {
+                    Ok::<_, ()>(ConvergeTo::<u32, u32>::Left(value))
+                } else {
+                    Ok(ConvergeTo::Right(value))
+                }
+            })
+            .collect::<Vec<_>>(),
+    );
+
+    let last = steps.into_iter().last().unwrap().unwrap();
+
+    assert_eq!(values[last.indices.left], 3);
+}
@@ -0,0 +1,254 @@
+use super::super::*;
+use super::git::{broken_since, merged_history, TempRepository};
+use std::fs;
+
+fn commits(ids: &[&str]) -> Vec<Commit> {
+    ids.iter().map(|&id| Commit::new(id)).collect()
+}
+
+#[test]
+fn parse_marks() {
+    let log = BisectLog::parse(
+        "git bisect start 'main' 'v1.0'\n\
+         # status: waiting for both good and bad commits\n\
+         # bad: [c9] Release\n\
+         git bisect bad c9\n\
+         \n\
+         # good: [c0] Initial commit\n\
+         git bisect good c0\n\
+         # skip: [c4] Broken build\n\
+         git bisect skip c4 c5\n\
+         git bisect terms\n\
+         git-bisect old c2\n\
+         git bisect new c7\n\
+         # first bad commit: [c7] Regression\n",
+    )
+    .unwrap();
+
+    let entries = log
+        .entries()
+        .iter()
+        .map(|entry| (entry.mark, entry.commit.id()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        entries,
+        vec![
+            (Mark::Bad, "c9"),
+            (Mark::Good, "c0"),
+            (Mark::Skip, "c4"),
+            (Mark::Skip, "c5"),
+            (Mark::Good, "c2"),
+            (Mark::Bad, "c7"),
+        ]
+    );
+}
+
+#[yare::parameterized(
+    with_equals = { "git bisect start '--term-old=fixed' '--term-new=broken' 'main' 'v1.0'" },
+    separate = { "git bisect start '--term-old' 'fixed' '--term-new' 'broken'" },
+    unquoted = { "git bisect start --term-new broken --term-old=fixed" },
+    good_and_bad_aliases = { "git bisect start '--term-good=fixed' '--term-bad=broken'" },
+)]
+fn parse_custom_terms(start: &str) {
+    let log = BisectLog::parse(&format!(
+        "{}\n\
+         # broken: [c9] Release\n\
+         git bisect broken c9\n\
+         git bisect terms\n\
+         git bisect fixed c0\n\
+         git bisect skip c4\n",
+        start
+    ))
+    .unwrap();
+
+    let entries = log
+        .entries()
+        .iter()
+        .map(|entry| (entry.mark, entry.commit.id()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        entries,
+        vec![(Mark::Bad, "c9"), (Mark::Good, "c0"), (Mark::Skip, "c4")]
+    );
+}
+
+#[yare::parameterized(
+    not_a_command = { "echo good c1", 1 },
+    missing_commit = { "git bisect start\ngit bisect good", 2 },
+    missing_command = { "git bisect", 1 },
+    unknown_term = { "git bisect start\ngit bisect broken c1", 2 },
+    terms_of_previous_start = {
+        "git bisect start '--term-new=broken'\ngit bisect broken c2\ngit bisect start\ngit bisect broken c1",
+        4
+    },
+)]
+fn parse_error(log: &str, line: usize) {
+    let result = BisectLog::parse(log);
+
+    assert!(matches!(result, Err(BisectLogError::Parse { line: l, .. }) if l == line));
+}
+
+#[yare::parameterized(
+    nothing_marked = { &[], Indices::new(0, 4) },
+    range_bounds_only = { &[(Mark::Good, "c0"), (Mark::Bad, "c5")], Indices::new(0, 4) },
+    good = { &[(Mark::Good, "c2")], Indices::new(2, 4) },
+    bad = { &[(Mark::Bad, "c3")], Indices::new(0, 2) },
+    both = { &[(Mark::Bad, "c4"), (Mark::Good, "c2")], Indices::new(2, 3) },
+    latest_good_and_earliest_bad = {
+        &[(Mark::Good, "c1"), (Mark::Good, "c3"), (Mark::Good, "c2"), (Mark::Bad, "c5"), (Mark::Bad, "c4")],
+        Indices::new(3, 3)
+    },
+    skip_is_ignored = { &[(Mark::Skip, "c3")], Indices::new(0, 4) },
+    unknown_commit = { &[(Mark::Good, "c9")], Indices::new(0, 4) },
+)]
+fn indices(marks: &[(Mark, &str)], expected: Indices) {
+    let candidates = commits(&["c1", "c2", "c3", "c4", "c5"]);
+    let mut log = BisectLog::new();
+
+    for &(mark, id) in marks {
+        log.push(mark, Commit::new(id));
+    }
+
+    assert_eq!(log.indices(&candidates).unwrap(), expected);
+}
+
+#[test]
+fn indices_inconsistent() {
+    let candidates = commits(&["c1", "c2", "c3"]);
+    let mut log = BisectLog::new();
+    log.push(Mark::Bad, Commit::new("c1"));
+    log.push(Mark::Good, Commit::new("c2"));
+
+    assert_eq!(
+        log.indices(&candidates),
+        Err(BisectLogError::Inconsistent {
+            good: Commit::new("c2"),
+            bad: Commit::new("c1"),
+        })
+    );
+}
+
+#[test]
+fn indices_no_candidates() {
+    let log = BisectLog::new();
+
+    assert_eq!(log.indices(&[]), Err(BisectLogError::NoCandidates));
+}
+
+#[test]
+fn write_then_parse() {
+    let mut log = BisectLog::new();
+    log.push(Mark::Bad, Commit::new("c9"));
+    log.push(Mark::Good, Commit::new("c0"));
+    log.push(Mark::Skip, Commit::new("c4"));
+
+    assert_eq!(BisectLog::parse(&log.to_string()).unwrap(), log);
+}
+
+#[test]
+fn continue_from_git_bisect() {
+    let (repository, ids) = TempRepository::with_versions(16);
+
+    repository.git(&["bisect", "start"]);
+    repository.git(&["bisect", "bad", "main"]);
+    repository.git(&["bisect", "good", ids[0].as_str()]);
+    repository.git(&["bisect", "good", ids[4].as_str()]);
+    repository.git(&["bisect", "bad", ids[12].as_str()]);
+    let log = repository.git(&["bisect", "log"]);
+    repository.git(&["bisect", "reset"]);
+
+    let opened = Repository::open(repository.path()).unwrap();
+    let candidates = opened
+        .candidates(&RevRange::new(ids[0].as_str(), "main"))
+        .unwrap();
+
+    let indices = BisectLog::parse(&log)
+        .unwrap()
+        .indices(&candidates)
+        .unwrap();
+
+    // The candidates start at version 1
+    assert_eq!(candidates[indices.left].id(), ids[5]);
+    assert_eq!(candidates[indices.right].id(), ids[12]);
+}
+
+#[test]
+fn continue_from_git_bisect_with_custom_terms() {
+    let (repository, ids) = TempRepository::with_versions(16);
+
+    repository.git(&["bisect", "start", "--term-old=fixed", "--term-new=broken"]);
+    repository.git(&["bisect", "broken", "main"]);
+    repository.git(&["bisect", "fixed", ids[0].as_str()]);
+    repository.git(&["bisect", "fixed", ids[6].as_str()]);
+    repository.git(&["bisect", "broken", ids[9].as_str()]);
+    let log = repository.git(&["bisect", "log"]);
+    repository.git(&["bisect", "reset"]);
+
+    let opened = Repository::open(repository.path()).unwrap();
+    let candidates = opened
+        .candidates(&RevRange::new(ids[0].as_str(), "main"))
+        .unwrap();
+
+    let indices = BisectLog::parse(&log)
+        .unwrap()
+        .indices(&candidates)
+        .unwrap();
+
+    assert_eq!(candidates[indices.left].id(), ids[7]);
+    assert_eq!(candidates[indices.right].id(), ids[9]);
+}
+
+#[test]
+fn continue_on_merge_history_with_first_parent_candidates() {
+    let (repository, good, commits) = merged_history();
+    let merge = repository.git(&["rev-parse", "main"]);
+
+    // The mainline parent of the merge is bad, and its side branch parent is good
+    let log = format!(
+        "git bisect start\n\
+         git bisect bad {}\n\
+         git bisect good {}\n\
+         git bisect bad {}\n\
+         git bisect good {}\n",
+        merge, good, commits[6], commits[4]
+    );
+
+    let opened = Repository::open(repository.path()).unwrap();
+    let candidates = opened
+        .candidates(&RevRange::new(good.as_str(), "main").with_first_parent(true))
+        .unwrap();
+
+    let indices = BisectLog::parse(&log)
+        .unwrap()
+        .indices(&candidates)
+        .unwrap();
+
+    // The commits of the side branch aren't candidates
+    assert_eq!(candidates[indices.left].id(), commits[5]);
+    assert_eq!(candidates[indices.right].id(), commits[6]);
+}
+
+#[test]
+fn replay_outcome_with_git_bisect() {
+    let (repository, ids) = TempRepository::with_versions(16);
+    let opened = Repository::open(repository.path()).unwrap();
+
+    let outcome = opened
+        .run(
+            &RevRange::new(ids[0].as_str(), "main"),
+            CheckoutMode::InPlace,
+            broken_since(11),
+        )
+        .unwrap();
+
+    let log = outcome.bisect_log();
+    let path = repository.path().join(".git").join("replay.log");
+    fs::write(&path, log.to_string()).unwrap();
+
+    let replayed = repository.git(&["bisect", "replay", path.to_str().unwrap()]);
+    repository.git(&["bisect", "reset"]);
+
+    assert!(replayed.contains(&format!("{} is the first bad commit", ids[11])));
+}
@@ -0,0 +1,119 @@
+use super::{super::*, *};
+
+fn segments(found: &ChangePoints<u32>) -> Vec<(usize, usize, u32)> {
+    found
+        .segments
+        .iter()
+        .map(|segment| (segment.indices.left, segment.indices.right, segment.value))
+        .collect()
+}
+
+#[yare::parameterized(
+    constant = { |_| 0, vec![(0, 9, 0)] },
+    binary = { |&value| u32::from(value >= 4), vec![(0, 2, 0), (3, 9, 1)] },
+    three_values = { |&value| value / 4, vec![(0, 2, 0), (3, 6, 1), (7, 9, 2)] },
+    change_at_last = { |&value| u32::from(value == 10), vec![(0, 8, 0), (9, 9, 1)] },
+    change_at_second = { |&value| u32::from(value >= 2), vec![(0, 0, 0), (1, 9, 1)] },
+    every_element = { |&value| value, (0..10).map(|index| (index, index, index as u32 + 1)).collect() },
+)]
+fn change_points_finds_every_segment(f: fn(&u32) -> u32, expected: Vec<(usize, usize, u32)>) {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let found = bisector.change_points(f).unwrap();
+
+    assert_eq!(segments(&found), expected);
+}
+
+#[test]
+fn indices() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let found = bisector.change_points(|&value| value / 4).unwrap();
+
+    assert_eq!(found.indices().collect::<Vec<_>>(), vec![3, 7]);
+}
+
+#[test]
+fn constant_regions_are_not_probed() {
+    let values = (0..1_000_000).collect::<Vec<u32>>();
+    let bisector = Bisector::new(&values);
+
+    let mut calls = 0;
+    let found = bisector
+        .change_points(|&value| {
+            calls += 1;
+            value / 250_000
+        })
+        .unwrap();
+
+    assert_eq!(
+        found.indices().collect::<Vec<_>>(),
+        vec![250_000, 500_000, 750_000]
+    );
+    assert_eq!(found.probes, calls);
+    assert!(calls < 100);
+}
+
+#[test]
+fn reappearing_value_between_equal_probes_is_missed() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    // The first, middle and last element produce the same value
+    let found = bisector
+        .change_points(|&value| u32::from(value == 3))
+        .unwrap();
+
+    assert_eq!(segments(&found), vec![(0, 9, 0)]);
+}
+
+#[test]
+fn single() {
+    let values = input_1();
+    let bisector = Bisector::new(&values);
+
+    let found = bisector.change_points(|&value| value).unwrap();
+
+    assert_eq!(segments(&found), vec![(0, 0, 1)]);
+    assert_eq!(found.probes, 1);
+}
+
+#[test]
+fn empty() {
+    let values = input_empty();
+    let bisector = Bisector::new(&values);
+
+    let result = bisector.change_points(|&value| value);
+
+    assert_eq!(result.err(), Some(EmptySliceError));
+}
+
+#[test]
+fn try_change_points_err() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let result = bisector.try_change_points(|&value| {
+        if value == 5 {
+            Err(value)
+        } else {
+            Ok(value / 4)
+        }
+    });
+
+    assert_eq!(result.err(), Some(TryRunError::Convergence(5)));
+}
+
+#[test]
+fn try_change_points_ok() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let found = bisector
+        .try_change_points(|&value| Ok::<_, ()>(value / 4))
+        .unwrap();
+
+    assert_eq!(segments(&found), vec![(0, 2, 0), (3, 6, 1), (7, 9, 2)]);
+}
@@ -0,0 +1,162 @@
+use super::super::*;
+use super::git::{broken_since, run_git, version, TempRepository};
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+// Converges to the left if any file named `bug*` exists in the working tree
+fn has_bug(_: &Commit, path: &Path) -> ConvergeTo<(), ()> {
+    let bugs = fs::read_dir(path)
+        .unwrap()
+        .filter(|entry| {
+            let name = entry.as_ref().unwrap().file_name();
+            name.to_string_lossy().starts_with("bug")
+        })
+        .count();
+
+    if bugs > 0 {
+        ConvergeTo::Left(())
+    } else {
+        ConvergeTo::Right(())
+    }
+}
+
+#[test]
+fn confirmed() {
+    let repository = TempRepository::new();
+    let good = repository.commit("README.md", "0");
+    repository.commit("a", "1");
+    let culprit = repository.commit("bug", "2");
+    repository.commit("b", "3");
+    repository.commit("c", "4");
+
+    let worktrees = TempDir::new().unwrap();
+    let worktree = worktrees.path().join("confirm");
+    let opened = Repository::open(repository.path()).unwrap();
+    let range = RevRange::new(good.as_str(), "main");
+
+    let mut outcome = opened.run(&range, CheckoutMode::InPlace, has_bug).unwrap();
+    assert_eq!(outcome.commit.id(), culprit);
+    assert!(outcome.confirmation.is_none());
+
+    let mut probed = Vec::new();
+    let confirmation = opened
+        .confirm(&mut outcome, &worktree, |commit, path| {
+            probed.push(commit.clone());
+
+            // The culprit is reverted, but not committed
+            assert_eq!(path, worktree);
+            assert_eq!(run_git(path, &["rev-parse", "HEAD"]), commit.id());
+
+            has_bug(commit, path)
+        })
+        .unwrap();
+
+    assert_eq!(*confirmation, Confirmation::Confirmed(()));
+    assert_eq!(outcome.confirmation, Some(Confirmation::Confirmed(())));
+    assert_eq!(probed, vec![outcome.candidates.last().unwrap().clone()]);
+    assert!(!worktree.exists());
+    assert!(repository.path().join("bug").exists());
+}
+
+#[test]
+fn confirmed_merge() {
+    let repository = TempRepository::new();
+    let good = repository.commit("README.md", "0");
+
+    repository.git(&["checkout", "--quiet", "-b", "feature"]);
+    repository.commit("feature", "1");
+    repository.commit("bug", "2");
+
+    repository.git(&["checkout", "--quiet", "main"]);
+    repository.commit("a", "3");
+    repository.git(&["merge", "--quiet", "--no-ff", "--no-edit", "feature"]);
+    let merge = repository.git(&["rev-parse", "HEAD"]);
+    repository.commit("b", "4");
+
+    let worktrees = TempDir::new().unwrap();
+    let opened = Repository::open(repository.path()).unwrap();
+    let range = RevRange::new(good.as_str(), "main").with_first_parent(true);
+
+    let mut outcome = opened.run(&range, CheckoutMode::InPlace, has_bug).unwrap();
+    assert_eq!(outcome.commit.id(), merge);
+
+    let confirmation = opened
+        .confirm(&mut outcome, worktrees.path().join("confirm"), has_bug)
+        .unwrap();
+
+    assert_eq!(*confirmation, Confirmation::Confirmed(()));
+}
+
+#[test]
+fn revert_conflicts() {
+    let (repository, commits) = TempRepository::with_versions(16);
+    let worktrees = TempDir::new().unwrap();
+    let worktree = worktrees.path().join("confirm");
+    let opened = Repository::open(repository.path()).unwrap();
+    let range = RevRange::new(commits[0].as_str(), "main");
+
+    let mut outcome = opened
+        .run(&range, CheckoutMode::InPlace, broken_since(11))
+        .unwrap();
+
+    // Every commit changes the same line, so reverting an earlier one conflicts
+    let confirmation = opened
+        .confirm(&mut outcome, &worktree, |_, _| -> ConvergeTo<u32, u32> {
+            panic!("a conflicting revert must not be tested")
+        })
+        .unwrap();
+
+    assert_eq!(*confirmation, Confirmation::RevertConflicts);
+    assert!(!worktree.exists());
+    assert!(!repository.git(&["worktree", "list"]).contains("confirm"));
+    assert_eq!(version(repository.path()), 15);
+}
+
+#[test]
+fn still_failing() {
+    let repository = TempRepository::new();
+    let good = repository.commit("README.md", "0");
+    let first = repository.commit("bug-1", "1");
+    repository.commit("a", "2");
+    repository.commit("bug-2", "3");
+
+    let worktrees = TempDir::new().unwrap();
+    let opened = Repository::open(repository.path()).unwrap();
+    let range = RevRange::new(good.as_str(), "main");
+
+    let mut outcome = opened.run(&range, CheckoutMode::InPlace, has_bug).unwrap();
+    assert_eq!(outcome.commit.id(), first);
+
+    let confirmation = opened
+        .confirm(&mut outcome, worktrees.path().join("confirm"), has_bug)
+        .unwrap();
+
+    assert_eq!(*confirmation, Confirmation::StillFailing(()));
+}
+
+#[test]
+fn try_confirm_err() {
+    let repository = TempRepository::new();
+    let good = repository.commit("README.md", "0");
+    repository.commit("bug", "1");
+    repository.commit("a", "2");
+
+    let worktrees = TempDir::new().unwrap();
+    let worktree = worktrees.path().join("confirm");
+    let opened = Repository::open(repository.path()).unwrap();
+    let range = RevRange::new(good.as_str(), "main");
+
+    let mut outcome = opened.run(&range, CheckoutMode::InPlace, has_bug).unwrap();
+
+    let result = opened.try_confirm(&mut outcome, &worktree, |_, _| {
+        Err::<ConvergeTo<(), ()>, _>("does not build")
+    });
+
+    assert!(matches!(
+        result,
+        Err(GitError::Convergence("does not build"))
+    ));
+    assert!(outcome.confirmation.is_none());
+    assert!(!worktree.exists());
+}
@@ -0,0 +1,283 @@
+use super::{super::*, *};
+
+//   0 - 1 - 2 ----- 5 - 6
+//        \         /
+//         3 ----- 4
+fn merge_history() -> (Vec<u32>, Vec<Vec<usize>>) {
+    (
+        (0..7).collect(),
+        vec![
+            vec![],
+            vec![0],
+            vec![1],
+            vec![1],
+            vec![3],
+            vec![2, 4],
+            vec![5],
+        ],
+    )
+}
+
+fn linear(len: usize) -> (Vec<u32>, Vec<Vec<usize>>) {
+    (
+        (0..len as u32).collect(),
+        (0..len)
+            .map(|index| index.checked_sub(1).into_iter().collect())
+            .collect(),
+    )
+}
+
+// Bad if the node is one of the given culprits, or descends from one
+fn bad_from<'p>(
+    parents: &'p [Vec<usize>],
+    culprit: usize,
+) -> impl FnMut(&u32) -> ConvergeTo<(), ()> + 'p {
+    move |&node| {
+        let mut stack = vec![node as usize];
+
+        while let Some(index) = stack.pop() {
+            if index == culprit {
+                return ConvergeTo::Left(());
+            }
+
+            stack.extend(&parents[index]);
+        }
+
+        ConvergeTo::Right(())
+    }
+}
+
+#[yare::parameterized(
+    first_on_main = { 1 },
+    main_before_merge = { 2 },
+    side_branch = { 3 },
+    side_branch_tip = { 4 },
+    merge = { 5 },
+    tip = { 6 },
+)]
+fn run_finds_first_bad_node(culprit: usize) {
+    let (nodes, parents) = merge_history();
+    let bisector = DagBisector::new(&nodes, parents.clone(), 6)
+        .unwrap()
+        .with_good(0);
+
+    let outcome = bisector.run(bad_from(&parents, culprit));
+
+    assert_eq!(outcome.index, culprit);
+    assert_eq!(*outcome.node, culprit as u32);
+}
+
+#[test]
+fn candidates_exclude_ancestors_of_good() {
+    let (nodes, parents) = merge_history();
+    let bisector = DagBisector::new(&nodes, parents, 6).unwrap();
+
+    assert_eq!(bisector.candidates(), vec![0, 1, 2, 3, 4, 5, 6]);
+    assert_eq!(bisector.clone().with_good(2).candidates(), vec![3, 4, 5, 6]);
+    assert_eq!(bisector.with_good(4).with_bad(5).candidates(), vec![2, 5]);
+}
+
+#[test]
+fn next_probe_halves_candidates() {
+    let (nodes, parents) = merge_history();
+    let bisector = DagBisector::new(&nodes, parents, 6).unwrap().with_good(0);
+
+    // 4 has three candidate ancestors (1, 3 and itself) out of six candidates
+    assert_eq!(bisector.next_probe(), Some(4));
+}
+
+// A pseudo-random history of `len` commits, in which every commit has one or two parents among
+// the commits before it
+fn random_history(len: usize, mut seed: u64) -> (Vec<u32>, Vec<Vec<usize>>) {
+    let mut next = |bound: usize| {
+        seed = seed
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (seed >> 33) as usize % bound
+    };
+
+    let parents = (0..len)
+        .map(|index| match index {
+            0 => vec![],
+            _ if next(4) == 0 => vec![index - 1, next(index)],
+            _ => vec![index - 1 - next(index.min(3))],
+        })
+        .collect();
+
+    ((0..len as u32).collect(), parents)
+}
+
+// The next probe as defined by the documentation, computing the ancestors of every candidate
+fn next_probe_by_definition(bisector: &DagBisector<u32>, parents: &[Vec<usize>]) -> Option<usize> {
+    let candidates = bisector.candidates();
+    let weight = |index: usize| {
+        let mut visited = vec![false; parents.len()];
+        let mut stack = vec![index];
+
+        while let Some(index) = stack.pop() {
+            if !visited[index] && candidates.contains(&index) {
+                visited[index] = true;
+                stack.extend(&parents[index]);
+            }
+        }
+
+        visited.iter().filter(|&&visited| visited).count()
+    };
+
+    candidates
+        .iter()
+        .filter(|&&index| index != bisector.bad())
+        .map(|&index| (weight(index).min(candidates.len() - weight(index)), index))
+        .max_by(|(left, left_index), (right, right_index)| {
+            left.cmp(right).then(right_index.cmp(left_index))
+        })
+        .map(|(_, index)| index)
+}
+
+#[yare::parameterized(
+    small = { 20, 1 },
+    medium = { 100, 2 },
+    wide = { 200, 3 },
+)]
+fn next_probe_matches_definition(len: usize, seed: u64) {
+    let (nodes, parents) = random_history(len, seed);
+    let mut bisector = DagBisector::new(&nodes, parents.clone(), len - 1).unwrap();
+    let mut is_bad = bad_from(&parents, len / 3);
+
+    while let Some(index) = bisector.next_probe() {
+        assert_eq!(Some(index), next_probe_by_definition(&bisector, &parents));
+
+        bisector = match is_bad(&nodes[index]) {
+            ConvergeTo::Left(()) => bisector.with_bad(index),
+            ConvergeTo::Right(()) => bisector.with_good(index),
+        };
+    }
+
+    assert_eq!(next_probe_by_definition(&bisector, &parents), None);
+}
+
+#[test]
+fn long_linear_history() {
+    let (nodes, parents) = linear(20_000);
+    let bisector = DagBisector::new(&nodes, parents.clone(), 19_999).unwrap();
+
+    let outcome = bisector.run(bad_from(&parents, 12_345));
+
+    // Like plain bisection, about log2(20 000) probes
+    assert_eq!(outcome.index, 12_345);
+    assert!(outcome.probes.len() <= 15);
+}
+
+#[test]
+fn next_probe_converged() {
+    let (nodes, parents) = merge_history();
+    let bisector = DagBisector::new(&nodes, parents, 5)
+        .unwrap()
+        .with_good(2)
+        .with_good(4);
+
+    assert_eq!(bisector.candidates(), vec![5]);
+    assert_eq!(bisector.next_probe(), None);
+}
+
+#[yare::parameterized(
+    first = { 1 },
+    middle = { 500 },
+    last = { 1023 },
+)]
+fn linear_history_is_bisected(culprit: usize) {
+    let (nodes, parents) = linear(1024);
+    let bisector = DagBisector::new(&nodes, parents.clone(), 1023)
+        .unwrap()
+        .with_good(0);
+
+    let outcome = bisector.run(bad_from(&parents, culprit));
+
+    assert_eq!(outcome.index, culprit);
+    assert!(outcome.probes.len() <= 10);
+}
+
+#[test]
+fn probes_are_recorded() {
+    let (nodes, parents) = merge_history();
+    let bisector = DagBisector::new(&nodes, parents.clone(), 6)
+        .unwrap()
+        .with_good(0);
+
+    let outcome = bisector.run(bad_from(&parents, 4));
+
+    let probed = outcome
+        .probes
+        .iter()
+        .map(|probe| probe.index)
+        .collect::<Vec<_>>();
+    assert_eq!(probed, vec![4, 1, 3]);
+    assert!(matches!(outcome.probes[0].result, ConvergeTo::Left(())));
+    assert!(matches!(outcome.probes[1].result, ConvergeTo::Right(())));
+}
+
+#[test]
+fn length_mismatch() {
+    let nodes = input_1_to_10();
+
+    let result = DagBisector::new(&nodes, vec![vec![]], 0);
+
+    assert_eq!(
+        result.err(),
+        Some(InvalidDagError::LengthMismatch {
+            nodes: 10,
+            parents: 1
+        })
+    );
+}
+
+#[yare::parameterized(
+    parent = { vec![vec![], vec![2]], 1, 2 },
+    bad = { vec![vec![], vec![0]], 5, 5 },
+)]
+fn out_of_range(parents: Vec<Vec<usize>>, bad: usize, index: usize) {
+    let nodes = vec![1, 2];
+
+    let result = DagBisector::new(&nodes, parents, bad);
+
+    assert_eq!(result.err(), Some(InvalidDagError::OutOfRange { index }));
+}
+
+#[yare::parameterized(
+    self_loop = { vec![vec![], vec![1], vec![1]] },
+    two_nodes = { vec![vec![], vec![2], vec![1]] },
+)]
+fn cycle(parents: Vec<Vec<usize>>) {
+    let nodes = vec![1, 2, 3];
+
+    let result = DagBisector::new(&nodes, parents, 2);
+
+    assert_eq!(result.err(), Some(InvalidDagError::Cycle));
+}
+
+#[test]
+fn single_node() {
+    let nodes = input_1();
+    let bisector = DagBisector::new(&nodes, vec![vec![]], 0).unwrap();
+
+    let outcome = bisector.run(|_| -> ConvergeTo<(), ()> { unreachable!() });
+
+    assert_eq!(outcome.index, 0);
+    assert!(outcome.probes.is_empty());
+}
+
+#[test]
+fn try_run_err() {
+    let (nodes, parents) = merge_history();
+    let bisector = DagBisector::new(&nodes, parents, 6).unwrap().with_good(0);
+
+    let result = bisector.try_run(|&node| {
+        if node == 4 {
+            Err("does not build")
+        } else {
+            Ok(ConvergeTo::<(), ()>::Right(()))
+        }
+    });
+
+    assert_eq!(result.err(), Some("does not build"));
+}
@@ -0,0 +1,171 @@
+use super::{super::*, *};
+
+fn probed(gallop: &Gallop<u32, u32>) -> Vec<usize> {
+    gallop.probes.iter().map(|probe| probe.index).collect()
+}
+
+#[yare::parameterized(
+    first = { 1, Indices::new(0, 0), vec![0] },
+    second = { 2, Indices::new(1, 1), vec![0, 1] },
+    third = { 3, Indices::new(2, 3), vec![0, 1, 3] },
+    fourth = { 4, Indices::new(2, 3), vec![0, 1, 3] },
+    fifth = { 5, Indices::new(4, 7), vec![0, 1, 3, 7] },
+    eighth = { 8, Indices::new(4, 7), vec![0, 1, 3, 7] },
+    ninth = { 9, Indices::new(8, 9), vec![0, 1, 3, 7] },
+    last = { 10, Indices::new(8, 9), vec![0, 1, 3, 7] },
+    none = { 11, Indices::new(8, 9), vec![0, 1, 3, 7] },
+)]
+fn gallop_brackets_boundary(boundary: u32, expected: Indices, expected_probes: Vec<usize>) {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let gallop = bisector.gallop(converge_at(boundary), Indices::from_bisector(&bisector));
+
+    assert_eq!(gallop.indices, expected);
+    assert_eq!(probed(&gallop), expected_probes);
+}
+
+#[yare::parameterized(
+    first = { 1 },
+    third = { 3 },
+    middle = { 5 },
+    seventh = { 7 },
+    last = { 10 },
+    none = { 11 },
+)]
+fn gallop_then_bisect_equals_bisect(boundary: u32) {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let expected = bisector
+        .iter(converge_at(boundary))
+        .last()
+        .map(|step| step.indices.left);
+
+    let mut indices = bisector
+        .gallop(converge_at(boundary), Indices::from_bisector(&bisector))
+        .indices;
+
+    loop {
+        let step = bisector.bisect(converge_at(boundary), indices);
+
+        if step.result.is_none() {
+            break;
+        }
+
+        indices = step.indices;
+    }
+
+    assert_eq!(Some(indices.left), expected);
+}
+
+#[test]
+fn gallop_from_left_index() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let gallop = bisector.gallop(converge_at(7), Indices::new(3, 9));
+
+    assert_eq!(probed(&gallop), vec![3, 4, 6]);
+    assert_eq!(gallop.indices, Indices::new(5, 6));
+}
+
+#[test]
+fn gallop_capped() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let gallop = bisector.gallop(converge_at(11), Indices::new(0, 5));
+
+    assert_eq!(probed(&gallop), vec![0, 1, 3]);
+    assert_eq!(gallop.indices, Indices::new(4, 5));
+}
+
+#[test]
+fn gallop_converged() {
+    let values = input_1();
+    let bisector = Bisector::new(&values);
+
+    let gallop = bisector.gallop(converge_at(1), Indices::from_bisector(&bisector));
+
+    assert!(gallop.probes.is_empty());
+    assert_eq!(gallop.indices, Indices::new(0, 0));
+}
+
+#[test]
+fn try_gallop_err() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let mut calls = 0;
+    let result = bisector.try_gallop(
+        |&value| {
+            calls += 1;
+
+            if value == 4 {
+                Err(value)
+            } else {
+                Ok(ConvergeTo::<(), ()>::Right(()))
+            }
+        },
+        Indices::from_bisector(&bisector),
+    );
+
+    assert_eq!(result.err(), Some(4));
+    assert_eq!(calls, 3);
+}
+
+#[test]
+fn range_gallop_open_ended() {
+    let bisector = RangeBisector::new(0u64..).unwrap();
+    let boundary = 1_000_000_007;
+
+    let gallop = bisector.gallop(
+        |value| {
+            if value >= boundary {
+                ConvergeTo::Left(())
+            } else {
+                ConvergeTo::<(), ()>::Right(())
+            }
+        },
+        Indices::from_range_bisector(&bisector),
+    );
+
+    assert_eq!(gallop.probes.len(), 31);
+    assert_eq!(bisector.value(gallop.indices.left), (1 << 29) - 1 + 1);
+    assert_eq!(bisector.value(gallop.indices.right), (1 << 30) - 1);
+}
+
+#[test]
+fn range_gallop_no_boundary_saturates() {
+    let bisector = RangeBisector::new(..=usize::MAX).unwrap();
+
+    let gallop = bisector.gallop(
+        |_| ConvergeTo::<(), ()>::Right(()),
+        Indices::from_range_bisector(&bisector),
+    );
+
+    assert_eq!(gallop.probes.len(), 64);
+    assert_eq!(gallop.indices.right, usize::MAX);
+}
+
+#[test]
+fn range_try_gallop_ok() {
+    let bisector = RangeBisector::new(-10i32..=10).unwrap();
+
+    let gallop = bisector
+        .try_gallop(
+            |value| {
+                if value >= -5 {
+                    Ok::<_, ()>(ConvergeTo::<i32, i32>::Left(value))
+                } else {
+                    Ok(ConvergeTo::Right(value))
+                }
+            },
+            Indices::from_range_bisector(&bisector),
+        )
+        .unwrap();
+
+    assert_eq!(bisector.value(gallop.indices.left), -6);
+    assert_eq!(bisector.value(gallop.indices.right), -3);
+}
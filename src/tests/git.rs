@@ -0,0 +1,561 @@
+use super::super::*;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+pub(super) fn run_git(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+// A temporary repository on the `main` branch
+pub(super) struct TempRepository {
+    dir: TempDir,
+}
+
+impl TempRepository {
+    pub(super) fn new() -> Self {
+        let dir = TempDir::new().unwrap();
+        let repository = Self { dir };
+
+        repository.git(&["init", "--quiet"]);
+        repository.git(&["checkout", "--quiet", "-b", "main"]);
+        repository.git(&["config", "user.name", "Bisector"]);
+        repository.git(&["config", "user.email", "bisector@example.com"]);
+        repository.git(&["config", "commit.gpgsign", "false"]);
+
+        repository
+    }
+
+    pub(super) fn path(&self) -> &Path {
+        self.dir.path()
+    }
+
+    pub(super) fn git(&self, args: &[&str]) -> String {
+        run_git(self.path(), args)
+    }
+
+    // Writes the given content to the given file and commits it, returning the commit id
+    pub(super) fn commit(&self, file: &str, content: &str) -> String {
+        let path = self.path().join(file);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+
+        self.git(&["add", "--all"]);
+        self.git(&[
+            "commit",
+            "--quiet",
+            "--message",
+            &format!("{}: {}", file, content),
+        ]);
+        self.git(&["rev-parse", "HEAD"])
+    }
+
+    // Commits the versions 0 up to the given number to the `version` file
+    pub(super) fn with_versions(versions: u32) -> (Self, Vec<String>) {
+        let repository = Self::new();
+        let commits = (0..versions)
+            .map(|version| repository.commit("version", &version.to_string()))
+            .collect();
+
+        (repository, commits)
+    }
+}
+
+pub(super) fn version(path: &Path) -> u32 {
+    fs::read_to_string(path.join("version"))
+        .unwrap()
+        .trim()
+        .parse()
+        .unwrap()
+}
+
+pub(super) fn broken_since(first_bad: u32) -> impl FnMut(&Commit, &Path) -> ConvergeTo<u32, u32> {
+    move |_, path| {
+        let version = version(path);
+
+        if version >= first_bad {
+            ConvergeTo::Left(version)
+        } else {
+            ConvergeTo::Right(version)
+        }
+    }
+}
+
+fn ids(commits: &[Commit]) -> Vec<&str> {
+    commits.iter().map(Commit::id).collect()
+}
+
+#[test]
+fn open_finds_toplevel() {
+    let (repository, _) = TempRepository::with_versions(1);
+    fs::create_dir(repository.path().join("nested")).unwrap();
+
+    let opened = Repository::open(repository.path().join("nested")).unwrap();
+
+    assert_eq!(
+        opened.path().canonicalize().unwrap(),
+        repository.path().canonicalize().unwrap()
+    );
+}
+
+#[test]
+fn open_outside_repository() {
+    let dir = TempDir::new().unwrap();
+
+    let result = Repository::open(dir.path());
+
+    assert!(matches!(result, Err(GitError::Command { .. })));
+}
+
+#[test]
+fn candidates_from_oldest_to_newest() {
+    let (repository, commits) = TempRepository::with_versions(10);
+    let opened = Repository::open(repository.path()).unwrap();
+
+    let candidates = opened
+        .candidates(&RevRange::new(commits[0].as_str(), "main"))
+        .unwrap();
+
+    assert_eq!(ids(&candidates), commits[1..].to_vec());
+}
+
+#[test]
+fn candidates_with_pathspec() {
+    let repository = TempRepository::new();
+    let good = repository.commit("src/lib.rs", "0");
+    let docs = repository.commit("docs/index.md", "1");
+    let src = repository.commit("src/lib.rs", "2");
+    let _ = repository.commit("docs/index.md", "3");
+    let bad = repository.commit("README.md", "4");
+
+    let opened = Repository::open(repository.path()).unwrap();
+    let candidates = opened
+        .candidates(&RevRange::new(good.as_str(), bad.as_str()).with_pathspec("src"))
+        .unwrap();
+
+    // The bad commit is the last candidate, even though it doesn't touch `src`
+    assert_eq!(ids(&candidates), vec![src.as_str(), bad.as_str()]);
+    assert!(!ids(&candidates).contains(&docs.as_str()));
+}
+
+#[yare::parameterized(
+    all_parents = { false, 4 },
+    first_parent = { true, 2 },
+)]
+fn candidates_with_merge(first_parent: bool, expected: usize) {
+    let repository = TempRepository::new();
+    let good = repository.commit("version", "0");
+
+    repository.git(&["checkout", "--quiet", "-b", "feature"]);
+    repository.commit("feature", "1");
+    repository.commit("feature", "2");
+
+    repository.git(&["checkout", "--quiet", "main"]);
+    repository.commit("version", "3");
+    repository.git(&["merge", "--quiet", "--no-ff", "--no-edit", "feature"]);
+
+    let opened = Repository::open(repository.path()).unwrap();
+    let candidates = opened
+        .candidates(&RevRange::new(good.as_str(), "main").with_first_parent(first_parent))
+        .unwrap();
+
+    assert_eq!(candidates.len(), expected);
+}
+
+// A history where the side branch, which commits the versions 1 to 4 of the `feature` file, is
+// merged after the mainline committed the versions 5 and 6 of the `version` file. Returns the good
+// commit, and the commits of each version, where the `feature` and `version` files are at version 0
+// until they were first committed.
+pub(super) fn merged_history() -> (TempRepository, String, Vec<String>) {
+    let repository = TempRepository::new();
+    let good = repository.commit("version", "0");
+    let mut commits = vec![good.clone()];
+
+    repository.git(&["checkout", "--quiet", "-b", "feature"]);
+    for version in 1..5 {
+        commits.push(repository.commit("feature", &version.to_string()));
+    }
+
+    repository.git(&["checkout", "--quiet", "main"]);
+    for version in 5..7 {
+        commits.push(repository.commit("version", &version.to_string()));
+    }
+
+    repository.git(&["merge", "--quiet", "--no-ff", "--no-edit", "feature"]);
+
+    (repository, good, commits)
+}
+
+fn file_version(path: &Path, file: &str) -> u32 {
+    fs::read_to_string(path.join(file))
+        .map(|content| content.trim().parse().unwrap())
+        .unwrap_or(0)
+}
+
+#[test]
+fn candidates_list_parents_first() {
+    let (repository, good, _) = merged_history();
+    let opened = Repository::open(repository.path()).unwrap();
+
+    let candidates = opened
+        .candidates(&RevRange::new(good.as_str(), "main"))
+        .unwrap();
+
+    for (index, candidate) in candidates.iter().enumerate() {
+        let parents = repository.git(&["rev-list", "--parents", "-n", "1", candidate.id()]);
+
+        for parent in parents.split_whitespace().skip(1) {
+            assert!(ids(&candidates[..index]).contains(&parent) || parent == good);
+        }
+    }
+}
+
+#[yare::parameterized(
+    first_on_side = { "feature", 1 },
+    third_on_side = { "feature", 3 },
+    last_on_side = { "feature", 4 },
+    first_on_mainline = { "version", 5 },
+    last_on_mainline = { "version", 6 },
+)]
+fn run_finds_culprit_on_either_side_of_merge(file: &str, first_bad: u32) {
+    let (repository, good, commits) = merged_history();
+    let opened = Repository::open(repository.path()).unwrap();
+
+    let outcome = opened
+        .run(
+            &RevRange::new(good.as_str(), "main"),
+            CheckoutMode::InPlace,
+            |_, path| {
+                let version = file_version(path, file);
+
+                if version >= first_bad {
+                    ConvergeTo::Left(version)
+                } else {
+                    ConvergeTo::<u32, u32>::Right(version)
+                }
+            },
+        )
+        .unwrap();
+
+    assert_eq!(outcome.commit.id(), commits[first_bad as usize]);
+    assert_eq!(outcome.candidates[outcome.index], outcome.commit);
+}
+
+#[test]
+fn run_with_pathspec_on_merged_history() {
+    let (repository, good, commits) = merged_history();
+    let opened = Repository::open(repository.path()).unwrap();
+
+    let outcome = opened
+        .run(
+            &RevRange::new(good.as_str(), "main").with_pathspec("feature"),
+            CheckoutMode::InPlace,
+            |_, path| {
+                if file_version(path, "feature") >= 3 {
+                    ConvergeTo::Left(())
+                } else {
+                    ConvergeTo::<(), ()>::Right(())
+                }
+            },
+        )
+        .unwrap();
+
+    // The merge commit doesn't touch `feature` compared with the side branch, so it's only a
+    // candidate as the bad commit
+    assert_eq!(
+        ids(&outcome.candidates),
+        vec![
+            commits[1].as_str(),
+            commits[2].as_str(),
+            commits[3].as_str(),
+            commits[4].as_str(),
+            repository.git(&["rev-parse", "main"]).as_str(),
+        ]
+    );
+    assert_eq!(outcome.commit.id(), commits[3]);
+}
+
+#[test]
+fn no_candidates() {
+    let (repository, _) = TempRepository::with_versions(3);
+    let opened = Repository::open(repository.path()).unwrap();
+
+    let result = opened.candidates(&RevRange::new("main", "main"));
+
+    assert!(matches!(result, Err(GitError::NoCandidates)));
+}
+
+#[test]
+fn unknown_revision() {
+    let (repository, _) = TempRepository::with_versions(3);
+    let opened = Repository::open(repository.path()).unwrap();
+
+    let result = opened.candidates(&RevRange::new("v0.0.0", "main"));
+
+    assert!(matches!(result, Err(GitError::Command { .. })));
+}
+
+#[yare::parameterized(
+    first = { 1 },
+    middle = { 6 },
+    last = { 15 },
+)]
+fn run_in_place(first_bad: u32) {
+    let (repository, commits) = TempRepository::with_versions(16);
+    let opened = Repository::open(repository.path()).unwrap();
+
+    let outcome = opened
+        .run(
+            &RevRange::new(commits[0].as_str(), "main"),
+            CheckoutMode::InPlace,
+            broken_since(first_bad),
+        )
+        .unwrap();
+
+    assert_eq!(outcome.commit.id(), commits[first_bad as usize]);
+    assert_eq!(outcome.candidates[outcome.index], outcome.commit);
+
+    // The original branch is checked out again
+    assert_eq!(repository.git(&["symbolic-ref", "--short", "HEAD"]), "main");
+    assert_eq!(version(repository.path()), 15);
+}
+
+#[test]
+fn run_in_place_restores_detached_head() {
+    let (repository, commits) = TempRepository::with_versions(8);
+    repository.git(&["checkout", "--quiet", "--detach", commits[7].as_str()]);
+    let opened = Repository::open(repository.path()).unwrap();
+
+    let _ = opened
+        .run(
+            &RevRange::new(commits[0].as_str(), "main"),
+            CheckoutMode::InPlace,
+            broken_since(3),
+        )
+        .unwrap();
+
+    assert_eq!(repository.git(&["rev-parse", "HEAD"]), commits[7]);
+}
+
+#[test]
+fn run_in_worktree() {
+    let (repository, commits) = TempRepository::with_versions(16);
+    let worktrees = TempDir::new().unwrap();
+    let worktree = worktrees.path().join("bisect");
+    let opened = Repository::open(repository.path()).unwrap();
+
+    let mut probed = 0;
+    let outcome = opened
+        .run(
+            &RevRange::new(commits[0].as_str(), "main"),
+            CheckoutMode::Worktree(worktree.clone()),
+            |commit, path| {
+                probed += 1;
+
+                // The repository itself is left untouched
+                assert_eq!(path, worktree);
+                assert_eq!(version(repository.path()), 15);
+                assert_eq!(run_git(path, &["rev-parse", "HEAD"]), commit.id());
+
+                broken_since(9)(commit, path)
+            },
+        )
+        .unwrap();
+
+    assert_eq!(outcome.commit.id(), commits[9]);
+    assert_eq!(outcome.probes.len(), probed);
+    assert!(!worktree.exists());
+    assert!(!repository.git(&["worktree", "list"]).contains("bisect"));
+}
+
+#[test]
+fn panicking_predicate_restores_head() {
+    let (repository, commits) = TempRepository::with_versions(8);
+    let opened = Repository::open(repository.path()).unwrap();
+
+    let result = std::panic::catch_unwind(|| {
+        opened.run(
+            &RevRange::new(commits[0].as_str(), "main"),
+            CheckoutMode::InPlace,
+            |_, _| -> ConvergeTo<(), ()> { panic!("predicate panicked") },
+        )
+    });
+
+    assert!(result.is_err());
+    assert_eq!(repository.git(&["symbolic-ref", "--short", "HEAD"]), "main");
+    assert_eq!(version(repository.path()), 7);
+}
+
+#[test]
+fn run_in_place_dirty_working_tree() {
+    let (repository, commits) = TempRepository::with_versions(8);
+    fs::write(repository.path().join("version"), "local change").unwrap();
+    let opened = Repository::open(repository.path()).unwrap();
+
+    let result = opened.run(
+        &RevRange::new(commits[0].as_str(), "main"),
+        CheckoutMode::InPlace,
+        |_, _| -> ConvergeTo<(), ()> { panic!("nothing should be probed") },
+    );
+
+    assert!(matches!(result, Err(GitError::DirtyWorkingTree)));
+    assert_eq!(
+        fs::read_to_string(repository.path().join("version")).unwrap(),
+        "local change"
+    );
+}
+
+#[test]
+fn run_in_place_ignores_untracked_files() {
+    let (repository, commits) = TempRepository::with_versions(8);
+    fs::write(repository.path().join("untracked"), "").unwrap();
+    let opened = Repository::open(repository.path()).unwrap();
+
+    let outcome = opened
+        .run(
+            &RevRange::new(commits[0].as_str(), "main"),
+            CheckoutMode::InPlace,
+            broken_since(5),
+        )
+        .unwrap();
+
+    assert_eq!(outcome.commit.id(), commits[5]);
+    assert!(repository.path().join("untracked").exists());
+}
+
+#[test]
+fn run_in_place_keeps_colliding_untracked_file() {
+    let repository = TempRepository::new();
+    let good = repository.commit("version", "0");
+    repository.commit("notes.txt", "tracked notes");
+
+    for version in 1..8 {
+        repository.commit("version", &version.to_string());
+    }
+
+    // The notes are untracked at the bad revision, but tracked by most candidates
+    repository.git(&["rm", "--quiet", "notes.txt"]);
+    repository.git(&["commit", "--quiet", "--message", "Untrack notes"]);
+    fs::write(repository.path().join("notes.txt"), "my notes").unwrap();
+    let opened = Repository::open(repository.path()).unwrap();
+
+    let result = opened.run(
+        &RevRange::new(good.as_str(), "main"),
+        CheckoutMode::InPlace,
+        broken_since(4),
+    );
+
+    assert!(matches!(result, Err(GitError::Command { .. })));
+    assert_eq!(
+        fs::read_to_string(repository.path().join("notes.txt")).unwrap(),
+        "my notes"
+    );
+    assert_eq!(repository.git(&["symbolic-ref", "--short", "HEAD"]), "main");
+}
+
+#[test]
+fn run_in_place_discards_changes_of_predicate() {
+    let (repository, commits) = TempRepository::with_versions(8);
+    let opened = Repository::open(repository.path()).unwrap();
+
+    let outcome = opened
+        .run(
+            &RevRange::new(commits[0].as_str(), "main"),
+            CheckoutMode::InPlace,
+            |commit, path| {
+                let result = broken_since(5)(commit, path);
+                fs::write(path.join("version"), "modified by the predicate").unwrap();
+                result
+            },
+        )
+        .unwrap();
+
+    assert_eq!(outcome.commit.id(), commits[5]);
+    assert_eq!(repository.git(&["symbolic-ref", "--short", "HEAD"]), "main");
+    assert_eq!(version(repository.path()), 7);
+}
+
+#[test]
+fn checkout_restore() {
+    let (repository, commits) = TempRepository::with_versions(4);
+    let opened = Repository::open(repository.path()).unwrap();
+
+    let mut checkout = opened.checkout(CheckoutMode::InPlace).unwrap();
+    checkout.switch(&Commit::new(commits[1].as_str())).unwrap();
+    checkout.restore().unwrap();
+
+    assert_eq!(repository.git(&["symbolic-ref", "--short", "HEAD"]), "main");
+    assert_eq!(version(repository.path()), 3);
+}
+
+#[test]
+fn checkout_restore_err() {
+    let (repository, commits) = TempRepository::with_versions(4);
+    let worktrees = TempDir::new().unwrap();
+    let worktree = worktrees.path().join("bisect");
+    let opened = Repository::open(repository.path()).unwrap();
+
+    let mut checkout = opened
+        .checkout(CheckoutMode::Worktree(worktree.clone()))
+        .unwrap();
+    checkout.switch(&Commit::new(commits[1].as_str())).unwrap();
+
+    // Removing the worktree behind the back of the checkout makes restoring it fail
+    repository.git(&["worktree", "remove", "--force", worktree.to_str().unwrap()]);
+
+    assert!(matches!(checkout.restore(), Err(GitError::Command { .. })));
+}
+
+#[test]
+fn checkout_switch() {
+    let (repository, commits) = TempRepository::with_versions(4);
+    let opened = Repository::open(repository.path()).unwrap();
+
+    {
+        let mut checkout = opened.checkout(CheckoutMode::InPlace).unwrap();
+        let path = checkout.switch(&Commit::new(commits[1].as_str())).unwrap();
+
+        assert_eq!(version(path), 1);
+    }
+
+    assert_eq!(version(repository.path()), 3);
+}
+
+#[test]
+fn try_run_err() {
+    let (repository, commits) = TempRepository::with_versions(8);
+    let opened = Repository::open(repository.path()).unwrap();
+
+    let result = opened.try_run(
+        &RevRange::new(commits[0].as_str(), "main"),
+        CheckoutMode::InPlace,
+        |_, path| {
+            if version(path) >= 4 {
+                Err("does not build")
+            } else {
+                Ok(ConvergeTo::<(), ()>::Right(()))
+            }
+        },
+    );
+
+    assert!(matches!(
+        result,
+        Err(GitError::Convergence("does not build"))
+    ));
+    assert_eq!(repository.git(&["symbolic-ref", "--short", "HEAD"]), "main");
+}
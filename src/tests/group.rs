@@ -0,0 +1,115 @@
+use super::{super::*, *};
+
+fn contains_any(bad: &[u32]) -> impl FnMut(&[&u32]) -> ConvergeTo<(), ()> + '_ {
+    move |group| {
+        if group.iter().any(|item| bad.contains(item)) {
+            ConvergeTo::Left(())
+        } else {
+            ConvergeTo::Right(())
+        }
+    }
+}
+
+#[yare::parameterized(
+    none = { &[], vec![] },
+    first = { &[1], vec![0] },
+    last = { &[10], vec![9] },
+    two = { &[3, 8], vec![2, 7] },
+    adjacent = { &[5, 6], vec![4, 5] },
+    all = { &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10], (0..10).collect() },
+)]
+fn find_all_finds_every_bad_item(bad: &[u32], expected: Vec<usize>) {
+    let values = input_1_to_10();
+    let tester = GroupTester::new(&values);
+
+    let found = tester.find_all(contains_any(bad));
+
+    assert_eq!(found.indices, expected);
+    assert_eq!(
+        found.items,
+        expected
+            .iter()
+            .map(|&index| &values[index])
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(found.individual_tests, 10);
+}
+
+#[test]
+fn tests_are_counted() {
+    let values = (0..1024).collect::<Vec<u32>>();
+    let tester = GroupTester::new(&values);
+
+    let mut calls = 0;
+    let found = tester.find_all(|group: &[&u32]| {
+        calls += 1;
+        contains_any(&[100, 600, 1023])(group)
+    });
+
+    assert_eq!(found.items, vec![&100, &600, &1023]);
+    assert_eq!(found.tests, calls);
+    // At most two tests per level of splitting, per bad item, plus the complete view
+    assert!(found.tests <= 1 + 3 * 2 * 10);
+    assert_eq!(found.individual_tests, 1024);
+}
+
+#[test]
+fn no_bad_items_takes_one_test() {
+    let values = input_1_to_10();
+    let tester = GroupTester::new(&values);
+
+    let found = tester.find_all(contains_any(&[]));
+
+    assert_eq!(found.tests, 1);
+}
+
+#[test]
+fn single() {
+    let values = input_1();
+    let tester = GroupTester::new(&values);
+
+    let found = tester.find_all(contains_any(&[1]));
+
+    assert_eq!(found.indices, vec![0]);
+    assert_eq!(found.tests, 1);
+}
+
+#[test]
+fn empty() {
+    let values = input_empty();
+    let tester = GroupTester::new(&values);
+
+    let found = tester.find_all(|_: &[&u32]| -> ConvergeTo<(), ()> { unreachable!() });
+
+    assert!(found.indices.is_empty());
+    assert_eq!(found.tests, 0);
+    assert_eq!(found.individual_tests, 0);
+}
+
+#[test]
+fn try_find_all_err() {
+    let values = input_1_to_10();
+    let tester = GroupTester::new(&values);
+
+    let result = tester.try_find_all(|group: &[&u32]| {
+        if group.len() < 10 && group.contains(&&4) {
+            Err(4)
+        } else {
+            Ok(contains_any(&[4])(group))
+        }
+    });
+
+    assert_eq!(result.err(), Some(4));
+}
+
+#[test]
+fn try_find_all_ok() {
+    let values = input_1_to_10();
+    let tester = GroupTester::new(&values);
+
+    let found = tester
+        .try_find_all(|group: &[&u32]| Ok::<_, ()>(contains_any(&[2, 9])(group)))
+        .unwrap();
+
+    assert_eq!(found.items, vec![&2, &9]);
+}
@@ -0,0 +1,218 @@
+use super::super::*;
+
+// A tree of labelled nodes, where nodes are identified by their path of child positions
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Node {
+    label: &'static str,
+    children: Vec<Node>,
+}
+
+fn node(label: &'static str, children: Vec<Node>) -> Node {
+    Node { label, children }
+}
+
+fn leaf(label: &'static str) -> Node {
+    node(label, Vec::new())
+}
+
+impl Node {
+    fn at(&self, path: &[usize]) -> &Node {
+        path.iter().fold(self, |node, &index| &node.children[index])
+    }
+}
+
+impl Tree for Node {
+    type Node = Vec<usize>;
+    type Serialized = String;
+
+    fn root(&self) -> Vec<usize> {
+        Vec::new()
+    }
+
+    fn children(&self, path: &Vec<usize>) -> Vec<Vec<usize>> {
+        (0..self.at(path).children.len())
+            .map(|index| [path.as_slice(), &[index]].concat())
+            .collect()
+    }
+
+    fn remove(&mut self, path: &Vec<usize>) {
+        let (last, parent) = path.split_last().unwrap();
+        let parent = parent
+            .iter()
+            .fold(self, |node, &index| &mut node.children[index]);
+
+        parent.children.remove(*last);
+    }
+
+    fn serialize(&self) -> String {
+        if self.children.is_empty() {
+            return self.label.to_string();
+        }
+
+        let children = self
+            .children
+            .iter()
+            .map(Tree::serialize)
+            .collect::<Vec<_>>();
+
+        format!("{}({})", self.label, children.join(" "))
+    }
+}
+
+// A small program: fn(args(a b) body(let(x) if(cond then(panic)) ret))
+fn program() -> Node {
+    node(
+        "fn",
+        vec![
+            node("args", vec![leaf("a"), leaf("b")]),
+            node(
+                "body",
+                vec![
+                    node("let", vec![leaf("x")]),
+                    node("if", vec![leaf("cond"), node("then", vec![leaf("panic")])]),
+                    leaf("ret"),
+                ],
+            ),
+        ],
+    )
+}
+
+fn fails_when_all(words: &'static [&'static str]) -> impl FnMut(&String) -> Verdict<(), (), ()> {
+    move |serialized| {
+        if words.iter().all(|word| serialized.contains(word)) {
+            Verdict::Left(())
+        } else {
+            Verdict::Right(())
+        }
+    }
+}
+
+#[yare::parameterized(
+    deep_leaf = { &["panic"], "fn(body(if(then(panic))))", 4 },
+    two_branches = { &["panic", "x"], "fn(body(let(x) if(then(panic))))", 4 },
+    shallow = { &["ret"], "fn(body(ret))", 2 },
+    // At least one node of each level is kept
+    top_level = { &["args"], "fn(args(a))", 2 },
+)]
+fn hdd_minimizes_each_level(words: &'static [&'static str], expected: &str, levels: usize) {
+    let mut minimizer = HierarchicalMinimizer::new(program());
+
+    let minimized = minimizer.hdd(fails_when_all(words)).unwrap();
+
+    assert_eq!(minimized.tree.serialize(), expected);
+    assert_eq!(minimized.levels, levels);
+}
+
+#[test]
+fn structure_is_preserved() {
+    let mut minimizer = HierarchicalMinimizer::new(program());
+
+    // Only well-formed documents can be tested: every `if` needs its `cond`
+    let minimized = minimizer
+        .hdd(|serialized: &String| {
+            if serialized.contains("if(") && !serialized.contains("cond") {
+                Verdict::<(), (), ()>::Skip(())
+            } else if serialized.contains("panic") {
+                Verdict::Left(())
+            } else {
+                Verdict::Right(())
+            }
+        })
+        .unwrap();
+
+    assert_eq!(minimized.tree.serialize(), "fn(body(if(cond then(panic))))");
+}
+
+#[test]
+fn original_tree_is_unchanged() {
+    let mut minimizer = HierarchicalMinimizer::new(program());
+
+    let _ = minimizer.hdd(fails_when_all(&["panic"])).unwrap();
+
+    assert_eq!(minimizer.tree(), &program());
+}
+
+#[test]
+fn root_only() {
+    let mut minimizer = HierarchicalMinimizer::new(leaf("fn"));
+
+    let minimized = minimizer.hdd(fails_when_all(&["fn"])).unwrap();
+
+    assert_eq!(minimized.tree, leaf("fn"));
+    assert_eq!(minimized.levels, 0);
+    assert_eq!(minimized.tests, 1);
+}
+
+#[test]
+fn tests_are_counted() {
+    let mut minimizer = HierarchicalMinimizer::new(program());
+
+    let mut calls = 0;
+    let minimized = minimizer
+        .hdd(|serialized: &String| {
+            calls += 1;
+            fails_when_all(&["panic"])(serialized)
+        })
+        .unwrap();
+
+    assert_eq!(minimized.tests, calls);
+    assert_eq!(minimizer.cache().len(), calls - 1);
+}
+
+#[test]
+fn shared_cache() {
+    let mut cache = MemoryCache::new();
+
+    let first = HierarchicalMinimizer::new(program())
+        .with_cache(&mut cache)
+        .hdd(fails_when_all(&["panic", "a"]))
+        .unwrap();
+
+    // Only the complete tree, and the minimized tree for its output, are tested again
+    let second = HierarchicalMinimizer::new(program())
+        .with_cache(&mut cache)
+        .hdd(|serialized: &String| Verdict::<_, (), ()>::Left(serialized.clone()))
+        .unwrap();
+
+    assert_eq!(second.tree, first.tree);
+    assert_eq!(second.output, first.tree.serialize());
+    assert_eq!(second.tests, 2);
+}
+
+#[test]
+fn complete_tree_passes() {
+    let mut minimizer = HierarchicalMinimizer::new(program());
+
+    let result = minimizer.hdd(fails_when_all(&["unreachable"]));
+
+    assert_eq!(result.err(), Some(MinimizeError::Passes(())));
+}
+
+#[test]
+fn try_hdd_err() {
+    let mut minimizer = HierarchicalMinimizer::new(program());
+
+    let result = minimizer.try_hdd(|serialized: &String| {
+        if serialized.contains("args") {
+            Ok(fails_when_all(&["panic"])(serialized))
+        } else {
+            Err("parse error")
+        }
+    });
+
+    assert_eq!(
+        result.err(),
+        Some(MinimizeError::Convergence("parse error"))
+    );
+}
+
+#[test]
+fn try_hdd_ok() {
+    let mut minimizer = HierarchicalMinimizer::new(program());
+
+    let minimized = minimizer
+        .try_hdd(|serialized: &String| Ok::<_, ()>(fails_when_all(&["x"])(serialized)))
+        .unwrap();
+
+    assert_eq!(minimized.tree.serialize(), "fn(body(let(x)))");
+}
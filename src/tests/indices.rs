@@ -28,6 +28,40 @@ fn creating_starting_indices_from_bisector_with_empty_slice_panics() {
     let _ = Indices::from_bisector(&bisector);
 }
 
+#[test]
+fn create_starting_indices_from_bisector_with_turbofish() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    assert_eq!(Indices::from_bisector::<u32>(&bisector), Indices::new(0, 9));
+    assert_eq!(
+        Indices::try_from_bisector::<u32>(&bisector),
+        Ok(Indices::new(0, 9))
+    );
+}
+
+#[yare::parameterized(
+    one_to_ten = { input_1_to_10, (0, 9) },
+    one = { input_1, (0, 0) },
+)]
+fn create_starting_indices_from_source(input: fn() -> Vec<u32>, indices_expected: (usize, usize)) {
+    let values = input()
+        .into_iter()
+        .collect::<std::collections::VecDeque<_>>();
+    let bisector = Bisector::from_source(&values);
+
+    let (left_expected, right_expected) = indices_expected;
+
+    assert_eq!(
+        Indices::from_source(&bisector),
+        Indices::new(left_expected, right_expected)
+    );
+    assert_eq!(
+        Indices::try_from_source(&bisector),
+        Ok(Indices::new(left_expected, right_expected))
+    );
+}
+
 #[test]
 fn create_indices_with_new() {
     let indices = Indices::new(0, 1);
@@ -0,0 +1,114 @@
+use super::{super::*, *};
+
+fn converge_at_7(value: &u32) -> ConvergeTo<u32, u32> {
+    if *value >= 7 {
+        ConvergeTo::Left(*value)
+    } else {
+        ConvergeTo::Right(*value)
+    }
+}
+
+#[test]
+fn iter_yields_same_steps_as_bisect_loop() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let mut expected = vec![];
+    let mut i = Indices::from_bisector(&bisector);
+    while let Step {
+        indices,
+        result: Some(_),
+    } = bisector.bisect(converge_at_7, i)
+    {
+        i = indices;
+        expected.push(indices);
+    }
+
+    let actual = bisector
+        .iter(converge_at_7)
+        .map(|step| step.indices)
+        .collect::<Vec<_>>();
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn iter_converges() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let mut iter = bisector.iter(converge_at_7);
+    let last = iter.by_ref().last().unwrap();
+
+    assert_eq!(last.indices, Indices::new(6, 6));
+    assert_eq!(last.result.unwrap().unwrap_converge_right(), 6);
+    assert_eq!(iter.indices(), Some(Indices::new(6, 6)));
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn iter_is_lazy() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let mut calls = 0;
+    let steps = bisector
+        .iter(|value| {
+            calls += 1;
+            converge_at_7(value)
+        })
+        .take(2)
+        .count();
+
+    assert_eq!(steps, 2);
+    assert_eq!(calls, 2);
+}
+
+#[yare::parameterized(
+    one = { input_1 },
+    empty = { input_empty },
+)]
+fn iter_without_steps(input: fn() -> Vec<u32>) {
+    let values = input();
+    let bisector = Bisector::new(&values);
+
+    let mut iter = bisector.iter(converge_at_7);
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn try_iter_ok() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let steps = bisector
+        .try_iter(|value| Ok::<_, ()>(converge_at_7(value)))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(steps.len(), 4);
+    assert_eq!(steps[3].indices, Indices::new(6, 6));
+}
+
+#[test]
+fn try_iter_stops_after_err() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let mut iter = bisector.try_iter(|&value| {
+        if value == 2 {
+            Err(value)
+        } else {
+            Ok(ConvergeTo::<u32, u32>::Left(value))
+        }
+    });
+
+    assert!(iter.next().unwrap().is_ok());
+    assert!(iter.next().unwrap().is_ok());
+    assert_eq!(iter.next().unwrap().err(), Some(2));
+    assert!(iter.next().is_none());
+
+    // The failed step did not progress the indices
+    assert_eq!(iter.indices(), Some(Indices::new(0, 2)));
+}
@@ -0,0 +1,253 @@
+use super::{super::*, *};
+
+// Fails when every one of the given items is part of the configuration
+fn fails_with(culprits: &[u32]) -> impl FnMut(&[&u32]) -> Verdict<(), (), ()> + '_ {
+    move |configuration| {
+        if culprits
+            .iter()
+            .all(|culprit| configuration.contains(&culprit))
+        {
+            Verdict::Left(())
+        } else {
+            Verdict::Right(())
+        }
+    }
+}
+
+#[yare::parameterized(
+    single = { &[4], vec![3] },
+    first = { &[1], vec![0] },
+    last = { &[10], vec![9] },
+    pair = { &[3, 7], vec![2, 6] },
+    adjacent = { &[5, 6], vec![4, 5] },
+    triple = { &[1, 5, 10], vec![0, 4, 9] },
+    everything = { &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10], (0..10).collect() },
+)]
+fn ddmin_finds_minimal_configuration(culprits: &[u32], expected: Vec<usize>) {
+    let values = input_1_to_10();
+    let mut minimizer = Minimizer::new(&values);
+
+    let minimized = minimizer.ddmin(fails_with(culprits)).unwrap();
+
+    assert_eq!(minimized.indices, expected);
+    assert_eq!(
+        minimized.items,
+        expected
+            .iter()
+            .map(|&index| &values[index])
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn empty_configuration_is_never_tested() {
+    let values = input_1_to_10();
+    let mut minimizer = Minimizer::new(&values);
+
+    let minimized = minimizer
+        .ddmin(|configuration: &[&u32]| {
+            assert!(!configuration.is_empty());
+            Verdict::<(), (), ()>::Left(())
+        })
+        .unwrap();
+
+    assert_eq!(minimized.indices, vec![0]);
+}
+
+#[test]
+fn result_is_one_minimal() {
+    let values = (0..64).collect::<Vec<u32>>();
+    let mut minimizer = Minimizer::new(&values);
+
+    // Fails when at least three items divisible by 7 are present
+    let mut f = |configuration: &[&u32]| {
+        if configuration
+            .iter()
+            .filter(|&&&value| value % 7 == 0)
+            .count()
+            >= 3
+        {
+            Verdict::<(), (), ()>::Left(())
+        } else {
+            Verdict::Right(())
+        }
+    };
+
+    let minimized = minimizer.ddmin(&mut f).unwrap();
+
+    assert_eq!(minimized.items.len(), 3);
+
+    for removed in 0..minimized.items.len() {
+        let mut configuration = minimized.items.clone();
+        configuration.remove(removed);
+
+        assert!(f(&configuration).try_into_right().is_some());
+    }
+}
+
+#[test]
+fn unresolved_configurations_are_not_adopted() {
+    let values = input_1_to_10();
+    let mut minimizer = Minimizer::new(&values);
+
+    // Configurations without item 1 can't be tested
+    let minimized = minimizer
+        .ddmin(|configuration: &[&u32]| {
+            if !configuration.contains(&&1) {
+                Verdict::<(), (), ()>::Skip(())
+            } else if configuration.contains(&&8) {
+                Verdict::Left(())
+            } else {
+                Verdict::Right(())
+            }
+        })
+        .unwrap();
+
+    assert_eq!(minimized.items, vec![&1, &8]);
+}
+
+#[test]
+fn output_of_most_recent_failure() {
+    let values = input_1_to_10();
+    let mut minimizer = Minimizer::new(&values);
+
+    let minimized = minimizer
+        .ddmin(|configuration: &[&u32]| {
+            if configuration.contains(&&6) {
+                Verdict::<usize, (), ()>::Left(configuration.len())
+            } else {
+                Verdict::Right(())
+            }
+        })
+        .unwrap();
+
+    assert_eq!(minimized.items, vec![&6]);
+    assert_eq!(minimized.output, 1);
+}
+
+#[test]
+fn every_configuration_is_tested_at_most_once() {
+    let values = (0..32).collect::<Vec<u32>>();
+    let mut minimizer = Minimizer::new(&values);
+
+    let mut tested = Vec::new();
+    let minimized = minimizer
+        .ddmin(|configuration: &[&u32]| {
+            tested.push(configuration.to_vec());
+            fails_with(&[3, 17, 30])(configuration)
+        })
+        .unwrap();
+
+    assert_eq!(minimized.tests, tested.len());
+
+    let mut unique = tested.clone();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(unique.len(), tested.len());
+
+    assert_eq!(minimizer.cache().len(), tested.len());
+}
+
+#[test]
+fn shared_cache() {
+    let values = input_1_to_10();
+    let mut cache = MemoryCache::new();
+
+    let first = Minimizer::new(&values)
+        .with_cache(&mut cache)
+        .ddmin(fails_with(&[2, 9]))
+        .unwrap();
+
+    let cached = cache.len();
+
+    // Only the complete view, and the minimal configuration for its output, are tested again
+    let second = Minimizer::new(&values)
+        .with_cache(&mut cache)
+        .ddmin(|configuration: &[&u32]| {
+            Verdict::<_, (), ()>::Left(
+                configuration
+                    .iter()
+                    .map(|&&value| value)
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .unwrap();
+
+    assert_eq!(second.indices, first.indices);
+    assert_eq!(second.output, vec![2, 9]);
+    assert_eq!(second.tests, 2);
+    assert!(second.cache_hits > 0);
+    assert_eq!(cache.len(), cached);
+}
+
+#[test]
+fn cached_minimal_configuration_no_longer_fails() {
+    let values = input_1_to_10();
+    let mut cache = MemoryCache::new();
+
+    Minimizer::new(&values)
+        .with_cache(&mut cache)
+        .ddmin(fails_with(&[2, 9]))
+        .unwrap();
+
+    let result =
+        Minimizer::new(&values)
+            .with_cache(&mut cache)
+            .ddmin(|configuration: &[&u32]| {
+                if configuration.len() == values.len() {
+                    Verdict::<(), u32, ()>::Left(())
+                } else {
+                    Verdict::Right(2)
+                }
+            });
+
+    assert_eq!(result.err(), Some(MinimizeError::Passes(2)));
+}
+
+#[test]
+fn complete_view_passes() {
+    let values = input_1_to_10();
+    let mut minimizer = Minimizer::new(&values);
+
+    let result = minimizer.ddmin(fails_with(&[11]));
+
+    assert_eq!(result.err(), Some(MinimizeError::Passes(())));
+}
+
+#[test]
+fn complete_view_unresolved() {
+    let values = input_1_to_10();
+    let mut minimizer = Minimizer::new(&values);
+
+    let result = minimizer.ddmin(|_| Verdict::<(), (), u32>::Skip(125));
+
+    assert_eq!(result.err(), Some(MinimizeError::Unresolved(125)));
+}
+
+#[test]
+fn try_ddmin_err() {
+    let values = input_1_to_10();
+    let mut minimizer = Minimizer::new(&values);
+
+    let result = minimizer.try_ddmin(|configuration: &[&u32]| {
+        if configuration.len() < 5 {
+            Err("timeout")
+        } else {
+            Ok(fails_with(&[4])(configuration))
+        }
+    });
+
+    assert_eq!(result.err(), Some(MinimizeError::Convergence("timeout")));
+}
+
+#[test]
+fn try_ddmin_ok() {
+    let values = input_1_to_10();
+    let mut minimizer = Minimizer::new(&values);
+
+    let minimized = minimizer
+        .try_ddmin(|configuration: &[&u32]| Ok::<_, ()>(fails_with(&[4, 5])(configuration)))
+        .unwrap();
+
+    assert_eq!(minimized.items, vec![&4, &5]);
+}
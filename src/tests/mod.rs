@@ -1,12 +1,41 @@
 use crate::ConvergeTo;
 
+#[cfg(feature = "async")]
+mod asynchronous;
 mod bisect;
 mod bisect_complex;
+#[cfg(feature = "git")]
+mod bisect_log;
+mod change_points;
+#[cfg(feature = "git")]
+mod confirm;
+mod dag;
 
 #[cfg(feature = "testing_external_program_ewc")]
 mod external_program;
+mod gallop;
+#[cfg(feature = "git")]
+mod git;
+mod group;
+mod hierarchical;
 mod indices;
+mod iter;
+mod minimize;
+mod multisect;
+mod noisy;
+mod numeric;
+mod range;
+mod run;
+mod skip;
+mod source;
+mod speculative;
+mod transitions;
 mod try_bisect;
+mod validate;
+mod vote;
+mod window;
+#[cfg(feature = "git")]
+mod worktree_pool;
 
 pub(in crate::tests) fn input_1_to_10() -> Vec<u32> {
     (1..=10).collect()
@@ -20,6 +49,19 @@ pub(in crate::tests) fn input_empty() -> Vec<u32> {
     vec![]
 }
 
+// A convergence function which converges to the left for values from the given boundary onwards.
+pub(in crate::tests) fn converge_at(
+    boundary: u32,
+) -> impl Fn(&u32) -> ConvergeTo<u32, u32> + Copy + Sync {
+    move |&value| {
+        if value >= boundary {
+            ConvergeTo::Left(value)
+        } else {
+            ConvergeTo::Right(value)
+        }
+    }
+}
+
 // A helper trait, since our ConvergeTo<L, R> struct does not implement debug, as we don't want to
 // force L: Debug and R: Debug on implementers.
 trait UnwrapConvergeTo<L, R> {
@@ -0,0 +1,178 @@
+use super::{super::*, *};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+fn multisect_to_end(bisector: &Bisector<u32>, k: usize, boundary: u32) -> (Indices, usize) {
+    let mut indices = Indices::from_bisector(bisector);
+    let mut steps = 0;
+
+    loop {
+        let step = bisector.multisect(k, converge_at(boundary), indices);
+
+        if step.probes.is_empty() {
+            return (indices, steps);
+        }
+
+        indices = step.indices;
+        steps += 1;
+    }
+}
+
+#[yare::parameterized(
+    k1_first = { 1, 1 },
+    k1_middle = { 1, 5 },
+    k1_last = { 1, 10 },
+    k3_first = { 3, 1 },
+    k3_middle = { 3, 5 },
+    k3_last = { 3, 10 },
+    k7_first = { 7, 1 },
+    k7_seventh = { 7, 7 },
+    k7_last = { 7, 10 },
+    k100_middle = { 100, 5 },
+)]
+fn multisect_converges_like_bisect(k: usize, boundary: u32) {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let expected = bisector.run(converge_at(boundary)).unwrap();
+    let (indices, _) = multisect_to_end(&bisector, k, boundary);
+
+    assert_eq!(indices, Indices::new(expected.index, expected.index));
+}
+
+#[test]
+fn multisect_with_k_1_is_bisect() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+    let start_from = Indices::from_bisector(&bisector);
+
+    let expected = bisector.bisect(converge_at(7), start_from);
+    let step = bisector.multisect(1, converge_at(7), start_from);
+
+    assert_eq!(step.indices, expected.indices);
+    assert_eq!(step.probes.len(), 1);
+    assert_eq!(step.probes[0].index, 4);
+}
+
+#[test]
+fn multisect_takes_fewer_steps() {
+    let values = (0..1024).collect::<Vec<u32>>();
+    let bisector = Bisector::new(&values);
+
+    let (_, bisect_steps) = multisect_to_end(&bisector, 1, 700);
+    let (indices, multisect_steps) = multisect_to_end(&bisector, 7, 700);
+
+    assert_eq!(indices, Indices::new(700, 700));
+    assert_eq!(bisect_steps, 10);
+    assert!(multisect_steps <= 4);
+}
+
+#[test]
+fn multisect_evaluates_split_points() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+    let calls = AtomicUsize::new(0);
+
+    let step = bisector.multisect(
+        2,
+        |value| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            converge_at(7)(value)
+        },
+        Indices::from_bisector(&bisector),
+    );
+
+    let probed = step.probes.iter().map(|p| p.index).collect::<Vec<_>>();
+
+    assert_eq!(probed, vec![3, 6]);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert_eq!(step.indices, Indices::new(4, 6));
+    let probe = step.probes.into_iter().nth(1).unwrap();
+    assert_eq!(probe.result.unwrap_converge_left(), 7);
+}
+
+#[test]
+fn multisect_clamps_k_to_view() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let step = bisector.multisect(
+        usize::MAX,
+        converge_at(7),
+        Indices::from_bisector(&bisector),
+    );
+
+    // Every element but the last is a split point
+    let probed = step.probes.iter().map(|p| p.index).collect::<Vec<_>>();
+
+    assert_eq!(probed, (0..9).collect::<Vec<_>>());
+    assert_eq!(step.indices, Indices::new(6, 6));
+}
+
+#[test]
+fn multisect_threads_are_bounded() {
+    let values = (0..1000).collect::<Vec<u32>>();
+    let bisector = Bisector::new(&values);
+    let threads = std::sync::Mutex::new(std::collections::HashSet::new());
+
+    let step = bisector.multisect(
+        256,
+        |value| {
+            threads.lock().unwrap().insert(std::thread::current().id());
+            converge_at(500)(value)
+        },
+        Indices::from_bisector(&bisector),
+    );
+
+    let available = std::thread::available_parallelism().map_or(1, |n| n.get());
+
+    assert_eq!(step.probes.len(), 256);
+    assert!(threads.lock().unwrap().len() <= available);
+}
+
+#[test]
+fn multisect_when_converged() {
+    let values = input_1();
+    let bisector = Bisector::new(&values);
+
+    let step = bisector.multisect(4, converge_at(1), Indices::from_bisector(&bisector));
+
+    assert!(step.probes.is_empty());
+    assert_eq!(step.indices, Indices::new(0, 0));
+}
+
+#[test]
+fn try_multisect_ok() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+    let f = converge_at(2);
+
+    let step = bisector
+        .try_multisect(
+            3,
+            |value| Ok::<_, ()>(f(value)),
+            Indices::from_bisector(&bisector),
+        )
+        .unwrap();
+
+    assert_eq!(step.indices, Indices::new(0, 2));
+}
+
+#[test]
+fn try_multisect_returns_lowest_err() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let step = bisector.try_multisect(
+        3,
+        |&value| {
+            if value > 3 {
+                Err(value)
+            } else {
+                Ok(ConvergeTo::<u32, u32>::Right(value))
+            }
+        },
+        Indices::from_bisector(&bisector),
+    );
+
+    assert_eq!(step.err(), Some(5));
+}
@@ -0,0 +1,203 @@
+use super::{super::*, *};
+
+#[yare::parameterized(
+    negative = { -0.1, 0.1 },
+    half = { 0.1, 0.5 },
+    more_than_half = { 0.9, 0.1 },
+    nan = { f64::NAN, 0.1 },
+)]
+fn invalid_noise(false_positive: f64, false_negative: f64) {
+    assert_eq!(
+        Noise::new(false_positive, false_negative),
+        Err(InvalidNoiseError)
+    );
+}
+
+#[test]
+fn posterior_starts_uniform() {
+    let values = input_1_to_10();
+    let bisector = NoisyBisector::new(&values, Noise::new(0.1, 0.1).unwrap());
+
+    let posterior = Posterior::try_from_bisector(&bisector).unwrap();
+
+    assert!(posterior.probabilities().iter().all(|&p| p == 0.1));
+}
+
+#[test]
+fn posterior_of_empty_view() {
+    let values = input_empty();
+    let bisector = NoisyBisector::new(&values, Noise::new(0.1, 0.1).unwrap());
+
+    assert_eq!(
+        Posterior::try_from_bisector(&bisector),
+        Err(EmptySliceError)
+    );
+}
+
+#[test]
+fn step_probes_median_and_updates_posterior() {
+    let values = input_1_to_10();
+    let bisector = NoisyBisector::new(&values, Noise::new(0.2, 0.2).unwrap());
+
+    let posterior = Posterior::try_from_bisector(&bisector).unwrap();
+    let step = bisector.bisect(converge_at(3), posterior);
+
+    let (probe, result) = step.result.unwrap();
+    assert_eq!(probe, 4);
+    assert_eq!(result.unwrap_converge_left(), 5);
+
+    // Mass moved towards the indices at or before the probe
+    let probabilities = step.posterior.probabilities();
+    assert!((probabilities.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+    assert!((probabilities[0] - 0.16).abs() < 1e-9);
+    assert!((probabilities[9] - 0.04).abs() < 1e-9);
+}
+
+#[yare::parameterized(
+    first = { 1, 0 },
+    middle = { 5, 4 },
+    last = { 10, 9 },
+)]
+fn noiseless_run_behaves_like_bisect(boundary: u32, expected: usize) {
+    let values = input_1_to_10();
+    let bisector = NoisyBisector::new(&values, Noise::new(0.0, 0.0).unwrap());
+
+    let outcome = bisector.run(converge_at(boundary)).unwrap();
+
+    assert!(outcome.converged);
+    assert_eq!(outcome.index, expected);
+    assert_eq!(outcome.confidence, 1.0);
+    assert!(outcome.probes <= 4);
+}
+
+#[test]
+fn noisy_run_recovers_from_wrong_verdicts() {
+    let values = (0..1000).collect::<Vec<u32>>();
+    let bisector = NoisyBisector::new(&values, Noise::new(0.2, 0.2).unwrap()).with_confidence(0.99);
+
+    // The first probe converges to the wrong side, and after that every fourth probe
+    let f = converge_at(321);
+    let mut probes = 0;
+
+    let outcome = bisector
+        .run(|value| {
+            probes += 1;
+
+            match (f(value), probes % 4 == 1) {
+                (ConvergeTo::Left(l), true) => ConvergeTo::Right(l),
+                (ConvergeTo::Right(r), true) => ConvergeTo::Left(r),
+                (result, false) => result,
+            }
+        })
+        .unwrap();
+
+    assert!(outcome.converged);
+    assert_eq!(outcome.index, 321);
+    assert!(outcome.confidence >= 0.99);
+    assert_eq!(outcome.posterior.probabilities().len(), 1000);
+}
+
+#[test]
+fn run_stops_at_max_probes() {
+    let values = (0..1000).collect::<Vec<u32>>();
+    let bisector = NoisyBisector::new(&values, Noise::new(0.3, 0.3).unwrap()).with_max_probes(3);
+
+    let outcome = bisector.run(converge_at(500)).unwrap();
+
+    assert!(!outcome.converged);
+    assert_eq!(outcome.probes, 3);
+}
+
+#[test]
+fn run_one() {
+    let values = input_1();
+    let bisector = NoisyBisector::new(&values, Noise::new(0.1, 0.1).unwrap());
+
+    let outcome = bisector.run(converge_at(1)).unwrap();
+
+    assert!(outcome.converged);
+    assert_eq!(outcome.index, 0);
+    assert_eq!(outcome.probes, 0);
+}
+
+#[test]
+fn run_empty() {
+    let values = input_empty();
+    let bisector = NoisyBisector::new(&values, Noise::new(0.1, 0.1).unwrap());
+
+    assert_eq!(bisector.run(converge_at(1)).err(), Some(EmptySliceError));
+}
+
+#[yare::parameterized(
+    zero = { 0.0 },
+    one = { 1.0 },
+    more_than_one = { 1.5 },
+    negative = { -0.5 },
+    nan = { f64::NAN },
+)]
+#[should_panic]
+fn invalid_confidence(confidence: f64) {
+    let values = input_1_to_10();
+    let _ = NoisyBisector::new(&values, Noise::new(0.1, 0.1).unwrap()).with_confidence(confidence);
+}
+
+#[yare::parameterized(
+    shorter = { 5 },
+    longer = { 20 },
+)]
+#[should_panic(expected = "the posterior must hold a probability for each element of the view")]
+fn bisect_with_posterior_of_other_view(len: u32) {
+    let values = input_1_to_10();
+    let other = (1..=len).collect::<Vec<u32>>();
+    let noise = Noise::new(0.1, 0.1).unwrap();
+
+    let posterior = Posterior::try_from_bisector(&NoisyBisector::new(&other, noise)).unwrap();
+    let _ = NoisyBisector::new(&values, noise).bisect(converge_at(3), posterior);
+}
+
+#[yare::parameterized(
+    noiseless = { 0.0, 0.0 },
+    one_sided = { 0.0, 0.3 },
+    noisy = { 0.3, 0.3 },
+    very_noisy = { 0.45, 0.45 },
+)]
+fn run_with_contradicting_function_stops(false_positive: f64, false_negative: f64) {
+    let values = (0..100).collect::<Vec<u32>>();
+    let bisector = NoisyBisector::new(&values, Noise::new(false_positive, false_negative).unwrap())
+        .with_confidence(0.999);
+
+    // Alternates between both sides, regardless of the probed element
+    let mut calls = 0;
+    let outcome = bisector
+        .run(|_| {
+            calls += 1;
+
+            if calls % 2 == 0 {
+                ConvergeTo::Left(())
+            } else {
+                ConvergeTo::<(), ()>::Right(())
+            }
+        })
+        .unwrap();
+
+    assert!(outcome.probes <= bisector.max_probes());
+    assert_eq!(outcome.probes, calls);
+}
+
+#[test]
+fn default_max_probes_grows_with_noise() {
+    let values = (0..1024).collect::<Vec<u32>>();
+    let max_probes =
+        |rate: f64| NoisyBisector::new(&values, Noise::new(rate, rate).unwrap()).max_probes();
+
+    // log2(1024) + log2(1 / 0.05) bits, four times over
+    assert_eq!(max_probes(0.0), 58);
+    assert!(max_probes(0.1) > max_probes(0.0));
+    assert!(max_probes(0.4) > max_probes(0.1));
+    assert_eq!(
+        NoisyBisector::new(&values, Noise::new(0.4, 0.4).unwrap())
+            .with_max_probes(7)
+            .max_probes(),
+        7
+    );
+}
@@ -0,0 +1,285 @@
+use super::super::*;
+
+#[yare::parameterized(
+    sqrt_2 = { |x: f64| x * x - 2.0, 0.0, 2.0, std::f64::consts::SQRT_2 },
+    cube = { |x: f64| x * x * x + 8.0, -5.0, 1.0, -2.0 },
+    cos = { |x: f64| x.cos(), 0.0, 3.0, std::f64::consts::FRAC_PI_2 },
+    decreasing = { |x: f64| 1.0 - x, 0.5, 3.0, 1.0 },
+    reversed_interval = { |x: f64| x - 0.25, 1.0, 0.0, 0.25 },
+)]
+fn find_root(f: fn(f64) -> f64, a: f64, b: f64, expected: f64) {
+    let bisector = ContinuousBisector::new(a, b).with_tolerance(Tolerance::absolute(1e-10));
+    let root = bisector.find_root(f).unwrap();
+
+    assert!((root.estimate - expected).abs() <= 1e-10);
+    assert!(root.bracket.left <= expected && expected <= root.bracket.right);
+    assert!([StopReason::Tolerance, StopReason::Exact].contains(&root.reason));
+}
+
+#[test]
+fn exact_midpoint() {
+    let root = ContinuousBisector::new(0.0, 4.0)
+        .find_root(|x| x - 2.0)
+        .unwrap();
+
+    assert_eq!(root.estimate, 2.0);
+    assert_eq!(root.bracket, Bracket::new(2.0, 2.0));
+    assert_eq!(root.iterations, 1);
+    assert_eq!(root.reason, StopReason::Exact);
+}
+
+#[test]
+fn exact_endpoint() {
+    let root = ContinuousBisector::new(0.0, 4.0)
+        .find_root(|x| x - 4.0)
+        .unwrap();
+
+    assert_eq!(root.estimate, 4.0);
+    assert_eq!(root.iterations, 0);
+    assert_eq!(root.reason, StopReason::Exact);
+}
+
+#[yare::parameterized(
+    both_positive = { |x: f64| x * x + 1.0 },
+    both_negative = { |x: f64| -x * x - 1.0 },
+    nan = { |_: f64| f64::NAN },
+)]
+fn not_bracketed(f: fn(f64) -> f64) {
+    let result = ContinuousBisector::new(-1.0, 1.0).find_root(f);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn not_bracketed_reports_endpoint_values() {
+    let result = ContinuousBisector::new(1.0, 3.0).find_root(|x| x);
+
+    assert_eq!(
+        result.unwrap_err(),
+        NotBracketedError {
+            left: 1.0,
+            right: 3.0,
+        }
+    );
+}
+
+#[test]
+fn max_iterations() {
+    let root = ContinuousBisector::new(0.0, 1.0)
+        .with_tolerance(Tolerance::absolute(0.0))
+        .with_max_iterations(10)
+        .find_root(|x| x - 1.0 / 3.0)
+        .unwrap();
+
+    assert_eq!(root.iterations, 10);
+    assert_eq!(root.reason, StopReason::MaxIterations);
+    assert_eq!(root.bracket.width(), 1.0 / 1024.0);
+}
+
+#[test]
+fn precision() {
+    let root = ContinuousBisector::new(1.0, 2.0)
+        .with_tolerance(Tolerance::absolute(0.0))
+        .with_max_iterations(10_000)
+        .find_root(|x| x * x - 2.0)
+        .unwrap();
+
+    assert_eq!(root.reason, StopReason::Precision);
+    assert!(root.bracket.left < root.bracket.right);
+    assert!(root.iterations < 10_000);
+}
+
+#[test]
+fn relative_tolerance() {
+    let root = ContinuousBisector::new(1e9, 1e10)
+        .with_tolerance(Tolerance::relative(1e-6))
+        .find_root(|x| x - 3e9 - 0.123)
+        .unwrap();
+
+    assert_eq!(root.reason, StopReason::Tolerance);
+    assert!(root.bracket.width() <= 1e-6 * root.estimate);
+    assert!((root.estimate - 3e9).abs() <= 1e-6 * 3e9);
+}
+
+#[test]
+fn bisect_step() {
+    let bisector = ContinuousBisector::new(0.0, 8.0);
+
+    let step = bisector.bisect(ConvergeTo::<f64, f64>::Left, bisector.bracket());
+    assert_eq!(step.bracket, Bracket::new(0.0, 4.0));
+
+    let step = bisector.bisect(ConvergeTo::<f64, f64>::Right, step.bracket);
+    assert_eq!(step.bracket, Bracket::new(2.0, 4.0));
+
+    match step.result {
+        Some(ConvergeTo::Right(x)) => assert_eq!(x, 2.0),
+        _ => panic!("Expected right"),
+    }
+}
+
+#[test]
+fn bisect_converged() {
+    let bisector = ContinuousBisector::new(0.0, 8.0).with_tolerance(Tolerance::absolute(1.0));
+    let bracket = Bracket::new(3.0, 4.0);
+
+    let step = bisector.bisect(|_| -> ConvergeTo<(), ()> { unreachable!() }, bracket);
+
+    assert_eq!(step.bracket, bracket);
+    assert!(step.result.is_none());
+}
+
+#[test]
+fn run_with_predicate() {
+    let root = ContinuousBisector::new(0.0, 100.0)
+        .with_tolerance(Tolerance::absolute(1e-3))
+        .run(|x| {
+            if x >= 42.5 {
+                ConvergeTo::Left(())
+            } else {
+                ConvergeTo::Right(())
+            }
+        });
+
+    assert!((root.estimate - 42.5).abs() <= 1e-3);
+    assert_eq!(root.reason, StopReason::Tolerance);
+    assert_eq!(root.iterations, 17);
+}
+
+#[yare::parameterized(
+    bisection_sqrt_2 = { Method::Bisection, |x: f64| x * x - 2.0, 0.0, 2.0, std::f64::consts::SQRT_2, StopReason::Tolerance },
+    false_position_sqrt_2 = { Method::FalsePosition, |x: f64| x * x - 2.0, 0.0, 2.0, std::f64::consts::SQRT_2, StopReason::EstimatesConverged },
+    illinois_sqrt_2 = { Method::Illinois, |x: f64| x * x - 2.0, 0.0, 2.0, std::f64::consts::SQRT_2, StopReason::EstimatesConverged },
+    brent_sqrt_2 = { Method::Brent, |x: f64| x * x - 2.0, 0.0, 2.0, std::f64::consts::SQRT_2, StopReason::Tolerance },
+    false_position_cos = { Method::FalsePosition, |x: f64| x.cos(), 0.0, 3.0, std::f64::consts::FRAC_PI_2, StopReason::EstimatesConverged },
+    illinois_cos = { Method::Illinois, |x: f64| x.cos(), 0.0, 3.0, std::f64::consts::FRAC_PI_2, StopReason::EstimatesConverged },
+    brent_cos = { Method::Brent, |x: f64| x.cos(), 0.0, 3.0, std::f64::consts::FRAC_PI_2, StopReason::Tolerance },
+    illinois_cube = { Method::Illinois, |x: f64| x * x * x + 8.0, -5.0, 1.0, -2.0, StopReason::Exact },
+    brent_cube = { Method::Brent, |x: f64| x * x * x + 8.0, -5.0, 1.0, -2.0, StopReason::Tolerance },
+    brent_decreasing = { Method::Brent, |x: f64| 1.0 - x * x, 0.0, 3.0, 1.0, StopReason::Tolerance },
+)]
+fn find_root_with_method(
+    method: Method,
+    f: fn(f64) -> f64,
+    a: f64,
+    b: f64,
+    expected: f64,
+    reason: StopReason,
+) {
+    let bisector = ContinuousBisector::new(a, b)
+        .with_tolerance(Tolerance::absolute(1e-10))
+        .with_method(method);
+    let root = bisector.find_root(f).unwrap();
+
+    assert!((root.estimate - expected).abs() <= 1e-6);
+    assert!(root.bracket.left <= root.estimate && root.estimate <= root.bracket.right);
+    assert_eq!(root.reason, reason);
+}
+
+#[test]
+fn faster_methods_take_fewer_iterations() {
+    let iterations = |method| {
+        ContinuousBisector::new(0.0, 3.0)
+            .with_tolerance(Tolerance::absolute(1e-12))
+            .with_method(method)
+            .find_root(|x| x.exp() - 10.0)
+            .unwrap()
+            .iterations
+    };
+
+    let bisection = iterations(Method::Bisection);
+
+    assert!(iterations(Method::Illinois) < bisection);
+    assert!(iterations(Method::Brent) < bisection);
+}
+
+#[test]
+fn illinois_shrinks_bracket_from_both_sides() {
+    // The false position method retains the right endpoint of this convex function forever
+    let bracket = |method| {
+        ContinuousBisector::new(0.0, 2.0)
+            .with_tolerance(Tolerance::absolute(1e-12))
+            .with_method(method)
+            .find_root(|x| x.powi(4) - 1.0)
+            .unwrap()
+            .bracket
+    };
+
+    assert_eq!(bracket(Method::FalsePosition).right, 2.0);
+    assert!(bracket(Method::Illinois).width() < 1e-6);
+}
+
+#[test]
+fn brent_stops_on_its_own_tolerance() {
+    // Brent's tolerance also accounts for the rounding of the estimate, so the bracket may be
+    // slightly wider than the absolute tolerance when it stops, long before the iteration cap
+    let root = ContinuousBisector::new(1e6, 2e6)
+        .with_tolerance(Tolerance::absolute(1e-9))
+        .with_method(Method::Brent)
+        .find_root(|x| x * x - 2e12)
+        .unwrap();
+
+    assert!((root.estimate - 2f64.sqrt() * 1e6).abs() <= 1e-6);
+    assert!(root.iterations < 100);
+    assert_eq!(root.reason, StopReason::Tolerance);
+}
+
+#[test]
+fn false_position_stops_on_converged_estimates() {
+    // The false position method retains the right endpoint of this convex function forever, so
+    // the bracket stays wide while the estimates converge
+    let root = ContinuousBisector::new(0.0, 2.0)
+        .with_tolerance(Tolerance::absolute(1e-12))
+        .with_method(Method::FalsePosition)
+        .find_root(|x| x.powi(4) - 1.0)
+        .unwrap();
+
+    assert!((root.estimate - 1.0).abs() <= 1e-9);
+    assert!(root.bracket.width() > 0.5);
+    assert_eq!(root.reason, StopReason::EstimatesConverged);
+}
+
+#[yare::parameterized(
+    false_position = { Method::FalsePosition },
+    illinois = { Method::Illinois },
+    brent = { Method::Brent },
+)]
+fn methods_detect_not_bracketed(method: Method) {
+    let result = ContinuousBisector::new(-1.0, 1.0)
+        .with_method(method)
+        .find_root(|x| x * x + 1.0);
+
+    assert!(result.is_err());
+}
+
+#[yare::parameterized(
+    false_position = { Method::FalsePosition },
+    illinois = { Method::Illinois },
+    brent = { Method::Brent },
+)]
+fn methods_max_iterations(method: Method) {
+    let root = ContinuousBisector::new(0.0, 10.0)
+        .with_tolerance(Tolerance::absolute(0.0))
+        .with_max_iterations(2)
+        .with_method(method)
+        .find_root(|x| x.powi(5) - 3.0)
+        .unwrap();
+
+    assert_eq!(root.iterations, 2);
+    assert_eq!(root.reason, StopReason::MaxIterations);
+}
+
+#[yare::parameterized(
+    false_position = { Method::FalsePosition },
+    illinois = { Method::Illinois },
+    brent = { Method::Brent },
+)]
+fn methods_exact(method: Method) {
+    let root = ContinuousBisector::new(0.0, 4.0)
+        .with_method(method)
+        .find_root(|x| x - 1.0)
+        .unwrap();
+
+    assert_eq!(root.estimate, 1.0);
+    assert_eq!(root.bracket, Bracket::new(1.0, 1.0));
+    assert_eq!(root.reason, StopReason::Exact);
+}
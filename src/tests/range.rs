@@ -0,0 +1,194 @@
+use super::{super::*, *};
+use std::ops::Bound;
+
+fn run<I: Integer>(bisector: &RangeBisector<I>, boundary: I) -> (I, usize) {
+    let mut indices = Indices::from_range_bisector(bisector);
+    let mut probes = 0;
+
+    loop {
+        let step = bisector.bisect(
+            |value| {
+                if value >= boundary {
+                    ConvergeTo::Left(value)
+                } else {
+                    ConvergeTo::Right(value)
+                }
+            },
+            indices,
+        );
+
+        if step.result.is_none() {
+            break;
+        }
+
+        indices = step.indices;
+        probes += 1;
+    }
+
+    (bisector.value(indices.left), probes)
+}
+
+#[yare::parameterized(
+    first = { 1 },
+    third = { 3 },
+    middle = { 5 },
+    seventh = { 7 },
+    last = { 10 },
+    none = { 11 },
+)]
+fn range_equals_slice(boundary: u32) {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+    let range_bisector = RangeBisector::new(1..=10u32).unwrap();
+
+    let expected = bisector
+        .iter(|&value| {
+            if value >= boundary {
+                ConvergeTo::Left(value)
+            } else {
+                ConvergeTo::<u32, u32>::Right(value)
+            }
+        })
+        .map(|step| step.indices)
+        .collect::<Vec<_>>();
+
+    let mut indices = Indices::from_range_bisector(&range_bisector);
+    let mut actual = Vec::new();
+
+    loop {
+        let step = range_bisector.bisect(
+            |value| {
+                if value >= boundary {
+                    ConvergeTo::Left(value)
+                } else {
+                    ConvergeTo::<u32, u32>::Right(value)
+                }
+            },
+            indices,
+        );
+
+        if step.result.is_none() {
+            break;
+        }
+
+        indices = step.indices;
+        actual.push(indices);
+    }
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn huge_unsigned_range() {
+    let bisector = RangeBisector::new(0..u64::MAX).unwrap();
+
+    assert_eq!(bisector.first(), 0);
+    assert_eq!(bisector.last(), u64::MAX - 1);
+    assert_eq!(run(&bisector, 1_234_567_890_123), (1_234_567_890_123, 64));
+}
+
+#[test]
+fn full_signed_range() {
+    let bisector = RangeBisector::new(..).unwrap();
+
+    assert_eq!(bisector.first(), i64::MIN);
+    assert_eq!(bisector.last(), i64::MAX);
+    assert_eq!(run(&bisector, -42i64).0, -42);
+    assert_eq!(run(&bisector, i64::MIN).0, i64::MIN);
+    assert_eq!(run(&bisector, i64::MAX).0, i64::MAX);
+}
+
+#[test]
+fn small_signed_range() {
+    let bisector = RangeBisector::new(-100i8..=100).unwrap();
+
+    assert_eq!(
+        Indices::from_range_bisector(&bisector),
+        Indices::new(0, 200)
+    );
+    assert_eq!(run(&bisector, -3).0, -3);
+}
+
+#[test]
+fn excluded_start() {
+    let bisector = RangeBisector::new((Bound::Excluded(5u8), Bound::Included(10))).unwrap();
+
+    assert_eq!(bisector.first(), 6);
+    assert_eq!(bisector.last(), 10);
+}
+
+#[test]
+fn single_value() {
+    let bisector = RangeBisector::new(7u16..8).unwrap();
+    let indices = Indices::from_range_bisector(&bisector);
+
+    assert_eq!(indices, Indices::new(0, 0));
+
+    let step = bisector.bisect(|_| -> ConvergeTo<(), ()> { unreachable!() }, indices);
+
+    assert!(step.result.is_none());
+    assert_eq!(bisector.value(step.indices.left), 7);
+}
+
+#[yare::parameterized(
+    exclusive = { RangeBisector::new(5u32..5) },
+    inverted = { RangeBisector::new((Bound::Included(6u32), Bound::Included(5))) },
+    excluded_end_at_min = { RangeBisector::new(..0u32) },
+    excluded_start_at_max = { RangeBisector::new((Bound::Excluded(u32::MAX), Bound::Unbounded)) },
+)]
+fn empty_range(bisector: Result<RangeBisector<u32>, InvalidRangeError>) {
+    assert_eq!(bisector.unwrap_err(), InvalidRangeError::Empty);
+}
+
+#[test]
+fn too_large_range() {
+    let bisector = RangeBisector::new(0..=u128::MAX);
+
+    assert_eq!(bisector.unwrap_err(), InvalidRangeError::TooLarge);
+}
+
+#[test]
+fn try_bisect_err() {
+    let bisector = RangeBisector::new(0..100u32).unwrap();
+    let indices = Indices::from_range_bisector(&bisector);
+
+    let result = bisector.try_bisect(Err::<ConvergeTo<(), ()>, u32>, indices);
+
+    assert_eq!(result.err(), Some(49));
+}
+
+#[test]
+fn try_bisect_ok() {
+    let bisector = RangeBisector::new(0..100u32).unwrap();
+    let indices = Indices::from_range_bisector(&bisector);
+
+    let step = bisector
+        .try_bisect(
+            |value| Ok::<_, ()>(ConvergeTo::<u32, u32>::Right(value)),
+            indices,
+        )
+        .unwrap();
+
+    assert_eq!(step.indices, Indices::new(50, 99));
+    assert_eq!(step.result.unwrap().unwrap_converge_right(), 49);
+}
+
+#[test]
+fn full_width_inclusive_range_source() {
+    let range = 0..=usize::MAX;
+    let bisector = Bisector::from_source(&range);
+
+    let step = bisector.bisect(
+        |&value| ConvergeTo::<usize, usize>::Right(value),
+        Indices::new(0, usize::MAX),
+    );
+
+    assert_eq!(step.indices, Indices::new(usize::MAX / 2 + 1, usize::MAX));
+    assert_eq!(*Bisectable::get(&range, usize::MAX), usize::MAX);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds of the range")]
+fn inclusive_range_source_out_of_bounds() {
+    Bisectable::get(&(3..=5u8), 3);
+}
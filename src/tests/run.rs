@@ -0,0 +1,114 @@
+use super::{super::*, *};
+
+#[yare::parameterized(
+    first = { 1, 0 },
+    second = { 2, 1 },
+    middle = { 5, 4 },
+    seventh = { 7, 6 },
+    last = { 10, 9 },
+)]
+fn run_finds_boundary(boundary: u32, expected_index: usize) {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let outcome = bisector.run(converge_at(boundary)).unwrap();
+
+    assert_eq!(outcome.index, expected_index);
+    assert_eq!(*outcome.element, boundary);
+}
+
+#[test]
+fn run_reports_witnesses_closest_to_boundary() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let outcome = bisector.run(converge_at(7)).unwrap();
+
+    assert_eq!(outcome.left, Some(7));
+    assert_eq!(outcome.right, Some(6));
+    assert_eq!(outcome.probes, 4);
+}
+
+#[test]
+fn run_all_left() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let outcome = bisector.run(converge_at(0)).unwrap();
+
+    assert_eq!(outcome.index, 0);
+    assert_eq!(outcome.left, Some(1));
+    assert!(outcome.right.is_none());
+}
+
+#[test]
+fn run_all_right() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let outcome = bisector.run(converge_at(100)).unwrap();
+
+    // The last element is never probed, so we converge to it
+    assert_eq!(outcome.index, 9);
+    assert!(outcome.left.is_none());
+    assert_eq!(outcome.right, Some(9));
+}
+
+#[test]
+fn run_one() {
+    let values = input_1();
+    let bisector = Bisector::new(&values);
+
+    let outcome = bisector.run(converge_at(1)).unwrap();
+
+    assert_eq!(outcome.index, 0);
+    assert_eq!(outcome.probes, 0);
+    assert!(outcome.left.is_none());
+    assert!(outcome.right.is_none());
+}
+
+#[test]
+fn run_empty() {
+    let values = input_empty();
+    let bisector = Bisector::new(&values);
+
+    let outcome = bisector.run(converge_at(1));
+
+    assert_eq!(outcome.err(), Some(EmptySliceError));
+}
+
+#[test]
+fn try_run_ok() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+    let f = converge_at(3);
+
+    let outcome = bisector.try_run(|value| Ok::<_, ()>(f(value))).unwrap();
+
+    assert_eq!(outcome.index, 2);
+    assert_eq!(outcome.left, Some(3));
+    assert_eq!(outcome.right, Some(2));
+}
+
+#[test]
+fn try_run_convergence_err() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let outcome = bisector.try_run(|_| Err::<ConvergeTo<u32, u32>, _>("oops"));
+
+    assert_eq!(outcome.err(), Some(TryRunError::Convergence("oops")));
+}
+
+#[test]
+fn try_run_empty() {
+    let values = input_empty();
+    let bisector = Bisector::new(&values);
+
+    let outcome = bisector.try_run(|_| Err::<ConvergeTo<u32, u32>, _>("oops"));
+
+    assert_eq!(
+        outcome.err(),
+        Some(TryRunError::EmptySlice(EmptySliceError))
+    );
+}
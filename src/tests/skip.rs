@@ -0,0 +1,148 @@
+use super::{super::*, *};
+
+// Converges to the left from 7 onwards, but can't test the given values
+fn skipping(skip: &'static [u32]) -> impl FnMut(&u32) -> Verdict<u32, u32, u32> {
+    move |&value| {
+        if skip.contains(&value) {
+            Verdict::Skip(value)
+        } else if value >= 7 {
+            Verdict::Left(value)
+        } else {
+            Verdict::Right(value)
+        }
+    }
+}
+
+#[yare::parameterized(
+    nearest_neighbour = { SkipStrategy::NearestNeighbour },
+    alternating = { SkipStrategy::Alternating },
+    random = { SkipStrategy::Random(42) },
+)]
+fn without_skips_behaves_like_bisect(strategy: SkipStrategy) {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let expected = bisector
+        .iter(|&value| {
+            if value >= 7 {
+                ConvergeTo::Left(value)
+            } else {
+                ConvergeTo::Right(value)
+            }
+        })
+        .map(|step| step.indices)
+        .collect::<Vec<_>>();
+
+    let actual = bisector
+        .iter_skipping(strategy, skipping(&[]))
+        .map(|step| step.indices)
+        .collect::<Vec<_>>();
+
+    assert_eq!(actual, expected);
+}
+
+#[yare::parameterized(
+    nearest_neighbour = { SkipStrategy::NearestNeighbour },
+    alternating = { SkipStrategy::Alternating },
+    random = { SkipStrategy::Random(7) },
+)]
+fn skipped_candidates_are_routed_around(strategy: SkipStrategy) {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let outcome = bisector
+        .run_skipping(strategy, skipping(&[3, 5, 8]))
+        .unwrap();
+
+    assert!(!outcome.is_ambiguous());
+    assert_eq!(outcome.candidates, Indices::new(6, 6));
+    assert_eq!(outcome.elements, &[7]);
+}
+
+#[test]
+fn skipped_step_keeps_indices() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let mut iter = bisector.iter_skipping(SkipStrategy::NearestNeighbour, skipping(&[5]));
+
+    let step = iter.next().unwrap();
+    assert_eq!(step.probe, 4);
+    assert_eq!(step.indices, Indices::new(0, 9));
+    assert_eq!(step.result.try_into_skip(), Some(5));
+
+    // The nearest neighbour, favouring the lower index
+    let step = iter.next().unwrap();
+    assert_eq!(step.probe, 3);
+    assert_eq!(step.indices, Indices::new(4, 9));
+    assert_eq!(step.result.try_into_right(), Some(4));
+
+    assert_eq!(iter.skipped().collect::<Vec<_>>(), vec![4]);
+}
+
+#[test]
+fn alternating_changes_direction() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let probes = bisector
+        .iter_skipping(SkipStrategy::Alternating, |&value| {
+            Verdict::<u32, u32, u32>::Skip(value)
+        })
+        .map(|step| step.probe)
+        .collect::<Vec<_>>();
+
+    // Middle is 4, then the nearest neighbours, alternating between right and left first
+    assert_eq!(&probes[..4], &[4, 5, 3, 6]);
+    assert_eq!(probes.len(), 9);
+}
+
+#[test]
+fn ambiguous_when_all_skipped() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let outcome = bisector
+        .run_skipping(SkipStrategy::NearestNeighbour, skipping(&[5, 6, 7]))
+        .unwrap();
+
+    assert!(outcome.is_ambiguous());
+    assert_eq!(outcome.candidates, Indices::new(4, 7));
+    assert_eq!(outcome.elements, &[5, 6, 7, 8]);
+    assert_eq!(outcome.skipped, vec![4, 5, 6]);
+    assert_eq!(outcome.left, Some(8));
+    assert_eq!(outcome.right, Some(4));
+}
+
+#[test]
+fn random_is_reproducible() {
+    let values = (0..100).collect::<Vec<u32>>();
+    let bisector = Bisector::new(&values);
+
+    let run = |seed| {
+        bisector
+            .iter_skipping(SkipStrategy::Random(seed), |&value| {
+                if value % 3 == 0 {
+                    Verdict::Skip(())
+                } else if value >= 50 {
+                    Verdict::Left(())
+                } else {
+                    Verdict::Right(())
+                }
+            })
+            .map(|step| step.probe)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(run(1), run(1));
+}
+
+#[test]
+fn run_skipping_empty() {
+    let values = input_empty();
+    let bisector = Bisector::new(&values);
+
+    let outcome = bisector.run_skipping(SkipStrategy::NearestNeighbour, skipping(&[]));
+
+    assert_eq!(outcome.err(), Some(EmptySliceError));
+}
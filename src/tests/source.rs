@@ -0,0 +1,186 @@
+use super::{super::*, *};
+use std::collections::VecDeque;
+
+fn steps<S>(bisector: &Bisector<u32, S>, boundary: u32) -> Vec<Indices>
+where
+    S: Bisectable<Item = u32> + ?Sized,
+{
+    bisector
+        .iter(converge_at(boundary))
+        .map(|step| step.indices)
+        .collect()
+}
+
+#[yare::parameterized(
+    first = { 1 },
+    third = { 3 },
+    middle = { 5 },
+    seventh = { 7 },
+    last = { 10 },
+    none = { 11 },
+)]
+fn sources_take_same_steps_as_slice(boundary: u32) {
+    let values = input_1_to_10();
+    let expected = steps(&Bisector::new(&values), boundary);
+
+    let vec = input_1_to_10();
+    assert_eq!(steps(&Bisector::from_source(&vec), boundary), expected);
+
+    let deque = input_1_to_10().into_iter().collect::<VecDeque<_>>();
+    assert_eq!(steps(&Bisector::from_source(&deque), boundary), expected);
+
+    let range = 1..11u32;
+    assert_eq!(steps(&Bisector::from_source(&range), boundary), expected);
+
+    let range_inclusive = 1..=10u32;
+    assert_eq!(
+        steps(&Bisector::from_source(&range_inclusive), boundary),
+        expected
+    );
+
+    let closure = FnSource::new(10, |index| index as u32 + 1);
+    assert_eq!(steps(&Bisector::from_source(&closure), boundary), expected);
+}
+
+#[test]
+fn slice_source_borrows() {
+    let values = input_1_to_10();
+
+    match Bisectable::get(values.as_slice(), 3) {
+        Candidate::Borrowed(value) => assert!(std::ptr::eq(value, &values[3])),
+        Candidate::Owned(_) => panic!("Expected a borrowed candidate"),
+    }
+}
+
+#[test]
+fn fn_source_produces_candidates_on_demand() {
+    let calls = std::cell::Cell::new(0);
+    let source = FnSource::new(1_000_000, |index| {
+        calls.set(calls.get() + 1);
+        index
+    });
+    let bisector = Bisector::from_source(&source);
+
+    let last = bisector
+        .iter(|&value| {
+            if value >= 123_456 {
+                ConvergeTo::Left(())
+            } else {
+                ConvergeTo::Right(())
+            }
+        })
+        .last()
+        .unwrap();
+
+    assert_eq!(last.indices.left, 123_456);
+    assert_eq!(calls.get(), 20);
+}
+
+#[test]
+#[should_panic]
+fn fn_source_out_of_bounds() {
+    let source = FnSource::new(3, |index| index);
+
+    source.get(3);
+}
+
+#[test]
+fn range_source_len() {
+    assert_eq!(Bisectable::len(&(0..0u8)), 0);
+    assert_eq!(Bisectable::len(&std::ops::Range { start: 5, end: 3u8 }), 0);
+    assert_eq!(Bisectable::len(&(0..=255u8)), 256);
+    assert_eq!(Bisectable::len(&(-3..=3i32)), 7);
+    assert!(Bisectable::is_empty(&(1..1i64)));
+}
+
+#[test]
+fn empty_source() {
+    let deque = VecDeque::<u32>::new();
+    let bisector = Bisector::from_source(&deque);
+
+    assert_eq!(
+        Indices::try_from_source(&bisector).unwrap_err(),
+        EmptySliceError
+    );
+    assert!(bisector.iter(converge_at(1)).next().is_none());
+}
+
+#[test]
+fn try_bisect_source() {
+    let source = FnSource::new(10, |index| index as u32 + 1);
+    let bisector = Bisector::from_source(&source);
+
+    let result = bisector.try_bisect(
+        |&value| Err::<ConvergeTo<(), ()>, u32>(value),
+        Indices::from_source(&bisector),
+    );
+
+    assert_eq!(result.err(), Some(5));
+}
+
+#[yare::parameterized(
+    first = { 1 },
+    middle = { 5 },
+    last = { 10 },
+)]
+fn run_source(boundary: u32) {
+    let source = FnSource::new(10, |index| index as u32 + 1);
+    let bisector = Bisector::from_source(&source);
+
+    let outcome = bisector.run(converge_at(boundary)).unwrap();
+    let validated = bisector.run_validated(converge_at(boundary.max(2)));
+
+    assert_eq!(outcome.index, boundary as usize - 1);
+    assert_eq!(*outcome.element, boundary);
+    assert_eq!(*validated.unwrap().element, boundary.max(2));
+}
+
+#[test]
+fn run_skipping_source() {
+    let source = FnSource::new(10, |index| index as u32 + 1);
+    let bisector = Bisector::from_source(&source);
+
+    let outcome = bisector
+        .run_skipping(SkipStrategy::NearestNeighbour, |&value| match value {
+            5 | 6 => Verdict::Skip(()),
+            v if v >= 6 => Verdict::Left(v),
+            v => Verdict::Right(v),
+        })
+        .unwrap();
+
+    assert_eq!(outcome.elements, &[5, 6, 7]);
+}
+
+#[test]
+fn bisect_voting_source() {
+    let source = FnSource::new(10, |index| index as u32 + 1);
+    let bisector = Bisector::from_source(&source);
+
+    let step = bisector.bisect_voting(
+        &Voting::new(3),
+        converge_at(7),
+        Indices::from_source(&bisector),
+    );
+
+    assert_eq!(step.indices, Indices::new(5, 9));
+    assert_eq!(step.votes.right, 3);
+}
+
+#[test]
+fn multisect_and_speculate_source() {
+    let source = FnSource::new(10, |index| index as u32 + 1);
+    let bisector = Bisector::from_source(&source);
+
+    let step = bisector.multisect(
+        2,
+        |&value| converge_at(7)(&value),
+        Indices::from_source(&bisector),
+    );
+    let speculation = bisector
+        .run_speculative(|&value| converge_at(7)(&value))
+        .unwrap();
+
+    // One step divides the 10 candidates into three parts
+    assert_eq!(step.indices, Indices::new(4, 6));
+    assert_eq!(speculation.indices, Indices::new(6, 6));
+}
@@ -0,0 +1,219 @@
+use super::{super::*, *};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+#[yare::parameterized(
+    first = { 1 },
+    third = { 3 },
+    middle = { 5 },
+    seventh = { 7 },
+    last = { 10 },
+    none = { 11 },
+)]
+fn speculative_takes_same_steps_as_iter(boundary: u32) {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let expected = bisector
+        .iter(converge_at(boundary))
+        .map(|step| step.indices)
+        .collect::<Vec<_>>();
+
+    let speculation = bisector.run_speculative(converge_at(boundary)).unwrap();
+    let actual = speculation
+        .steps
+        .iter()
+        .map(|step| step.indices)
+        .collect::<Vec<_>>();
+
+    assert_eq!(actual, expected);
+    assert_eq!(Some(&speculation.indices), expected.last());
+}
+
+#[test]
+fn speculative_reports_discarded_evaluations() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+    let evaluated = Mutex::new(Vec::new());
+
+    let speculation = bisector
+        .run_speculative(|value| {
+            evaluated.lock().unwrap().push(*value);
+            converge_at(7)(value)
+        })
+        .unwrap();
+
+    // Steps: [0, 9] probes 4, [5, 9] probes 7, [5, 7] probes 6, [5, 6] probes 5
+    // Not needed: the middles of [0, 4] (index 2) and [8, 9] (index 8)
+    let discarded = speculation
+        .discarded
+        .iter()
+        .map(|probe| probe.index)
+        .collect::<Vec<_>>();
+
+    assert_eq!(discarded, vec![2, 8]);
+
+    let mut evaluated = evaluated.into_inner().unwrap();
+    evaluated.sort_unstable();
+    assert_eq!(evaluated, vec![3, 5, 6, 7, 8, 9]);
+}
+
+#[test]
+fn speculative_evaluates_concurrently() {
+    let values = (0..16).collect::<Vec<u32>>();
+    let bisector = Bisector::new(&values);
+
+    let sleep = Duration::from_millis(100);
+    let f = converge_at(11);
+
+    let start = std::time::Instant::now();
+    let speculation = bisector
+        .run_speculative(|value| {
+            std::thread::sleep(sleep);
+            f(value)
+        })
+        .unwrap();
+
+    // Sequential evaluation would take 4 * 100ms, while the speculative evaluations of each next
+    // step run concurrently with the current step
+    assert_eq!(speculation.steps.len(), 4);
+    assert!(start.elapsed() < sleep * 4);
+}
+
+#[test]
+fn speculative_one() {
+    let values = input_1();
+    let bisector = Bisector::new(&values);
+
+    let speculation = bisector.run_speculative(converge_at(1)).unwrap();
+
+    assert!(speculation.steps.is_empty());
+    assert!(speculation.discarded.is_empty());
+    assert_eq!(speculation.indices, Indices::new(0, 0));
+}
+
+#[test]
+fn speculative_empty() {
+    let values = input_empty();
+    let bisector = Bisector::new(&values);
+
+    let speculation = bisector.run_speculative(converge_at(1));
+
+    assert_eq!(speculation.err().map(|_| ()), Some(()));
+}
+
+#[test]
+fn try_speculative_ok() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+    let f = converge_at(4);
+
+    let speculation = bisector
+        .try_run_speculative(|value| Ok::<_, ()>(f(value)))
+        .unwrap();
+
+    assert_eq!(speculation.indices, Indices::new(3, 3));
+}
+
+#[test]
+fn try_speculative_err_of_needed_element() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let speculation = bisector.try_run_speculative(|&value| {
+        if value == 5 {
+            Err(value)
+        } else {
+            Ok(ConvergeTo::<u32, u32>::Left(value))
+        }
+    });
+
+    assert_eq!(speculation.err(), Some(TryRunError::Convergence(5)));
+}
+
+#[test]
+fn try_speculative_err_of_discarded_element_is_ignored() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+    let f = converge_at(2);
+
+    // 8 is the middle of the right half of the first step, which is not needed
+    let speculation = bisector
+        .try_run_speculative(|&value| if value == 8 { Err(()) } else { Ok(f(&value)) })
+        .unwrap();
+
+    assert_eq!(speculation.indices, Indices::new(1, 1));
+    assert!(speculation.discarded.iter().all(|probe| probe.index != 7));
+}
+
+#[test]
+fn speculative_panic_of_discarded_element_is_ignored() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+    let f = converge_at(2);
+
+    // 8 is the middle of the right half of the first step, which is not needed
+    let speculation = bisector
+        .run_speculative(|&value| {
+            if value == 8 {
+                panic!("discarded evaluation panicked");
+            }
+
+            f(&value)
+        })
+        .unwrap();
+
+    assert_eq!(speculation.indices, Indices::new(1, 1));
+    assert!(speculation.discarded.iter().all(|probe| probe.index != 7));
+}
+
+#[test]
+fn speculative_panic_of_needed_element_propagates() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+    let f = converge_at(2);
+
+    let result = std::panic::catch_unwind(|| {
+        bisector.run_speculative(|&value| {
+            if value == 5 {
+                panic!("needed evaluation panicked");
+            }
+
+            f(&value)
+        })
+    });
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn speculative_evaluations_are_bounded() {
+    let values = (0..1024).collect::<Vec<u32>>();
+    let bisector = Bisector::new(&values);
+    let f = converge_at(1);
+
+    let running = AtomicUsize::new(0);
+    let most_running = AtomicUsize::new(0);
+
+    // The bisection always converges to the left, probing 511, 255, 127, and so on, so the
+    // speculative evaluations of the right halves are discarded; they're much slower, so without
+    // a bound, they would pile up
+    let speculation = bisector
+        .run_speculative(|&value| {
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            most_running.fetch_max(now, Ordering::SeqCst);
+
+            let needed = (value + 1).is_power_of_two();
+            std::thread::sleep(Duration::from_millis(if needed { 10 } else { 200 }));
+
+            running.fetch_sub(1, Ordering::SeqCst);
+            f(&value)
+        })
+        .unwrap();
+
+    assert_eq!(speculation.indices, Indices::new(1, 1));
+
+    // The current evaluation, and at most four speculative evaluations
+    assert!(most_running.load(Ordering::SeqCst) <= 5);
+}
@@ -0,0 +1,209 @@
+use super::{super::*, *};
+
+// Converges to the left for the values in any of the given (inclusive) ranges
+fn broken_in(ranges: &[(u32, u32)]) -> impl FnMut(&u32) -> ConvergeTo<u32, u32> + '_ {
+    move |&value| {
+        if ranges
+            .iter()
+            .any(|&(start, end)| start <= value && value <= end)
+        {
+            ConvergeTo::Left(value)
+        } else {
+            ConvergeTo::Right(value)
+        }
+    }
+}
+
+fn indices_of(transitions: &Transitions<u32, u32>) -> Vec<usize> {
+    transitions
+        .transitions
+        .iter()
+        .map(|transition| transition.index)
+        .collect()
+}
+
+#[yare::parameterized(
+    monotone = { &[(7, 10)], vec![6] },
+    none = { &[], vec![] },
+    all = { &[(1, 10)], vec![] },
+    come_and_go = { &[(3, 4)], vec![2, 4] },
+    come_and_go_twice = { &[(2, 3), (6, 8)], vec![1, 3, 5, 8] },
+    single_element = { &[(5, 5)], vec![4, 5] },
+    alternating = { &[(2, 2), (4, 4), (6, 6), (8, 8), (10, 10)], vec![1, 2, 3, 4, 5, 6, 7, 8, 9] },
+)]
+fn unlimited_budget_finds_every_transition(ranges: &[(u32, u32)], expected: Vec<usize>) {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let found = bisector.transitions(broken_in(ranges), usize::MAX).unwrap();
+
+    assert_eq!(indices_of(&found), expected);
+    assert!(found.unexplored.is_empty());
+    assert_eq!(found.probes, values.len());
+}
+
+#[test]
+fn witnesses() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let found = bisector
+        .transitions(broken_in(&[(4, 6)]), usize::MAX)
+        .unwrap();
+
+    let mut transitions = found.transitions.into_iter();
+
+    let first = transitions.next().unwrap();
+    assert_eq!(first.index, 3);
+    assert_eq!(first.before.unwrap_converge_right(), 3);
+    assert_eq!(first.after.unwrap_converge_left(), 4);
+
+    let second = transitions.next().unwrap();
+    assert_eq!(second.index, 6);
+    assert_eq!(second.before.unwrap_converge_left(), 6);
+    assert_eq!(second.after.unwrap_converge_right(), 7);
+
+    assert!(transitions.next().is_none());
+}
+
+#[test]
+fn known_transitions_are_explored_first() {
+    let values = (0..1000).collect::<Vec<u32>>();
+    let bisector = Bisector::new(&values);
+
+    // Both endpoints converge to the right, so the first probe is a guess, but once the middle is
+    // known to converge to the left, both transitions are found by bisection.
+    let found = bisector.transitions(broken_in(&[(300, 700)]), 25).unwrap();
+
+    assert_eq!(indices_of(&found), vec![300, 701]);
+    assert!(found.probes <= 25);
+    assert!(found.unexplored.iter().all(|region| !region.has_transition));
+}
+
+#[test]
+fn budget_exhausted() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    // The first, last and middle element all converge to the right, so the transitions at 2 and
+    // 4 are hidden in the first region
+    let found = bisector.transitions(broken_in(&[(3, 4)]), 3).unwrap();
+
+    assert_eq!(found.probes, 3);
+    assert!(found.transitions.is_empty());
+    assert_eq!(
+        found.unexplored,
+        vec![
+            Unexplored {
+                indices: Indices::new(0, 4),
+                has_transition: false,
+            },
+            Unexplored {
+                indices: Indices::new(4, 9),
+                has_transition: false,
+            },
+        ]
+    );
+}
+
+#[test]
+fn endpoints_only() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let found = bisector.transitions(broken_in(&[(10, 10)]), 2).unwrap();
+
+    assert_eq!(found.probes, 2);
+    assert_eq!(
+        found.unexplored,
+        vec![Unexplored {
+            indices: Indices::new(0, 9),
+            has_transition: true,
+        }]
+    );
+}
+
+#[yare::parameterized(
+    zero = { input_1_to_10, 0, 9 },
+    one = { input_1_to_10, 1, 9 },
+    single_zero = { input_1, 0, 0 },
+)]
+fn budget_smaller_than_endpoints(input: fn() -> Vec<u32>, budget: usize, last: usize) {
+    let values = input();
+    let bisector = Bisector::new(&values);
+
+    let found = bisector
+        .transitions(
+            |_| -> ConvergeTo<(), ()> { panic!("nothing should be probed") },
+            budget,
+        )
+        .unwrap();
+
+    assert!(found.transitions.is_empty());
+    assert_eq!(
+        found.unexplored,
+        vec![Unexplored {
+            indices: Indices::new(0, last),
+            has_transition: false,
+        }]
+    );
+    assert_eq!(found.probes, 0);
+}
+
+#[test]
+fn probes_never_exceed_budget() {
+    let values = (1..=100).collect::<Vec<u32>>();
+    let bisector = Bisector::new(&values);
+    let ranges = [(3, 4), (20, 31), (50, 50), (70, 100)];
+
+    for budget in 2..=100 {
+        let found = bisector.transitions(broken_in(&ranges), budget).unwrap();
+
+        assert!(found.probes <= budget);
+
+        if !found.unexplored.is_empty() {
+            assert_eq!(found.probes, budget);
+        }
+    }
+}
+
+#[test]
+fn single() {
+    let values = input_1();
+    let bisector = Bisector::new(&values);
+
+    let found = bisector.transitions(broken_in(&[]), usize::MAX).unwrap();
+
+    assert_eq!(found.probes, 1);
+    assert!(found.transitions.is_empty());
+    assert!(found.unexplored.is_empty());
+}
+
+#[test]
+fn empty() {
+    let values = input_empty();
+    let bisector = Bisector::new(&values);
+
+    let result = bisector.transitions(broken_in(&[]), usize::MAX);
+
+    assert_eq!(result.err(), Some(EmptySliceError));
+}
+
+#[test]
+fn try_transitions_err() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let result = bisector.try_transitions(
+        |&value| {
+            if value == 5 {
+                Err(value)
+            } else {
+                Ok(ConvergeTo::<(), ()>::Right(()))
+            }
+        },
+        usize::MAX,
+    );
+
+    assert_eq!(result.err(), Some(TryRunError::Convergence(5)));
+}
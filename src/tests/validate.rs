@@ -0,0 +1,139 @@
+use super::{super::*, *};
+
+#[yare::parameterized(
+    second = { 2 },
+    third = { 3 },
+    middle = { 5 },
+    seventh = { 7 },
+    last = { 10 },
+)]
+fn run_validated_finds_same_boundary_as_run(boundary: u32) {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let expected = bisector.run(converge_at(boundary)).unwrap();
+    let outcome = bisector.run_validated(converge_at(boundary)).unwrap();
+
+    assert_eq!(outcome.index, expected.index);
+    assert_eq!(*outcome.element, boundary);
+}
+
+#[test]
+fn endpoint_probes_count_toward_outcome() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let mut calls = 0;
+    let outcome = bisector
+        .run_validated(|&value| {
+            calls += 1;
+            converge_at(7)(&value)
+        })
+        .unwrap();
+
+    assert_eq!(outcome.probes, calls);
+    assert_eq!(outcome.left, Some(7));
+    assert_eq!(outcome.right, Some(6));
+}
+
+#[test]
+fn endpoints_are_witnesses_when_nothing_closer_was_probed() {
+    let values = vec![1, 2];
+    let bisector = Bisector::new(&values);
+
+    let outcome = bisector.run_validated(converge_at(2)).unwrap();
+
+    assert_eq!(outcome.index, 1);
+    assert_eq!(outcome.probes, 2);
+    assert_eq!(outcome.left, Some(2));
+    assert_eq!(outcome.right, Some(1));
+}
+
+#[test]
+fn both_left() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let result = bisector.run_validated(converge_at(1));
+
+    assert_eq!(
+        result.err(),
+        Some(ValidationError::BothLeft { first: 1, last: 10 })
+    );
+}
+
+#[test]
+fn both_right() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let result = bisector.run_validated(converge_at(11));
+
+    assert_eq!(
+        result.err(),
+        Some(ValidationError::BothRight { first: 1, last: 10 })
+    );
+}
+
+#[test]
+fn inverted() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let result = bisector.run_validated(|&value| {
+        if value <= 3 {
+            ConvergeTo::Left(value)
+        } else {
+            ConvergeTo::Right(value)
+        }
+    });
+
+    assert_eq!(
+        result.err(),
+        Some(ValidationError::Inverted { first: 1, last: 10 })
+    );
+}
+
+#[yare::parameterized(
+    empty = { input_empty() },
+    single = { input_1() },
+)]
+fn too_short(values: Vec<u32>) {
+    let bisector = Bisector::new(&values);
+
+    let result = bisector.run_validated(|_| -> ConvergeTo<(), ()> { unreachable!() });
+
+    assert_eq!(result.err(), Some(ValidationError::TooShort));
+}
+
+#[yare::parameterized(
+    first_endpoint = { 1 },
+    last_endpoint = { 10 },
+    middle = { 6 },
+)]
+fn try_run_validated_err(failing: u32) {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let result = bisector.try_run_validated(|&value| {
+        if value == failing {
+            Err(value)
+        } else {
+            Ok(converge_at(8)(&value))
+        }
+    });
+
+    assert_eq!(result.err(), Some(ValidationError::Convergence(failing)));
+}
+
+#[test]
+fn try_run_validated_ok() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let outcome = bisector
+        .try_run_validated(|&value| Ok::<_, ()>(converge_at(4)(&value)))
+        .unwrap();
+
+    assert_eq!(*outcome.element, 4);
+}
@@ -0,0 +1,179 @@
+use super::{super::*, *};
+use std::cell::Cell;
+
+// Converges to the given sides in order, cycling when all have been used
+fn sequence<'a>(
+    sides: &'a [bool],
+    run: &'a Cell<usize>,
+) -> impl FnMut(&u32) -> ConvergeTo<u32, u32> + 'a {
+    move |&value| {
+        let is_left = sides[run.get() % sides.len()];
+        run.set(run.get() + 1);
+
+        if is_left {
+            ConvergeTo::Left(value)
+        } else {
+            ConvergeTo::Right(value)
+        }
+    }
+}
+
+const L: bool = true;
+const R: bool = false;
+
+#[yare::parameterized(
+    all_left = { &[L], 3, Indices::new(0, 4), 3, 0 },
+    all_right = { &[R], 3, Indices::new(5, 9), 0, 3 },
+    majority_left = { &[L, R, L], 3, Indices::new(0, 4), 2, 1 },
+    majority_right = { &[R, L, R], 3, Indices::new(5, 9), 1, 2 },
+    tie_goes_to_most_recent = { &[L, R], 2, Indices::new(5, 9), 1, 1 },
+)]
+fn vote(sides: &[bool], runs: usize, expected: Indices, left: usize, right: usize) {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+    let run = Cell::new(0);
+
+    let step = bisector.bisect_voting(
+        &Voting::new(runs),
+        sequence(sides, &run),
+        Indices::from_bisector(&bisector),
+    );
+
+    assert_eq!(step.indices, expected);
+    assert_eq!(step.votes, Votes { left, right });
+    assert_eq!(run.get(), runs);
+}
+
+#[test]
+fn vote_stops_early_with_lead() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+    let run = Cell::new(0);
+
+    let step = bisector.bisect_voting(
+        &Voting::new(10).with_lead(2),
+        sequence(&[R], &run),
+        Indices::from_bisector(&bisector),
+    );
+
+    assert_eq!(step.indices, Indices::new(5, 9));
+    assert_eq!(step.votes, Votes { left: 0, right: 2 });
+    assert_eq!(step.result.unwrap().unwrap_converge_right(), 5);
+}
+
+#[test]
+#[should_panic]
+fn vote_without_lead() {
+    let _ = Voting::new(3).with_lead(0);
+}
+
+#[test]
+fn vote_tie_after_capped_escalation_goes_to_most_recent() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+    let run = Cell::new(0);
+
+    let step = bisector.bisect_voting(
+        &Voting::new(2).with_escalation(0, 2, 4),
+        sequence(&[R, L], &run),
+        Indices::from_bisector(&bisector),
+    );
+
+    assert_eq!(step.votes, Votes { left: 2, right: 2 });
+    assert_eq!(step.indices, Indices::new(0, 4));
+    assert_eq!(step.result.unwrap().unwrap_converge_left(), 5);
+}
+
+#[test]
+fn vote_escalates_when_close() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+    let run = Cell::new(0);
+
+    // After 4 runs, the votes are tied, so we run twice more, after which left leads by 2
+    let step = bisector.bisect_voting(
+        &Voting::new(4).with_escalation(1, 2, 10),
+        sequence(&[L, R, L, R, L, L], &run),
+        Indices::from_bisector(&bisector),
+    );
+
+    assert_eq!(step.indices, Indices::new(0, 4));
+    assert_eq!(step.votes, Votes { left: 4, right: 2 });
+}
+
+#[test]
+fn vote_escalation_is_capped() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+    let run = Cell::new(0);
+
+    let step = bisector.bisect_voting(
+        &Voting::new(2).with_escalation(1, 2, 7),
+        sequence(&[L, R], &run),
+        Indices::from_bisector(&bisector),
+    );
+
+    assert_eq!(step.votes.total(), 7);
+    assert_eq!(step.votes, Votes { left: 4, right: 3 });
+    assert_eq!(step.indices, Indices::new(0, 4));
+}
+
+#[test]
+fn vote_when_converged() {
+    let values = input_1();
+    let bisector = Bisector::new(&values);
+    let run = Cell::new(0);
+
+    let step = bisector.bisect_voting(
+        &Voting::new(3),
+        sequence(&[L], &run),
+        Indices::from_bisector(&bisector),
+    );
+
+    assert!(step.result.is_none());
+    assert_eq!(step.votes.total(), 0);
+    assert_eq!(run.get(), 0);
+}
+
+#[test]
+fn try_vote_ok() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+    let run = Cell::new(0);
+    let mut f = sequence(&[R, R, L], &run);
+
+    let step = bisector
+        .try_bisect_voting(
+            &Voting::new(3),
+            |value| Ok::<_, ()>(f(value)),
+            Indices::from_bisector(&bisector),
+        )
+        .unwrap();
+
+    assert_eq!(step.indices, Indices::new(5, 9));
+    assert_eq!(step.votes, Votes { left: 1, right: 2 });
+}
+
+#[test]
+fn try_vote_aborts_on_err() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+    let mut runs = 0;
+
+    let step = bisector.try_bisect_voting(
+        &Voting::new(5),
+        |&value| {
+            runs += 1;
+
+            if runs == 2 {
+                Err(())
+            } else {
+                Ok(ConvergeTo::<u32, u32>::Left(value))
+            }
+        },
+        Indices::from_bisector(&bisector),
+    );
+
+    assert!(step.is_err());
+    assert_eq!(runs, 2);
+}
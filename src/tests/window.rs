@@ -0,0 +1,134 @@
+use super::{super::*, *};
+
+// Converges to the left for the values in the given (inclusive) range
+fn bad_in(start: u32, end: u32) -> impl FnMut(&u32) -> ConvergeTo<u32, u32> + Copy {
+    move |&value| {
+        if start <= value && value <= end {
+            ConvergeTo::Left(value)
+        } else {
+            ConvergeTo::Right(value)
+        }
+    }
+}
+
+fn probed(probes: &[Probe<u32, u32>]) -> Vec<usize> {
+    probes.iter().map(|probe| probe.index).collect()
+}
+
+#[yare::parameterized(
+    middle = { 4, 6, 4, Indices::new(3, 5) },
+    known_is_start = { 4, 6, 3, Indices::new(3, 5) },
+    known_is_end = { 4, 6, 5, Indices::new(3, 5) },
+    single = { 5, 5, 4, Indices::new(4, 4) },
+    from_first = { 1, 3, 1, Indices::new(0, 2) },
+    to_last = { 8, 10, 8, Indices::new(7, 9) },
+    everything = { 1, 10, 5, Indices::new(0, 9) },
+    first_only = { 1, 1, 0, Indices::new(0, 0) },
+    last_only = { 10, 10, 9, Indices::new(9, 9) },
+)]
+fn window_finds_both_boundaries(start: u32, end: u32, bad: usize, expected: Indices) {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let window = bisector.window(bad_in(start, end), bad);
+
+    assert_eq!(window.indices, expected);
+}
+
+#[test]
+fn known_bad_element_is_not_probed() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let window = bisector.window(bad_in(4, 6), 4);
+
+    assert!(!probed(&window.start_probes).contains(&4));
+    assert!(!probed(&window.end_probes).contains(&4));
+}
+
+#[test]
+fn probes_establish_boundaries() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let window = bisector.window(bad_in(4, 6), 4);
+
+    // Start: [0, 4] probes 2, which converges to the right, then [3, 4] probes 3
+    assert_eq!(probed(&window.start_probes), vec![2, 3]);
+    assert!(matches!(
+        window.start_probes[0].result,
+        ConvergeTo::Right(3)
+    ));
+    assert!(matches!(window.start_probes[1].result, ConvergeTo::Left(4)));
+
+    // End: [4, 9] probes the upper middle 7, then [4, 6] probes 5, then [5, 6] probes 6
+    assert_eq!(probed(&window.end_probes), vec![7, 5, 6]);
+    assert!(matches!(window.end_probes[0].result, ConvergeTo::Right(8)));
+    assert!(matches!(window.end_probes[1].result, ConvergeTo::Left(6)));
+    assert!(matches!(window.end_probes[2].result, ConvergeTo::Right(7)));
+}
+
+#[test]
+fn single_element_view() {
+    let values = input_1();
+    let bisector = Bisector::new(&values);
+
+    let window = bisector.window(bad_in(1, 1), 0);
+
+    assert_eq!(window.indices, Indices::new(0, 0));
+    assert!(window.start_probes.is_empty());
+    assert!(window.end_probes.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn out_of_range() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let _ = bisector.window(bad_in(4, 6), 10);
+}
+
+#[yare::parameterized(
+    start_side = { 3 },
+    end_side = { 8 },
+)]
+fn try_window_err(failing: u32) {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let result = bisector.try_window(
+        |&value| {
+            if value == failing {
+                Err(value)
+            } else {
+                Ok(bad_in(4, 6)(&value))
+            }
+        },
+        4,
+    );
+
+    assert_eq!(result.err(), Some(failing));
+}
+
+#[test]
+fn try_window_ok() {
+    let values = input_1_to_10();
+    let bisector = Bisector::new(&values);
+
+    let window = bisector
+        .try_window(|&value| Ok::<_, ()>(bad_in(4, 6)(&value)), 4)
+        .unwrap();
+
+    assert_eq!(window.indices, Indices::new(3, 5));
+}
+
+#[test]
+fn window_over_source() {
+    let source = FnSource::new(100, |index| index as u32);
+    let bisector = Bisector::from_source(&source);
+
+    let window = bisector.window(bad_in(40, 59), 50);
+
+    assert_eq!(window.indices, Indices::new(40, 59));
+}
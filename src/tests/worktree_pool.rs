@@ -0,0 +1,214 @@
+use super::super::*;
+use super::git::{broken_since, run_git, version, TempRepository};
+use std::fs;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tempfile::TempDir;
+
+#[test]
+fn adds_and_removes_worktrees() {
+    let (repository, _) = TempRepository::with_versions(4);
+    let worktrees = TempDir::new().unwrap();
+    let dir = worktrees.path().join("pool");
+    let opened = Repository::open(repository.path()).unwrap();
+
+    {
+        let pool = opened.worktree_pool(&dir, 3).unwrap();
+
+        assert_eq!(pool.size(), 3);
+        assert_eq!(pool.paths(), [dir.join("0"), dir.join("1"), dir.join("2")]);
+
+        for path in pool.paths() {
+            assert_eq!(version(path), 3);
+        }
+    }
+
+    // The directory didn't exist, so it's removed as well
+    assert!(!dir.exists());
+    assert!(!repository.git(&["worktree", "list"]).contains("pool"));
+}
+
+#[test]
+fn keeps_existing_directory() {
+    let (repository, _) = TempRepository::with_versions(4);
+    let worktrees = TempDir::new().unwrap();
+    let opened = Repository::open(repository.path()).unwrap();
+
+    {
+        let _pool = opened.worktree_pool(worktrees.path(), 2).unwrap();
+    }
+
+    assert!(worktrees.path().exists());
+    assert_eq!(fs::read_dir(worktrees.path()).unwrap().count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn empty_pool() {
+    let (repository, _) = TempRepository::with_versions(1);
+    let worktrees = TempDir::new().unwrap();
+    let opened = Repository::open(repository.path()).unwrap();
+
+    let _ = opened.worktree_pool(worktrees.path().join("pool"), 0);
+}
+
+#[test]
+fn with_worktree_checks_out_commit() {
+    let (repository, commits) = TempRepository::with_versions(8);
+    let worktrees = TempDir::new().unwrap();
+    let opened = Repository::open(repository.path()).unwrap();
+    let pool = opened
+        .worktree_pool(worktrees.path().join("pool"), 2)
+        .unwrap();
+
+    let commit = Commit::new(commits[5].as_str());
+    let (checked_out, path) = pool
+        .with_worktree(&commit, |commit, path| {
+            assert_eq!(run_git(path, &["rev-parse", "HEAD"]), commit.id());
+            (version(path), path.to_path_buf())
+        })
+        .unwrap();
+
+    assert_eq!(checked_out, 5);
+    assert!(pool.paths().contains(&path));
+
+    // The repository itself is left untouched
+    assert_eq!(version(repository.path()), 7);
+}
+
+#[test]
+fn reuses_untracked_files() {
+    let (repository, commits) = TempRepository::with_versions(8);
+    let worktrees = TempDir::new().unwrap();
+    let opened = Repository::open(repository.path()).unwrap();
+    let pool = opened
+        .worktree_pool(worktrees.path().join("pool"), 1)
+        .unwrap();
+
+    pool.with_worktree(&Commit::new(commits[2].as_str()), |_, path| {
+        fs::create_dir(path.join("target")).unwrap();
+        fs::write(path.join("target").join("build"), "2").unwrap();
+        fs::write(path.join("version"), "modified").unwrap();
+    })
+    .unwrap();
+
+    let (checked_out, build) = pool
+        .with_worktree(&Commit::new(commits[6].as_str()), |_, path| {
+            let build = fs::read_to_string(path.join("target").join("build")).unwrap();
+            (version(path), build)
+        })
+        .unwrap();
+
+    // Tracked files are reset, but the build output of the previous lease is kept
+    assert_eq!(checked_out, 6);
+    assert_eq!(build, "2");
+}
+
+#[test]
+fn unknown_commit() {
+    let (repository, _) = TempRepository::with_versions(2);
+    let worktrees = TempDir::new().unwrap();
+    let opened = Repository::open(repository.path()).unwrap();
+    let pool = opened
+        .worktree_pool(worktrees.path().join("pool"), 1)
+        .unwrap();
+
+    let result = pool.with_worktree(&Commit::new("0000000"), |_, _| {
+        panic!("the function must not be called")
+    });
+
+    assert!(matches!(result, Err::<(), _>(GitError::Command { .. })));
+
+    // The worktree was given back
+    let _lease = pool.lease();
+}
+
+#[test]
+fn panicking_function_gives_back_worktree() {
+    let (repository, commits) = TempRepository::with_versions(2);
+    let worktrees = TempDir::new().unwrap();
+    let opened = Repository::open(repository.path()).unwrap();
+    let pool = opened
+        .worktree_pool(worktrees.path().join("pool"), 1)
+        .unwrap();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        pool.with_worktree(&Commit::new(commits[0].as_str()), |_, _| {
+            panic!("predicate panicked")
+        })
+    }));
+
+    assert!(result.is_err());
+
+    let mut lease = pool.lease();
+    let path = lease.switch(&Commit::new(commits[1].as_str())).unwrap();
+    assert_eq!(version(path), 1);
+}
+
+#[yare::parameterized(
+    one_worktree = { 1, 3 },
+    fewer_worktrees = { 2, 3 },
+    enough_worktrees = { 3, 3 },
+    more_worktrees = { 4, 2 },
+)]
+fn multisect(size: usize, k: usize) {
+    let (repository, commits) = TempRepository::with_versions(32);
+    let worktrees = TempDir::new().unwrap();
+    let opened = Repository::open(repository.path()).unwrap();
+    let pool = opened
+        .worktree_pool(worktrees.path().join("pool"), size)
+        .unwrap();
+
+    let candidates = opened
+        .candidates(&RevRange::new(commits[0].as_str(), "main"))
+        .unwrap();
+    let bisector = Bisector::new(&candidates);
+
+    let active = AtomicUsize::new(0);
+    let most_active = AtomicUsize::new(0);
+
+    let f = |commit: &Commit| {
+        pool.with_worktree(commit, |commit, path| {
+            let now = active.fetch_add(1, Ordering::SeqCst) + 1;
+            most_active.fetch_max(now, Ordering::SeqCst);
+
+            let result = broken_since(19)(commit, path);
+            active.fetch_sub(1, Ordering::SeqCst);
+            result
+        })
+    };
+
+    let mut indices = Indices::from_bisector(&bisector);
+
+    loop {
+        let step = bisector.try_multisect(k, f, indices).unwrap();
+
+        if step.probes.is_empty() {
+            break;
+        }
+
+        indices = step.indices;
+    }
+
+    assert_eq!(candidates[indices.left].id(), commits[19]);
+    assert!(most_active.load(Ordering::SeqCst) <= size);
+}
+
+#[test]
+fn run_speculative() {
+    let (repository, commits) = TempRepository::with_versions(32);
+    let worktrees = TempDir::new().unwrap();
+    let opened = Repository::open(repository.path()).unwrap();
+    let pool = opened
+        .worktree_pool(worktrees.path().join("pool"), 3)
+        .unwrap();
+
+    let candidates = opened
+        .candidates(&RevRange::new(commits[0].as_str(), "main"))
+        .unwrap();
+
+    let speculation = Bisector::new(&candidates)
+        .try_run_speculative(|commit| pool.with_worktree(commit, broken_since(7)))
+        .unwrap();
+
+    assert_eq!(candidates[speculation.indices.left].id(), commits[7]);
+}
@@ -0,0 +1,311 @@
+use crate::{Bisectable, Bisector, ConvergeTo, EmptySliceError, Indices, TryRunError};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BTreeMap, BinaryHeap};
+use std::convert::Infallible;
+
+/// A position in the view where the side to which the convergence function converges flips, as
+/// found by [`Bisector::transitions`].
+///
+/// [`Bisector::transitions`]: crate::Bisector::transitions
+pub struct Transition<L, R> {
+    /// The index of the first element after the flip, i.e. the flip happens between the elements
+    /// at `index - 1` and `index`.
+    pub index: usize,
+    /// The output of the convergence function for the element at `index - 1`.
+    pub before: ConvergeTo<L, R>,
+    /// The output of the convergence function for the element at `index`.
+    pub after: ConvergeTo<L, R>,
+}
+
+/// A region of the view which was not explored within the budget of [`Bisector::transitions`].
+///
+/// The elements at the left and right index of the region have been probed, but the elements in
+/// between have not. The only exception is a budget which doesn't cover the first and last element
+/// of the view, in which case nothing is probed, and the whole view is a single unexplored region.
+///
+/// [`Bisector::transitions`]: crate::Bisector::transitions
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Unexplored {
+    /// The indices of the probed elements which bound the region.
+    pub indices: Indices,
+    /// Whether the region is known to contain a transition, i.e. whether the elements at its
+    /// left and right index converged to different sides. Regions whose endpoints converged to
+    /// the same side may still contain an even number of transitions.
+    pub has_transition: bool,
+}
+
+/// All transitions found by [`Bisector::transitions`] and [`Bisector::try_transitions`].
+///
+/// [`Bisector::transitions`]: crate::Bisector::transitions
+/// [`Bisector::try_transitions`]: crate::Bisector::try_transitions
+pub struct Transitions<L, R> {
+    /// The transitions which were found, ordered by index.
+    pub transitions: Vec<Transition<L, R>>,
+    /// The regions which were not explored within the budget, ordered by index. If empty, every
+    /// transition in the view was found.
+    pub unexplored: Vec<Unexplored>,
+    /// The number of times the convergence function was called.
+    pub probes: usize,
+}
+
+impl<'v, T, S> Bisector<'v, T, S>
+where
+    S: Bisectable<Item = T> + ?Sized,
+{
+    /// Find every position in the view where the convergence function flips from one side to the
+    /// other, for views which are not monotone.
+    ///
+    /// Plain bisection assumes that the convergence function flips exactly once, and returns an
+    /// arbitrary boundary otherwise. When the convergence function flips back and forth, e.g. when
+    /// a regression was fixed and reintroduced, this method recursively bisects the sub-ranges of
+    /// the view, to find each [`Transition`].
+    ///
+    /// Regions whose endpoints converged to different sides are known to contain a transition, and
+    /// are explored first. Regions whose endpoints converged to the same side may still contain
+    /// an even number of transitions, and are explored next, widest first. Finding every transition
+    /// with certainty requires probing every element, so the number of calls to `f` is limited by
+    /// `budget`, which includes the probes of the first and last element. The regions which could
+    /// not be explored within the budget are reported as [`Unexplored`]. If the budget doesn't
+    /// cover the first and last element, i.e. if it's smaller than two (or one, for a view of a
+    /// single element), nothing is probed, and the whole view is reported as unexplored.
+    ///
+    /// The view must not be empty. If it is, an [`EmptySliceError`] `Err` result will be returned.
+    ///
+    /// ```
+    /// use bisector::{Bisector, ConvergeTo};
+    ///
+    /// // Broken in 3..5 and 8..
+    /// let values = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// let bisector = Bisector::new(&values);
+    ///
+    /// let found = bisector
+    ///     .transitions(
+    ///         |&value| {
+    ///             if (3..5).contains(&value) || value >= 8 {
+    ///                 ConvergeTo::Left(value)
+    ///             } else {
+    ///                 ConvergeTo::<u32, u32>::Right(value)
+    ///             }
+    ///         },
+    ///         usize::MAX,
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let indices = found
+    ///     .transitions
+    ///     .iter()
+    ///     .map(|transition| transition.index)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(indices, vec![3, 5, 8]);
+    /// assert!(found.unexplored.is_empty());
+    /// ```
+    ///
+    /// See also:
+    /// * [`Bisector::try_transitions`]: A variant of [`transitions`] which can be used when the
+    ///   convergence function is fallible.
+    ///
+    /// [`Transition`]: crate::Transition
+    /// [`Unexplored`]: crate::Unexplored
+    /// [`EmptySliceError`]: crate::EmptySliceError
+    /// [`Bisector::try_transitions`]: crate::Bisector::try_transitions
+    /// [`transitions`]: crate::Bisector::transitions
+    pub fn transitions<F, L, R>(
+        &self,
+        mut f: F,
+        budget: usize,
+    ) -> Result<Transitions<L, R>, EmptySliceError>
+    where
+        F: FnMut(&T) -> ConvergeTo<L, R>,
+        L: Clone,
+        R: Clone,
+    {
+        self.find_transitions(|value| Ok::<_, Infallible>(f(value)), budget)
+            .map_err(|err| match err {
+                TryRunError::EmptySlice(err) => err,
+                TryRunError::Convergence(infallible) => match infallible {},
+            })
+    }
+
+    /// This method can be used when the convergence function is fallible.
+    /// Otherwise exactly the same as [`Bisector::transitions`].
+    ///
+    /// Returns a [`TryRunError::EmptySlice`] error if the view is empty, and a
+    /// [`TryRunError::Convergence`] error if the convergence function failed.
+    ///
+    /// [`Bisector::transitions`]: crate::Bisector::transitions
+    /// [`TryRunError::EmptySlice`]: crate::TryRunError::EmptySlice
+    /// [`TryRunError::Convergence`]: crate::TryRunError::Convergence
+    pub fn try_transitions<F, E, L, R>(
+        &self,
+        f: F,
+        budget: usize,
+    ) -> Result<Transitions<L, R>, TryRunError<E>>
+    where
+        F: FnMut(&T) -> Result<ConvergeTo<L, R>, E>,
+        L: Clone,
+        R: Clone,
+    {
+        self.find_transitions(f, budget)
+    }
+
+    fn find_transitions<F, E, L, R>(
+        &self,
+        mut f: F,
+        budget: usize,
+    ) -> Result<Transitions<L, R>, TryRunError<E>>
+    where
+        F: FnMut(&T) -> Result<ConvergeTo<L, R>, E>,
+        L: Clone,
+        R: Clone,
+    {
+        let source = self.source();
+        let last = source
+            .len()
+            .checked_sub(1)
+            .ok_or(TryRunError::EmptySlice(EmptySliceError))?;
+
+        // Without both endpoints, not even the view as a whole can be compared
+        if budget < if last > 0 { 2 } else { 1 } {
+            return Ok(Transitions {
+                transitions: Vec::new(),
+                unexplored: vec![Unexplored {
+                    indices: Indices::new(0, last),
+                    has_transition: false,
+                }],
+                probes: 0,
+            });
+        }
+
+        let mut probed = BTreeMap::new();
+        let mut probe = |index: usize| -> Result<bool, TryRunError<E>> {
+            let result = f(&source.get(index)).map_err(TryRunError::Convergence)?;
+            let is_left = is_left(&result);
+            probed.insert(index, result);
+
+            Ok(is_left)
+        };
+
+        let mut regions = BinaryHeap::new();
+        let mut found = Vec::new();
+        let mut unexplored = Vec::new();
+
+        let first_is_left = probe(0)?;
+
+        if last > 0 {
+            regions.push(Region {
+                left: (0, first_is_left),
+                right: (last, probe(last)?),
+            });
+        }
+
+        let mut probes = if last > 0 { 2 } else { 1 };
+
+        while let Some(region) = regions.pop() {
+            let (left, left_is_left) = region.left;
+            let (right, right_is_left) = region.right;
+
+            if right - left == 1 {
+                if region.has_transition() {
+                    found.push(right);
+                }
+
+                continue;
+            }
+
+            if probes >= budget {
+                unexplored.push(Unexplored {
+                    indices: Indices::new(left, right),
+                    has_transition: region.has_transition(),
+                });
+
+                continue;
+            }
+
+            let middle = Indices::new(left, right).middle();
+            let middle_is_left = probe(middle)?;
+            probes += 1;
+
+            regions.push(Region {
+                left: (left, left_is_left),
+                right: (middle, middle_is_left),
+            });
+            regions.push(Region {
+                left: (middle, middle_is_left),
+                right: (right, right_is_left),
+            });
+        }
+
+        found.sort_unstable();
+        unexplored.sort_unstable_by_key(|region| region.indices.left);
+
+        let transitions = found
+            .into_iter()
+            .map(|index| Transition {
+                index,
+                before: clone_result(&probed[&(index - 1)]),
+                after: clone_result(&probed[&index]),
+            })
+            .collect();
+
+        Ok(Transitions {
+            transitions,
+            unexplored,
+            probes,
+        })
+    }
+}
+
+// A region of the view, bounded by two probed elements, and the side each converged to.
+//
+// Regions are ordered by their priority, and regions of equal priority by their bounds, so the
+// ordering is consistent with equality.
+#[derive(Debug, Eq, PartialEq)]
+struct Region {
+    left: (usize, bool),
+    right: (usize, bool),
+}
+
+impl Region {
+    fn has_transition(&self) -> bool {
+        self.left.1 != self.right.1
+    }
+
+    // Regions known to contain a transition are explored first, then the widest regions, and
+    // finally the regions closest to the start of the view.
+    fn priority(&self) -> (bool, usize, Reverse<usize>) {
+        (
+            self.has_transition(),
+            self.right.0 - self.left.0,
+            Reverse(self.left.0),
+        )
+    }
+}
+
+impl Ord for Region {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority()
+            .cmp(&other.priority())
+            .then_with(|| (self.left, self.right).cmp(&(other.left, other.right)))
+    }
+}
+
+impl PartialOrd for Region {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn is_left<L, R>(result: &ConvergeTo<L, R>) -> bool {
+    match result {
+        ConvergeTo::Left(_) => true,
+        ConvergeTo::Right(_) => false,
+    }
+}
+
+fn clone_result<L: Clone, R: Clone>(result: &ConvergeTo<L, R>) -> ConvergeTo<L, R> {
+    match result {
+        ConvergeTo::Left(out) => ConvergeTo::Left(out.clone()),
+        ConvergeTo::Right(out) => ConvergeTo::Right(out.clone()),
+    }
+}
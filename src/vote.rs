@@ -0,0 +1,282 @@
+use crate::{Bisectable, Bisector, ConvergeTo, Indices};
+
+/// Configuration of a majority vote, used by [`Bisector::bisect_voting`] and
+/// [`Bisector::try_bisect_voting`] to run a flaky convergence function several times per
+/// candidate.
+///
+/// By default, the convergence function is run the configured number of times, and the side which
+/// received the most votes wins. The vote can be cut short by requiring a minimum lead with
+/// [`Voting::with_lead`], and be escalated with extra runs when the votes are close to even, with
+/// [`Voting::with_escalation`].
+///
+/// When both sides received the same number of votes, for example after an even number of runs,
+/// or when escalation is capped, the side of the most recent run wins. To avoid ties, use an odd
+/// number of runs, and an even number of extra runs.
+///
+/// [`Bisector::bisect_voting`]: crate::Bisector::bisect_voting
+/// [`Bisector::try_bisect_voting`]: crate::Bisector::try_bisect_voting
+/// [`Voting::with_lead`]: crate::Voting::with_lead
+/// [`Voting::with_escalation`]: crate::Voting::with_escalation
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Voting {
+    runs: usize,
+    lead: Option<usize>,
+    escalation: Option<Escalation>,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Escalation {
+    margin: usize,
+    extra_runs: usize,
+    max_runs: usize,
+}
+
+impl Voting {
+    /// Create a new majority vote, which runs the convergence function `runs` times per candidate.
+    ///
+    /// The convergence function is always run at least once.
+    pub fn new(runs: usize) -> Self {
+        Self {
+            runs: runs.max(1),
+            lead: None,
+            escalation: None,
+        }
+    }
+
+    /// Stop voting as soon as one side has received `lead` more votes than the other side.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lead` is zero, since the vote would then stop after a single run.
+    pub fn with_lead(self, lead: usize) -> Self {
+        assert!(lead > 0, "the lead of a vote must be at least 1");
+
+        Self {
+            lead: Some(lead),
+            ..self
+        }
+    }
+
+    /// When, after all runs, the difference in votes between both sides is at most `margin`, run
+    /// the convergence function `extra_runs` more times. Voting is escalated until the difference
+    /// exceeds the margin, or until the convergence function has been run `max_runs` times in total.
+    pub fn with_escalation(self, margin: usize, extra_runs: usize, max_runs: usize) -> Self {
+        Self {
+            escalation: Some(Escalation {
+                margin,
+                extra_runs,
+                max_runs,
+            }),
+            ..self
+        }
+    }
+
+    // Runs the vote. The winning side is the side with the most votes; if both sides received the
+    // same number of votes, the side of the most recent run wins.
+    fn tally<F, E, L, R>(&self, mut run: F) -> Result<(ConvergeTo<L, R>, Votes), E>
+    where
+        F: FnMut() -> Result<ConvergeTo<L, R>, E>,
+    {
+        let mut votes = Votes { left: 0, right: 0 };
+        let mut left = None;
+        let mut right = None;
+        let mut latest_is_left = false;
+
+        let mut runs = self.runs;
+        let has_lead = |votes: &Votes| match self.lead {
+            Some(lead) => votes.lead() >= lead,
+            None => false,
+        };
+
+        loop {
+            while votes.total() < runs {
+                match run()? {
+                    ConvergeTo::Left(out) => {
+                        votes.left += 1;
+                        left = Some(out);
+                        latest_is_left = true;
+                    }
+                    ConvergeTo::Right(out) => {
+                        votes.right += 1;
+                        right = Some(out);
+                        latest_is_left = false;
+                    }
+                }
+
+                if has_lead(&votes) {
+                    break;
+                }
+            }
+
+            if has_lead(&votes) {
+                break;
+            }
+
+            match self.escalation {
+                Some(escalation)
+                    if votes.lead() <= escalation.margin
+                        && votes.total() < escalation.max_runs
+                        && escalation.extra_runs > 0 =>
+                {
+                    runs = (votes.total() + escalation.extra_runs).min(escalation.max_runs);
+                }
+                _ => break,
+            }
+        }
+
+        let converge_left =
+            votes.left > votes.right || (votes.left == votes.right && latest_is_left);
+
+        let result = match (converge_left, left, right) {
+            (true, Some(out), _) => ConvergeTo::Left(out),
+            (false, _, Some(out)) => ConvergeTo::Right(out),
+            _ => unreachable!("the winning side of a vote must have received a vote"),
+        };
+
+        Ok((result, votes))
+    }
+}
+
+/// The number of times the convergence function converged to each side, during a majority vote.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub struct Votes {
+    pub left: usize,
+    pub right: usize,
+}
+
+impl Votes {
+    /// The total number of votes.
+    pub fn total(&self) -> usize {
+        self.left + self.right
+    }
+
+    /// The difference between the number of votes of the side with the most votes, and the side
+    /// with the least votes.
+    pub fn lead(&self) -> usize {
+        self.left.max(self.right) - self.left.min(self.right)
+    }
+}
+
+/// The output of one bisection step, decided by a majority vote.
+///
+/// Like [`Step`], but with the `votes` which decided the step. The `result` holds the output of the
+/// most recent run of the convergence function which converged to the winning side.
+///
+/// When `result` is `None`, the bisection was already in a converged state, and no votes were
+/// cast.
+///
+/// [`Step`]: crate::Step
+pub struct VoteStep<L, R> {
+    pub indices: Indices,
+    pub result: Option<ConvergeTo<L, R>>,
+    pub votes: Votes,
+}
+
+impl<'v, T, S> Bisector<'v, T, S>
+where
+    S: Bisectable<Item = T> + ?Sized,
+{
+    /// Like [`Bisector::bisect`], but runs the convergence function `f` several times for the
+    /// middle element, and converges to the side which wins the majority vote, as configured by
+    /// `voting`.
+    ///
+    /// This may be used when the convergence function is flaky, i.e. when it may sometimes
+    /// converge to the wrong side, such that a single wrong result doesn't send the bisection into
+    /// the wrong half of the view.
+    ///
+    /// ```
+    /// use bisector::{Bisector, ConvergeTo, Indices, Voting};
+    ///
+    /// let values = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    /// let bisector = Bisector::new(&values);
+    ///
+    /// // Fails every third run
+    /// let mut run = 0;
+    /// let step = bisector.bisect_voting(
+    ///     &Voting::new(3),
+    ///     |&value| {
+    ///         run += 1;
+    ///
+    ///         if run % 3 == 0 {
+    ///             ConvergeTo::Left(value)
+    ///         } else {
+    ///             ConvergeTo::Right(value)
+    ///         }
+    ///     },
+    ///     Indices::from_bisector(&bisector),
+    /// );
+    ///
+    /// assert_eq!(step.indices, Indices::new(5, 9));
+    /// assert_eq!(step.votes.right, 2);
+    /// assert_eq!(step.votes.left, 1);
+    /// ```
+    ///
+    /// See also:
+    /// * [`Bisector::try_bisect_voting`]: A variant of [`bisect_voting`] which can be used when the
+    ///   convergence function is fallible.
+    ///
+    /// [`Bisector::bisect`]: crate::Bisector::bisect
+    /// [`Bisector::try_bisect_voting`]: crate::Bisector::try_bisect_voting
+    /// [`bisect_voting`]: crate::Bisector::bisect_voting
+    pub fn bisect_voting<F, L, R>(
+        &self,
+        voting: &Voting,
+        mut f: F,
+        indices: Indices,
+    ) -> VoteStep<L, R>
+    where
+        F: FnMut(&T) -> ConvergeTo<L, R>,
+    {
+        let mut votes = Votes::default();
+
+        let step = self.bisect(
+            |value| {
+                let (result, tally) = voting
+                    .tally(|| Ok::<_, std::convert::Infallible>(f(value)))
+                    .unwrap_or_else(|infallible| match infallible {});
+                votes = tally;
+                result
+            },
+            indices,
+        );
+
+        VoteStep {
+            indices: step.indices,
+            result: step.result,
+            votes,
+        }
+    }
+
+    /// This method can be used when the convergence function is fallible.
+    /// Otherwise exactly the same as [`Bisector::bisect_voting`].
+    ///
+    /// The vote is aborted as soon as the convergence function returns an error.
+    ///
+    /// [`Bisector::bisect_voting`]: crate::Bisector::bisect_voting
+    pub fn try_bisect_voting<F, E, L, R>(
+        &self,
+        voting: &Voting,
+        mut f: F,
+        indices: Indices,
+    ) -> Result<VoteStep<L, R>, E>
+    where
+        F: FnMut(&T) -> Result<ConvergeTo<L, R>, E>,
+    {
+        let mut votes = Votes::default();
+
+        let step = self.try_bisect(
+            |value| {
+                let (result, tally) = voting.tally(|| f(value))?;
+                votes = tally;
+                Ok(result)
+            },
+            indices,
+        )?;
+
+        Ok(VoteStep {
+            indices: step.indices,
+            result: step.result,
+            votes,
+        })
+    }
+}
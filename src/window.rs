@@ -0,0 +1,174 @@
+use crate::{Bisectable, Bisector, ConvergeTo, Indices, Probe};
+use std::convert::Infallible;
+
+/// A contiguous window of elements which converge to the left, surrounded by elements which
+/// converge to the right, as found by [`Bisector::window`] and [`Bisector::try_window`].
+///
+/// The output consists of three parts:
+/// * `indices`: The indices of the first and the last element of the window; both are part of the
+///   window
+/// * `start_probes`: The output of the convergence function for each element probed while searching
+///   for the start of the window, in the order in which they were probed
+/// * `end_probes`: The output of the convergence function for each element probed while searching
+///   for the end of the window, in the order in which they were probed
+///
+/// [`Bisector::window`]: crate::Bisector::window
+/// [`Bisector::try_window`]: crate::Bisector::try_window
+pub struct Window<L, R> {
+    pub indices: Indices,
+    pub start_probes: Vec<Probe<L, R>>,
+    pub end_probes: Vec<Probe<L, R>>,
+}
+
+impl<'v, T, S> Bisector<'v, T, S>
+where
+    S: Bisectable<Item = T> + ?Sized,
+{
+    /// Find the window around a known element which converges to the left, for views in which the
+    /// elements before and after the window converge to the right.
+    ///
+    /// This fits a regression which was fixed later on: the view consists of good elements, then a
+    /// stretch of bad elements, and then good elements again, and the convergence function
+    /// converges to the left for bad elements. Given the index of one element which is known to be
+    /// bad, the start of the window is found by bisecting the elements up to `bad` with
+    /// [`Bisector::bisect`], and the end of the window is found by bisecting the elements after `bad`
+    /// in the mirrored direction.
+    ///
+    /// Like [`Bisector::bisect`], the element at `bad` is never probed, and is assumed to converge to
+    /// the left. The elements before the window are assumed to converge to the right, and so are
+    /// the elements after it. If no element before `bad` converges to the right, the window starts
+    /// at the first element, and if no element after `bad` does, it ends at the last element.
+    ///
+    /// ```
+    /// use bisector::{Bisector, ConvergeTo, Indices};
+    ///
+    /// // Versions 4 up to and including 6 are bad
+    /// let values = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    /// let bisector = Bisector::new(&values);
+    ///
+    /// let window = bisector.window(
+    ///     |&version| {
+    ///         if (4..=6).contains(&version) {
+    ///             ConvergeTo::Left(version)
+    ///         } else {
+    ///             ConvergeTo::<u32, u32>::Right(version)
+    ///         }
+    ///     },
+    ///     4,
+    /// );
+    ///
+    /// assert_eq!(window.indices, Indices::new(3, 5));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bad` is not a valid index of the view.
+    ///
+    /// See also:
+    /// * [`Bisector::try_window`]: A variant of [`window`] which can be used when the convergence
+    ///   function is fallible.
+    ///
+    /// [`Bisector::bisect`]: crate::Bisector::bisect
+    /// [`Bisector::try_window`]: crate::Bisector::try_window
+    /// [`window`]: crate::Bisector::window
+    pub fn window<F, L, R>(&self, mut f: F, bad: usize) -> Window<L, R>
+    where
+        F: FnMut(&T) -> ConvergeTo<L, R>,
+    {
+        self.find_window(|value| Ok::<_, Infallible>(f(value)), bad)
+            .unwrap_or_else(|infallible| match infallible {})
+    }
+
+    /// This method can be used when the convergence function is fallible.
+    /// Otherwise exactly the same as [`Bisector::window`].
+    ///
+    /// The search stops as soon as the convergence function returns an error.
+    ///
+    /// [`Bisector::window`]: crate::Bisector::window
+    pub fn try_window<F, E, L, R>(&self, f: F, bad: usize) -> Result<Window<L, R>, E>
+    where
+        F: FnMut(&T) -> Result<ConvergeTo<L, R>, E>,
+    {
+        self.find_window(f, bad)
+    }
+
+    fn find_window<F, E, L, R>(&self, mut f: F, bad: usize) -> Result<Window<L, R>, E>
+    where
+        F: FnMut(&T) -> Result<ConvergeTo<L, R>, E>,
+    {
+        let len = self.source().len();
+        assert!(
+            bad < len,
+            "bad index {} out of range for view of length {}",
+            bad,
+            len
+        );
+
+        // The start of the window is the first element which converges to the left
+        let mut start_probes = Vec::new();
+        let mut indices = Indices::new(0, bad);
+
+        loop {
+            let middle = indices.middle();
+            let step = self.try_bisect(&mut f, indices)?;
+
+            match step.result {
+                Some(result) => start_probes.push(Probe {
+                    index: middle,
+                    result,
+                }),
+                None => break,
+            }
+
+            indices = step.indices;
+        }
+
+        let start = indices.left;
+
+        // The end of the window is the last element which converges to the left
+        let mut end_probes = Vec::new();
+        let mut indices = Indices::new(bad, len - 1);
+
+        while let Some(probe) = self.bisect_mirrored(&mut f, &mut indices)? {
+            end_probes.push(probe);
+        }
+
+        Ok(Window {
+            indices: Indices::new(start, indices.right),
+            start_probes,
+            end_probes,
+        })
+    }
+
+    // Mirrored version of `Bisector::try_bisect`, which converges to the last element which
+    // converges to the left. The element at the left index is never probed, and is assumed to
+    // converge to the left. Returns `None` once the indices have converged.
+    fn bisect_mirrored<F, E, L, R>(
+        &self,
+        f: F,
+        indices: &mut Indices,
+    ) -> Result<Option<Probe<L, R>>, E>
+    where
+        F: FnOnce(&T) -> Result<ConvergeTo<L, R>, E>,
+    {
+        let Indices { left, right } = *indices;
+
+        if left == right {
+            return Ok(None);
+        }
+
+        // The upper middle, so the left index is never probed
+        let middle = right - (right - left) / 2;
+        let result = f(&self.source().get(middle))?;
+
+        *indices = match result {
+            ConvergeTo::Left(_) => Indices::new(middle, right),
+            ConvergeTo::Right(_) => Indices::new(left, middle - 1),
+        };
+
+        Ok(Some(Probe {
+            index: middle,
+            result,
+        }))
+    }
+}
@@ -0,0 +1,271 @@
+use crate::git::git;
+use crate::{Commit, GitError, Repository};
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::sync::{Condvar, Mutex, PoisonError};
+
+/// A fixed set of `git worktree`s, which are leased to convergence functions running concurrently,
+/// as created by [`Repository::worktree_pool`].
+///
+/// Each lease has exclusive use of one worktree, in which it can check out a commit. Worktrees are
+/// reused by later leases, and only the tracked files are reset when switching commits, so
+/// untracked and ignored files, such as a `target/` directory, are kept. This makes incremental
+/// builds possible when testing a commit which is close to the previously tested one.
+///
+/// The pool is [`Sync`], so it can be shared by the convergence functions of
+/// [`Bisector::multisect`] and [`Bisector::run_speculative`], which are evaluated on several
+/// threads. The worktrees are removed when the pool is dropped.
+///
+/// [`Repository::worktree_pool`]: crate::Repository::worktree_pool
+/// [`Bisector::multisect`]: crate::Bisector::multisect
+/// [`Bisector::run_speculative`]: crate::Bisector::run_speculative
+#[derive(Debug)]
+pub struct WorktreePool<'r> {
+    repository: &'r Repository,
+    dir: PathBuf,
+    // Whether the directory was created for the pool, and should be removed with it
+    created: bool,
+    worktrees: Vec<PathBuf>,
+    free: Mutex<Vec<PathBuf>>,
+    released: Condvar,
+}
+
+impl Repository {
+    /// Create a pool of the given number of worktrees, in the given directory.
+    ///
+    /// The worktrees are added at `dir/0`, `dir/1`, and so on, with a detached `HEAD`. The
+    /// directory is created if it doesn't exist yet, in which case it's removed together with the
+    /// worktrees when the pool is dropped. If one of the worktrees can't be added, the worktrees
+    /// which were already added are removed again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size is zero, since a lease would never become available.
+    pub fn worktree_pool(
+        &self,
+        dir: impl Into<PathBuf>,
+        size: usize,
+    ) -> Result<WorktreePool<'_>, GitError> {
+        assert!(size > 0, "a worktree pool needs at least one worktree");
+
+        let dir = dir.into();
+
+        // Git is run from different directories, so relative paths would be ambiguous
+        let dir = if dir.is_absolute() {
+            dir
+        } else {
+            std::env::current_dir().map_err(GitError::Io)?.join(dir)
+        };
+
+        let mut pool = WorktreePool {
+            repository: self,
+            created: !dir.exists(),
+            dir,
+            worktrees: Vec::with_capacity(size),
+            free: Mutex::new(Vec::with_capacity(size)),
+            released: Condvar::new(),
+        };
+
+        for index in 0..size {
+            let path = pool.dir.join(index.to_string());
+
+            git(
+                self.path(),
+                [
+                    OsStr::new("worktree"),
+                    OsStr::new("add"),
+                    OsStr::new("--quiet"),
+                    OsStr::new("--detach"),
+                    path.as_os_str(),
+                    OsStr::new("HEAD"),
+                ],
+            )?;
+
+            pool.worktrees.push(path.clone());
+            pool.free_worktrees().push(path);
+        }
+
+        Ok(pool)
+    }
+}
+
+impl<'r> WorktreePool<'r> {
+    /// The repository to which the worktrees belong.
+    pub fn repository(&self) -> &'r Repository {
+        self.repository
+    }
+
+    /// The number of worktrees in the pool.
+    pub fn size(&self) -> usize {
+        self.worktrees.len()
+    }
+
+    /// The paths of all worktrees in the pool, whether leased or not.
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.worktrees
+    }
+
+    /// Lease a worktree, waiting until one is available if all of them are leased.
+    ///
+    /// The worktree is given back to the pool when the returned [`Lease`] is dropped, also when the
+    /// convergence function panics.
+    ///
+    /// [`Lease`]: crate::Lease
+    pub fn lease(&self) -> Lease<'_> {
+        let mut free = self.free_worktrees();
+
+        loop {
+            match free.pop() {
+                Some(path) => {
+                    return Lease {
+                        pool: self,
+                        path: Some(path),
+                    }
+                }
+                None => {
+                    free = self
+                        .released
+                        .wait(free)
+                        .unwrap_or_else(PoisonError::into_inner)
+                }
+            }
+        }
+    }
+
+    /// Lease a worktree, check out the given commit in it, and call `f` with the commit and the
+    /// path of the worktree. The worktree is given back to the pool once `f` returns.
+    ///
+    /// Since the signature of `f` matches the convergence function of [`Repository::run`], this
+    /// turns a convergence function over checked out commits into one over commits, which can be
+    /// evaluated concurrently:
+    ///
+    /// ```no_run
+    /// use bisector::{Bisector, ConvergeTo, Indices, Repository, RevRange};
+    /// use std::path::Path;
+    /// use std::process::Command;
+    ///
+    /// let repository = Repository::open(".").unwrap();
+    /// let candidates = repository
+    ///     .candidates(&RevRange::new("v1.0.0", "main"))
+    ///     .unwrap();
+    ///
+    /// let pool = repository.worktree_pool("../bisect", 3).unwrap();
+    /// let bisector = Bisector::new(&candidates);
+    ///
+    /// let cargo_test = |_: &_, path: &Path| {
+    ///     let status = Command::new("cargo")
+    ///         .arg("test")
+    ///         .current_dir(path)
+    ///         .status()
+    ///         .unwrap();
+    ///
+    ///     if status.success() {
+    ///         ConvergeTo::Right(())
+    ///     } else {
+    ///         ConvergeTo::<(), ()>::Left(())
+    ///     }
+    /// };
+    ///
+    /// let mut indices = Indices::from_bisector(&bisector);
+    ///
+    /// loop {
+    ///     let step = bisector
+    ///         .try_multisect(3, |commit| pool.with_worktree(commit, cargo_test), indices)
+    ///         .unwrap();
+    ///
+    ///     if step.probes.is_empty() {
+    ///         break;
+    ///     }
+    ///
+    ///     indices = step.indices;
+    /// }
+    ///
+    /// println!("first bad commit: {}", candidates[indices.left]);
+    /// ```
+    ///
+    /// Returns a [`GitError`] if the commit can't be checked out, in which case `f` isn't called.
+    ///
+    /// [`Repository::run`]: crate::Repository::run
+    /// [`GitError`]: crate::GitError
+    pub fn with_worktree<F, O>(&self, commit: &Commit, f: F) -> Result<O, GitError>
+    where
+        F: FnOnce(&Commit, &Path) -> O,
+    {
+        let mut lease = self.lease();
+        let path = lease.switch(commit)?;
+
+        Ok(f(commit, path))
+    }
+
+    fn free_worktrees(&self) -> std::sync::MutexGuard<'_, Vec<PathBuf>> {
+        // The lock is never held while running anything which may panic
+        self.free.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Drop for WorktreePool<'_> {
+    fn drop(&mut self) {
+        // Errors can't be reported from drop, and leave the worktree as it is
+        for path in &self.worktrees {
+            let _ = git(
+                self.repository.path(),
+                [
+                    OsStr::new("worktree"),
+                    OsStr::new("remove"),
+                    OsStr::new("--force"),
+                    path.as_os_str(),
+                ],
+            );
+        }
+
+        if self.created {
+            // Fails, and leaves the directory, if anything else was put in it
+            let _ = std::fs::remove_dir(&self.dir);
+        }
+    }
+}
+
+/// Exclusive use of one worktree of a [`WorktreePool`], as created by [`WorktreePool::lease`].
+///
+/// The worktree is given back to the pool when the lease is dropped.
+///
+/// [`WorktreePool`]: crate::WorktreePool
+/// [`WorktreePool::lease`]: crate::WorktreePool::lease
+#[derive(Debug)]
+pub struct Lease<'p> {
+    pool: &'p WorktreePool<'p>,
+    // Only taken when the lease is dropped
+    path: Option<PathBuf>,
+}
+
+impl Lease<'_> {
+    /// The path of the leased worktree.
+    pub fn path(&self) -> &Path {
+        self.path
+            .as_deref()
+            .expect("the path is only taken on drop")
+    }
+
+    /// Check out the given commit in the leased worktree, and return its path.
+    ///
+    /// Local changes to tracked files are thrown away, but untracked and ignored files are kept.
+    pub fn switch(&mut self, commit: &Commit) -> Result<&Path, GitError> {
+        let path = self.path();
+
+        git(
+            path,
+            ["checkout", "--quiet", "--detach", "--force", commit.id()],
+        )?;
+
+        Ok(path)
+    }
+}
+
+impl Drop for Lease<'_> {
+    fn drop(&mut self) {
+        if let Some(path) = self.path.take() {
+            self.pool.free_worktrees().push(path);
+            self.pool.released.notify_one();
+        }
+    }
+}